@@ -1,7 +1,12 @@
 // Libs
 use async_trait::async_trait;
+use std::{
+    collections::HashMap,
+    sync::Mutex,
+    time::{Duration, Instant},
+};
 
-use crate::{errors::CacheResult, utils::EnvManager};
+use crate::{errors::CacheResult, utils::config::CacheConfig};
 
 // Cache Trait.
 /**
@@ -32,6 +37,20 @@ pub trait Cache {
     - `value`: The value to insert.
     */
     async fn insert_key(&self, key: &str, value: &str) -> CacheResult<()>;
+
+    /**
+    A method to insert a key value pair that is never evicted from the cache and expires after its own
+    time-to-live.
+
+    It's meant for the values that are expensive to rebuild, like the spawn table.
+
+    ## Parameters:
+    - `key`: The key to insert.
+    - `value`: The value to insert.
+    - `ttl`: The time-to-live of the key.
+    */
+    async fn insert_persistent_key(&self, key: &str, value: &str, ttl: Duration)
+        -> CacheResult<()>;
 }
 
 // Redis Cache
@@ -46,17 +65,26 @@ pub mod redis_cache {
     pub struct RedisCache {
         client: redis::Client,
         conn: Option<MultiplexedConnection>,
+        namespace: String,
     }
 
     impl RedisCache {
         /**
         A method to create a new instance of the RedisCache.
+
+        ## Parameters:
+        - `config`: The cache's configuration.
         */
-        pub fn new() -> CacheResult<Self> {
-            let redis_url = format!("redis://@{}", EnvManager::get_var::<String>("CACHE_HOST"));
+        pub fn new(config: &CacheConfig) -> CacheResult<Self> {
+            let host = config
+                .host
+                .as_deref()
+                .ok_or("The cache host is not defined.")?;
+            let redis_url = format!("redis://@{}", host);
             Ok(Self {
                 client: redis::Client::open(redis_url)?,
                 conn: Option::None,
+                namespace: config.namespace.clone(),
             })
         }
     }
@@ -75,11 +103,7 @@ pub mod redis_cache {
                 Some(conn) => conn,
                 None => return Err("No connection to the Redis server.".into()),
             };
-            let key = format!(
-                "{}:{}",
-                EnvManager::get_var::<String>("CACHE_NAMESPACE"),
-                key
-            );
+            let key = format!("{}:{}", self.namespace, key);
             Ok(redis::cmd("GET").arg(key).query_async(&mut conn).await?)
         }
 
@@ -88,16 +112,382 @@ pub mod redis_cache {
                 Some(conn) => conn,
                 None => return Err("No connection to the Redis server.".into()),
             };
-            let key = format!(
-                "{}:{}",
-                EnvManager::get_var::<String>("CACHE_NAMESPACE"),
-                key
-            );
+            let key = format!("{}:{}", self.namespace, key);
+            Ok(redis::cmd("SET")
+                .arg(key)
+                .arg(value)
+                .query_async(&mut conn)
+                .await?)
+        }
+
+        async fn insert_persistent_key(
+            &self,
+            key: &str,
+            value: &str,
+            ttl: Duration,
+        ) -> CacheResult<()> {
+            let mut conn = match self.conn.clone() {
+                Some(conn) => conn,
+                None => return Err("No connection to the Redis server.".into()),
+            };
+            let key = format!("{}:{}", self.namespace, key);
             Ok(redis::cmd("SET")
                 .arg(key)
                 .arg(value)
+                .arg("EX")
+                .arg(ttl.as_secs().max(1))
                 .query_async(&mut conn)
                 .await?)
         }
     }
 }
+
+// Memory Cache
+pub mod memory_cache {
+    use super::*;
+
+    /**
+    A struct to represent an entry stored in the memory cache.
+    */
+    struct MemoryCacheEntry {
+        value: String,
+        inserted_at: Instant,
+        expires_at: Option<Instant>,
+        is_persistent: bool,
+    }
+
+    impl MemoryCacheEntry {
+        /**
+        A method to check if the entry has expired.
+        */
+        fn is_expired(&self) -> bool {
+            match self.expires_at {
+                Some(expires_at) => Instant::now() >= expires_at,
+                None => false,
+            }
+        }
+    }
+
+    /**
+    A struct to represent an in-memory cache.
+
+    It's thread-safe and supports an optional time-to-live for the entries and an optional
+    maximum amount of entries. When the cache is full, the oldest entry is evicted.
+    The persistent entries expire after their own time-to-live, aren't evicted and don't count
+    towards the maximum.
+    */
+    pub struct MemoryCache {
+        entries: Mutex<HashMap<String, MemoryCacheEntry>>,
+        namespace: String,
+        ttl: Option<Duration>,
+        max_entries: Option<usize>,
+    }
+
+    impl MemoryCache {
+        /**
+        A method to create a new instance of the MemoryCache.
+
+        A `memory_ttl_secs` or `memory_max_entries` of `0` disables the respective limit.
+
+        ## Parameters:
+        - `config`: The cache's configuration.
+        */
+        pub fn new(config: &CacheConfig) -> Self {
+            Self::with_limits(
+                &config.namespace,
+                (config.memory_ttl_secs > 0).then(|| Duration::from_secs(config.memory_ttl_secs)),
+                (config.memory_max_entries > 0).then_some(config.memory_max_entries),
+            )
+        }
+
+        /**
+        A method to create a new instance of the MemoryCache with custom limits.
+
+        ## Parameters:
+        - `namespace`: The namespace of the keys.
+        - `ttl`: The time-to-live of each entry. `None` means the entries never expire.
+        - `max_entries`: The maximum amount of entries. `None` means there's no limit.
+        */
+        pub fn with_limits(
+            namespace: &str,
+            ttl: Option<Duration>,
+            max_entries: Option<usize>,
+        ) -> Self {
+            Self {
+                entries: Mutex::new(HashMap::new()),
+                namespace: namespace.to_string(),
+                ttl,
+                max_entries,
+            }
+        }
+
+        /**
+        A method to get the namespaced key.
+
+        ## Parameters:
+        - `key`: The key to namespace.
+        */
+        fn namespaced_key(&self, key: &str) -> String {
+            format!("{}:{}", self.namespace, key)
+        }
+
+        /**
+        A method to insert an entry, evicting the oldest entries if the cache is full.
+
+        ## Parameters:
+        - `key`: The key to insert.
+        - `value`: The value to insert.
+        - `ttl`: The time-to-live of the entry. `None` means the entry never expires.
+        - `is_persistent`: If the entry is never evicted.
+        */
+        fn insert_entry(
+            &self,
+            key: &str,
+            value: &str,
+            ttl: Option<Duration>,
+            is_persistent: bool,
+        ) -> CacheResult<()> {
+            let key = self.namespaced_key(key);
+            let mut entries = self
+                .entries
+                .lock()
+                .map_err(|_| "The memory cache lock is poisoned.")?;
+            entries.remove(&key);
+
+            // Make room for the new entry if the cache is full.
+            if let (Some(max_entries), false) = (self.max_entries, is_persistent) {
+                let is_full = |entries: &HashMap<String, MemoryCacheEntry>| {
+                    entries
+                        .values()
+                        .filter(|entry| !entry.is_persistent)
+                        .count()
+                        >= max_entries
+                };
+                if is_full(&entries) {
+                    entries.retain(|_, entry| !entry.is_expired());
+                }
+
+                while is_full(&entries) {
+                    let oldest_key = entries
+                        .iter()
+                        .filter(|(_, entry)| !entry.is_persistent)
+                        .min_by_key(|(_, entry)| entry.inserted_at)
+                        .map(|(key, _)| key.clone());
+                    match oldest_key {
+                        Some(oldest_key) => entries.remove(&oldest_key),
+                        None => break,
+                    };
+                }
+            }
+
+            let now = Instant::now();
+            entries.insert(
+                key,
+                MemoryCacheEntry {
+                    value: value.to_string(),
+                    inserted_at: now,
+                    expires_at: ttl.map(|ttl| now + ttl),
+                    is_persistent,
+                },
+            );
+            Ok(())
+        }
+    }
+
+    #[async_trait]
+    impl Cache for MemoryCache {
+        type Connection = ();
+
+        async fn connect(&mut self) -> CacheResult<()> {
+            Ok(())
+        }
+
+        async fn get_key(&self, key: &str) -> CacheResult<Option<String>> {
+            let key = self.namespaced_key(key);
+            let mut entries = self
+                .entries
+                .lock()
+                .map_err(|_| "The memory cache lock is poisoned.")?;
+
+            match entries.get(&key) {
+                Some(entry) if entry.is_expired() => {
+                    entries.remove(&key);
+                    Ok(None)
+                }
+                Some(entry) => Ok(Some(entry.value.clone())),
+                None => Ok(None),
+            }
+        }
+
+        async fn insert_key(&self, key: &str, value: &str) -> CacheResult<()> {
+            self.insert_entry(key, value, self.ttl, false)
+        }
+
+        async fn insert_persistent_key(
+            &self,
+            key: &str,
+            value: &str,
+            ttl: Duration,
+        ) -> CacheResult<()> {
+            self.insert_entry(key, value, Some(ttl), true)
+        }
+    }
+}
+
+// Cache Backend
+pub mod cache_backend {
+    use super::{memory_cache::MemoryCache, redis_cache::RedisCache, *};
+
+    /**
+    An enum to represent the cache backend selected in the configuration.
+    */
+    pub enum CacheBackend {
+        Redis(RedisCache),
+        Memory(MemoryCache),
+    }
+
+    impl CacheBackend {
+        /**
+        A method to create the cache backend defined in the configuration.
+
+        The available backends are `redis` and `memory`.
+
+        ## Parameters:
+        - `config`: The cache's configuration.
+        */
+        pub fn new(config: &CacheConfig) -> CacheResult<Self> {
+            match config.backend.as_str() {
+                "redis" => Ok(Self::Redis(RedisCache::new(config)?)),
+                "memory" => Ok(Self::Memory(MemoryCache::new(config))),
+                backend => Err(format!("The cache backend '{}' is not supported.", backend).into()),
+            }
+        }
+    }
+
+    #[async_trait]
+    impl Cache for CacheBackend {
+        type Connection = ();
+
+        async fn connect(&mut self) -> CacheResult<()> {
+            match self {
+                Self::Redis(cache) => cache.connect().await,
+                Self::Memory(cache) => cache.connect().await,
+            }
+        }
+
+        async fn get_key(&self, key: &str) -> CacheResult<Option<String>> {
+            match self {
+                Self::Redis(cache) => cache.get_key(key).await,
+                Self::Memory(cache) => cache.get_key(key).await,
+            }
+        }
+
+        async fn insert_key(&self, key: &str, value: &str) -> CacheResult<()> {
+            match self {
+                Self::Redis(cache) => cache.insert_key(key, value).await,
+                Self::Memory(cache) => cache.insert_key(key, value).await,
+            }
+        }
+
+        async fn insert_persistent_key(
+            &self,
+            key: &str,
+            value: &str,
+            ttl: Duration,
+        ) -> CacheResult<()> {
+            match self {
+                Self::Redis(cache) => cache.insert_persistent_key(key, value, ttl).await,
+                Self::Memory(cache) => cache.insert_persistent_key(key, value, ttl).await,
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{memory_cache::MemoryCache, *};
+
+    #[tokio::test]
+    async fn memory_cache_gets_the_inserted_keys() {
+        let cache = MemoryCache::with_limits("discmon", None, None);
+        cache.insert_key("pikachu", "25").await.unwrap();
+
+        assert_eq!(
+            cache.get_key("pikachu").await.unwrap().as_deref(),
+            Some("25")
+        );
+        assert_eq!(cache.get_key("raichu").await.unwrap(), None);
+    }
+
+    #[tokio::test]
+    async fn memory_cache_expires_the_keys() {
+        let cache = MemoryCache::with_limits("discmon", Some(Duration::from_millis(20)), None);
+        cache.insert_key("pikachu", "25").await.unwrap();
+        cache
+            .insert_persistent_key("spawn_table", "[]", Duration::from_secs(60))
+            .await
+            .unwrap();
+        cache
+            .insert_persistent_key("generations", "[]", Duration::from_millis(60))
+            .await
+            .unwrap();
+        tokio::time::sleep(Duration::from_millis(40)).await;
+
+        assert_eq!(cache.get_key("pikachu").await.unwrap(), None);
+        assert_eq!(
+            cache.get_key("spawn_table").await.unwrap().as_deref(),
+            Some("[]")
+        );
+        assert_eq!(
+            cache.get_key("generations").await.unwrap().as_deref(),
+            Some("[]")
+        );
+
+        // The persistent keys expire after their own time-to-live.
+        tokio::time::sleep(Duration::from_millis(40)).await;
+        assert_eq!(cache.get_key("generations").await.unwrap(), None);
+    }
+
+    #[tokio::test]
+    async fn memory_cache_evicts_the_oldest_keys() {
+        let cache = MemoryCache::with_limits("discmon", None, Some(2));
+        cache
+            .insert_persistent_key("spawn_table", "[]", Duration::from_secs(60))
+            .await
+            .unwrap();
+        for (key, value) in [("bulbasaur", "1"), ("ivysaur", "2"), ("venusaur", "3")] {
+            cache.insert_key(key, value).await.unwrap();
+        }
+
+        assert_eq!(cache.get_key("bulbasaur").await.unwrap(), None);
+        assert_eq!(
+            cache.get_key("ivysaur").await.unwrap().as_deref(),
+            Some("2")
+        );
+        assert_eq!(
+            cache.get_key("venusaur").await.unwrap().as_deref(),
+            Some("3")
+        );
+        assert_eq!(
+            cache.get_key("spawn_table").await.unwrap().as_deref(),
+            Some("[]")
+        );
+    }
+
+    #[tokio::test]
+    async fn memory_cache_replaces_a_key_without_evicting() {
+        let cache = MemoryCache::with_limits("discmon", None, Some(2));
+        cache.insert_key("bulbasaur", "1").await.unwrap();
+        cache.insert_key("ivysaur", "2").await.unwrap();
+        cache.insert_key("ivysaur", "two").await.unwrap();
+
+        assert_eq!(
+            cache.get_key("bulbasaur").await.unwrap().as_deref(),
+            Some("1")
+        );
+        assert_eq!(
+            cache.get_key("ivysaur").await.unwrap().as_deref(),
+            Some("two")
+        );
+    }
+}
@@ -1,9 +1,146 @@
 // Libs
 use async_trait::async_trait;
 use serde::{de::DeserializeOwned, Serialize};
+use std::collections::BTreeMap;
+use surrealdb::sql::Datetime;
 
 use crate::errors::DatabaseResult;
 
+// Types
+/**
+The variables bound to a query. Each key can be used in the query as `$key`.
+*/
+pub type QueryVars = BTreeMap<String, surrealdb::sql::Value>;
+
+// Transaction
+/**
+A struct to represent a database transaction.
+
+The statements are only sent to the database when the transaction is committed, and they're
+applied atomically: if any statement fails, none of them is applied.
+A transaction that is dropped without being committed is discarded.
+*/
+#[derive(Debug, Default)]
+pub struct Transaction {
+    statements: Vec<String>,
+    vars: QueryVars,
+}
+
+impl Transaction {
+    /**
+    A method to create a new empty transaction.
+    */
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /**
+    A method to add the insertion of a new record to the transaction.
+
+    # Parameters:
+    - `tb`: The name of the table to insert the record into.
+    - `id`: The ID of the record.
+    - `record`: The record to insert into the table.
+    */
+    pub fn insert<T>(&mut self, tb: &str, id: &str, record: T) -> DatabaseResult<()>
+    where
+        T: Serialize,
+    {
+        let index = self.statements.len();
+        let content = surrealdb::sql::to_value(record).map_err(surrealdb::Error::from)?;
+
+        self.statements.push(format!(
+            "CREATE type::thing($tb_{index}, $id_{index}) CONTENT $content_{index}"
+        ));
+        self.vars.insert(format!("tb_{}", index), tb.into());
+        self.vars.insert(format!("id_{}", index), id.into());
+        self.vars.insert(format!("content_{}", index), content);
+        Ok(())
+    }
+
+    /**
+    A method to add the deletion of a record to the transaction.
+
+    # Parameters:
+    - `tb`: The name of the table to delete the record from.
+    - `id`: The ID of the record.
+    */
+    pub fn delete(&mut self, tb: &str, id: &str) {
+        let index = self.statements.len();
+
+        self.statements
+            .push(format!("DELETE type::thing($tb_{index}, $id_{index})"));
+        self.vars.insert(format!("tb_{}", index), tb.into());
+        self.vars.insert(format!("id_{}", index), id.into());
+    }
+
+    /**
+    A method to add the update of a record to the transaction.
+
+    The transaction fails if the record was updated since it was read, so a concurrent change is
+    never overwritten.
+
+    # Parameters:
+    - `tb`: The name of the table of the record.
+    - `id`: The ID of the record.
+    - `record`: The new content of the record.
+    - `read_updated_at`: The `updated_at` field of the record when it was read.
+    */
+    pub fn update<T>(
+        &mut self,
+        tb: &str,
+        id: &str,
+        record: T,
+        read_updated_at: &Datetime,
+    ) -> DatabaseResult<()>
+    where
+        T: Serialize,
+    {
+        let index = self.statements.len();
+        let content = surrealdb::sql::to_value(record).map_err(surrealdb::Error::from)?;
+
+        self.statements.push(format!(
+            "IF type::thing($tb_{index}, $id_{index}).updated_at != $updated_at_{index} \
+             {{ THROW \"The record was changed by another transaction.\" }}"
+        ));
+        self.statements.push(format!(
+            "UPDATE type::thing($tb_{index}, $id_{index}) CONTENT $content_{index}"
+        ));
+        self.vars.insert(format!("tb_{}", index), tb.into());
+        self.vars.insert(format!("id_{}", index), id.into());
+        self.vars.insert(
+            format!("updated_at_{}", index),
+            read_updated_at.clone().into(),
+        );
+        self.vars.insert(format!("content_{}", index), content);
+        Ok(())
+    }
+
+    /**
+    A method to add the increment of a record's number field to the transaction.
+
+    The field is incremented by the database, so concurrent transactions can't overwrite each other.
+    The field's name is formatted into the statement, so it must never come from a user.
+
+    # Parameters:
+    - `tb`: The name of the table of the record.
+    - `id`: The ID of the record.
+    - `field`: The name of the field to increment.
+    - `amount`: The amount to add to the field. It can be negative.
+    */
+    pub fn increment(&mut self, tb: &str, id: &str, field: &str, amount: i64) {
+        let index = self.statements.len();
+
+        self.statements.push(format!(
+            "UPDATE type::thing($tb_{index}, $id_{index}) \
+             SET {field} += $amount_{index}, updated_at = time::now()"
+        ));
+        self.vars.insert(format!("tb_{}", index), tb.into());
+        self.vars.insert(format!("id_{}", index), id.into());
+        self.vars.insert(format!("amount_{}", index), amount.into());
+    }
+}
+
 // Database Trait
 /**
 A trait to represent a database. This trait is used to define the methods that a database must implement.
@@ -16,32 +153,52 @@ pub trait Database {
     async fn connect(&mut self) -> DatabaseResult<()>;
 
     /**
-    A method to run the database's migrations.
+    A method to apply the pending database's migrations, in order.
+
+    It'll fail if the database's schema is newer than the latest known migration.
     */
     async fn run_migrations(&self) -> DatabaseResult<()>;
 
+    /**
+    A method to revert the applied migrations newer than a version, in reverse order.
+
+    # Parameters:
+    - `version`: The schema version to roll back to.
+    */
+    async fn rollback_migrations(&self, version: u32) -> DatabaseResult<()>;
+
     /**
     A method to get multiple records from the database.
 
     If the query returns multiple records, it'll return the results from the first query.
+    The values must be passed as variables and never formatted into the query.
 
     # Parameters:
     - `query`: The query to get the records.
+    - `vars`: The variables to bind to the query.
     */
-    async fn query<T>(&self, query: &str) -> DatabaseResult<Vec<T>>
+    async fn query<T>(&self, query: &str, vars: QueryVars) -> DatabaseResult<Vec<T>>
     where
         T: DeserializeOwned + Send + Sync + Unpin;
 
-    // /**
-    // A method to get a record from the database.
+    /**
+    A method to atomically apply all the statements of a transaction.
 
-    // # Parameters:
-    // - `tb`: The name of the table to get the record from.
-    // - `id`: The ID of the record to get.
-    // */
-    // async fn get<T>(&self, tb: &str, id: &Ulid) -> DatabaseResult<Option<T>>
-    // where
-    //     T: DeserializeOwned + Send + Sync + Unpin;
+    # Parameters:
+    - `transaction`: The transaction to commit.
+    */
+    async fn commit(&self, transaction: Transaction) -> DatabaseResult<()>;
+
+    /**
+    A method to get a record from the database.
+
+    # Parameters:
+    - `tb`: The name of the table to get the record from.
+    - `id`: The ID of the record to get.
+    */
+    async fn get<T>(&self, tb: &str, id: &str) -> DatabaseResult<Option<T>>
+    where
+        T: DeserializeOwned + Send + Sync + Unpin;
 
     /**
     A method to insert a new record into the database.
@@ -54,116 +211,288 @@ pub trait Database {
     where
         T: DeserializeOwned + Serialize + Send + Sync + Unpin;
 
-    // /**
-    // A method to update a record in the database.
-
-    // # Parameters:
-    // - `tb`: The name of the table to update the record in.
-    // - `id`: The ID of the record to update.
-    // - `record`: The record to update the record with.
-    // */
-    // async fn update<T>(&self, tb: &str, id: &Id, record: T) -> DatabaseResult<()>
-    // where
-    //     T: Serialize + Send + Sync + Unpin;
-
-    // /**
-    // A method to delete a record from the database.
-
-    // # Parameters:
-    // - `tb`: The name of the table to delete the record from.
-    // - `id`: The ID of the record to delete.
-    // */
-    // async fn delete(&self, tb: &str, id: &Id) -> DatabaseResult<()>;
+    /**
+    A method to update a record in the database.
+
+    If the record doesn't exist or was updated since it was read, `None` is returned.
+
+    # Parameters:
+    - `tb`: The name of the table to update the record in.
+    - `id`: The ID of the record to update.
+    - `record`: The record to update the record with.
+    - `read_updated_at`: The `updated_at` field of the record when it was read.
+    */
+    async fn update<T>(
+        &self,
+        tb: &str,
+        id: &str,
+        record: T,
+        read_updated_at: &Datetime,
+    ) -> DatabaseResult<Option<T>>
+    where
+        T: DeserializeOwned + Serialize + Send + Sync + Unpin;
+
+    /**
+    A method to delete a record from the database.
+
+    # Parameters:
+    - `tb`: The name of the table to delete the record from.
+    - `id`: The ID of the record to delete.
+    */
+    async fn delete(&self, tb: &str, id: &str) -> DatabaseResult<()>;
 }
 
 // SurrealDB
 pub mod surreal_db {
     use surrealdb::{
-        engine::remote::ws::{Client, Ws},
+        engine::any::{self, Any},
         opt::auth,
         sql::{Id, Thing},
         Surreal,
     };
 
+    use tracing::info;
+
     use super::*;
-    use crate::utils::EnvManager;
+    use crate::{
+        migrations::{get_reverted_migrations, latest_version, MIGRATIONS},
+        utils::config::DatabaseConfig,
+    };
+
+    /**
+    An enum to represent the engine used to run the SurrealDB database.
+    */
+    #[derive(Clone, Debug)]
+    pub enum SurrealEngine {
+        /**
+        A remote SurrealDB server accessed through WebSocket. It holds the server's host and credentials.
+        */
+        Remote {
+            host: String,
+            username: String,
+            password: String,
+        },
+
+        /**
+        An embedded in-memory database. All the data is lost when the bot stops.
+        */
+        Memory,
+
+        /**
+        An embedded file-backed database. It holds the path to the database's directory.
+        */
+        File(String),
+    }
+
+    impl SurrealEngine {
+        /**
+        A method to get the engine defined in the configuration.
+
+        The available engines are `remote`, `memory` and `file`.
+
+        ## Parameters:
+        - `config`: The database's configuration.
+        */
+        pub fn from_config(config: &DatabaseConfig) -> DatabaseResult<Self> {
+            match config.engine.as_str() {
+                "remote" => Ok(Self::Remote {
+                    host: config
+                        .host
+                        .clone()
+                        .ok_or("The database host is not defined.")?,
+                    username: config
+                        .user
+                        .clone()
+                        .ok_or("The database user is not defined.")?,
+                    password: config
+                        .pass
+                        .clone()
+                        .ok_or("The database pass is not defined.")?,
+                }),
+                "memory" => Ok(Self::Memory),
+                "file" => Ok(Self::File(
+                    config
+                        .path
+                        .clone()
+                        .ok_or("The database path is not defined.")?,
+                )),
+                engine => Err(format!("The database engine '{}' is not supported.", engine).into()),
+            }
+        }
+
+        /**
+        A method to get the endpoint used to connect to the engine.
+        */
+        fn endpoint(&self) -> String {
+            match self {
+                Self::Remote { host, .. } => format!("ws://{}", host),
+                Self::Memory => String::from("mem://"),
+                Self::File(path) => format!("surrealkv://{}", path),
+            }
+        }
+    }
 
     /**
     A struct to represent a SurrealDB database.
     */
-    #[derive(Default)]
     pub struct SurrealDB {
-        conn: Option<Surreal<Client>>,
+        conn: Option<Surreal<Any>>,
+        engine: SurrealEngine,
+        namespace: String,
+        name: String,
+    }
+
+    impl SurrealDB {
+        /**
+        A method to create a new instance of the SurrealDB.
+
+        ## Parameters:
+        - `engine`: The engine to run the database on.
+        - `namespace`: The namespace of the database.
+        - `name`: The name of the database.
+        */
+        pub fn new(engine: SurrealEngine, namespace: &str, name: &str) -> Self {
+            Self {
+                conn: None,
+                engine,
+                namespace: namespace.to_string(),
+                name: name.to_string(),
+            }
+        }
+
+        /**
+        A method to get the versions of the applied migrations.
+
+        The migration table is created if it doesn't exist.
+        It'll fail if the database's schema is newer than the latest known migration.
+        */
+        async fn get_applied_migrations(&self) -> DatabaseResult<Vec<u32>> {
+            let conn = self.conn.as_ref().unwrap();
+            conn.query("DEFINE TABLE migration SCHEMAFULL")
+                .query("DEFINE FIELD version ON TABLE migration TYPE number")
+                .query("DEFINE FIELD name ON TABLE migration TYPE string")
+                .query("DEFINE FIELD applied_at ON TABLE migration TYPE datetime")
+                .query("DEFINE INDEX migration_version ON TABLE migration FIELDS version UNIQUE")
+                .await?
+                .check()?;
+
+            let mut response = conn
+                .query("SELECT VALUE version FROM migration ORDER BY version")
+                .await?;
+            let applied_versions: Vec<u32> = response.take(0)?;
+
+            let schema_version = applied_versions.last().copied().unwrap_or(0);
+            if schema_version > latest_version() {
+                return Err(format!(
+                    "The database schema (v{}) is newer than the latest supported schema (v{}).",
+                    schema_version,
+                    latest_version()
+                )
+                .into());
+            }
+
+            Ok(applied_versions)
+        }
     }
 
     #[async_trait]
     impl Database for SurrealDB {
         async fn connect(&mut self) -> DatabaseResult<()> {
             // Connect to the database.
-            let conn_url: String = EnvManager::get_var("DATABASE_HOST");
-            let client = surrealdb::Surreal::new::<Ws>(&conn_url).await?;
-
-            client
-                .signin(auth::Root {
-                    username: &EnvManager::get_var::<String>("DATABASE_USER"),
-                    password: &EnvManager::get_var::<String>("DATABASE_PASS"),
-                })
-                .await?;
+            let client = any::connect(self.engine.endpoint()).await?;
 
-            client
-                .use_ns(&EnvManager::get_var::<String>("DATABASE_NAMESPACE"))
-                .use_db(&EnvManager::get_var::<String>("DATABASE_NAME"))
-                .await?;
+            // Only the remote engine requires authentication.
+            if let SurrealEngine::Remote {
+                username, password, ..
+            } = &self.engine
+            {
+                client.signin(auth::Root { username, password }).await?;
+            }
+
+            client.use_ns(&self.namespace).use_db(&self.name).await?;
 
             self.conn = Some(client);
             Ok(())
         }
 
         async fn run_migrations(&self) -> DatabaseResult<()> {
-            let conn = self.conn.clone().unwrap();
+            let conn = self.conn.as_ref().unwrap();
+            let applied_versions = self.get_applied_migrations().await?;
 
-            conn.query("DEFINE TABLE trainer SCHEMAFULL").await?;
-            conn.query("DEFINE FIELD discord_id ON TABLE trainer TYPE string")
-                .await?;
-            conn.query("DEFINE FIELD discord_guild_id ON TABLE trainer TYPE string")
-                .await?;
-            conn.query("DEFINE FIELD created_at ON TABLE trainer TYPE datetime")
-                .await?;
-            conn.query("DEFINE FIELD updated_at ON TABLE trainer TYPE datetime")
-                .await?;
+            for migration in MIGRATIONS
+                .iter()
+                .filter(|migration| !applied_versions.contains(&migration.version))
+            {
+                info!(
+                    "Applying the migration #{} ({})...",
+                    migration.version, migration.name
+                );
+                conn.query("BEGIN TRANSACTION")
+                    .query(migration.up)
+                    .query("CREATE migration SET version = $version, name = $name, applied_at = time::now()")
+                    .query("COMMIT TRANSACTION")
+                    .bind(("version", migration.version))
+                    .bind(("name", migration.name))
+                    .await?
+                    .check()?;
+            }
 
-            conn.query("DEFINE TABLE pokemon SCHEMAFULL").await?;
-            conn.query("DEFINE FIELD trainer_id ON TABLE pokemon TYPE record")
-                .await?;
-            conn.query("DEFINE FIELD poke_id ON TABLE pokemon TYPE number")
-                .await?;
-            conn.query("DEFINE FIELD is_shiny ON TABLE pokemon TYPE bool")
-                .await?;
-            conn.query("DEFINE FIELD created_at ON TABLE pokemon TYPE datetime")
-                .await?;
-            conn.query("DEFINE FIELD updated_at ON TABLE pokemon TYPE datetime")
-                .await?;
+            Ok(())
+        }
+
+        async fn rollback_migrations(&self, version: u32) -> DatabaseResult<()> {
+            let conn = self.conn.as_ref().unwrap();
+            let applied_versions = self.get_applied_migrations().await?;
+
+            for migration in get_reverted_migrations(&applied_versions, version) {
+                info!(
+                    "Reverting the migration #{} ({})...",
+                    migration.version, migration.name
+                );
+                conn.query("BEGIN TRANSACTION")
+                    .query(migration.down)
+                    .query("DELETE migration WHERE version = $version")
+                    .query("COMMIT TRANSACTION")
+                    .bind(("version", migration.version))
+                    .await?
+                    .check()?;
+            }
 
             Ok(())
         }
 
-        async fn query<T>(&self, query: &str) -> DatabaseResult<Vec<T>>
+        async fn query<T>(&self, query: &str, vars: QueryVars) -> DatabaseResult<Vec<T>>
         where
             T: DeserializeOwned + Send + Sync + Unpin,
         {
-            let mut response = self.conn.as_ref().unwrap().query(query).await?;
+            let mut response = self.conn.as_ref().unwrap().query(query).bind(vars).await?;
             Ok(response.take(0)?)
         }
 
-        // async fn get<T>(&self, tb: &str, id: &Ulid) -> DatabaseResult<Option<T>>
-        // where
-        //     T: DeserializeOwned + Send + Sync + Unpin,
-        // {
-        //     // Get the record.
-        //     let locator = (tb, id.to_string());
-        //     let conn = self.conn.as_ref().unwrap();
-        //     Ok(conn.select(locator).await?)
-        // }
+        async fn commit(&self, transaction: Transaction) -> DatabaseResult<()> {
+            let conn = self.conn.as_ref().unwrap();
+            let mut query = conn.query("BEGIN TRANSACTION");
+            for statement in transaction.statements {
+                query = query.query(statement);
+            }
+
+            query
+                .query("COMMIT TRANSACTION")
+                .bind(transaction.vars)
+                .await?
+                .check()?;
+            Ok(())
+        }
+
+        async fn get<T>(&self, tb: &str, id: &str) -> DatabaseResult<Option<T>>
+        where
+            T: DeserializeOwned + Send + Sync + Unpin,
+        {
+            // Get the record.
+            let locator = (tb, id);
+            let conn = self.conn.as_ref().unwrap();
+            Ok(conn.select(locator).await?)
+        }
 
         async fn insert<T>(&self, tb: &str, id: &str, record: T) -> DatabaseResult<Option<T>>
         where
@@ -178,23 +507,109 @@ pub mod surreal_db {
             Ok(conn.insert::<Option<T>>(locator).content(record).await?)
         }
 
-        // async fn update<T>(&self, tb: &str, id: &Ulid, record: T) -> DatabaseResult<()>
-        // where
-        //     T: Serialize + Send + Sync + Unpin,
-        // {
-        //     // Update the record.
-        //     let locator = (tb, id.to_string());
-        //     let conn = self.conn.as_ref().unwrap();
-        //     conn.update::<Option<()>>(locator).content(record).await?;
-        //     Ok(())
-        // }
-
-        // async fn delete(&self, tb: &str, id: &Ulid) -> DatabaseResult<()> {
-        //     // Delete the record.
-        //     let locator = (tb, id.to_string());
-        //     let conn = self.conn.as_ref().unwrap();
-        //     conn.delete::<Option<()>>(locator).await?;
-        //     Ok(())
-        // }
+        async fn update<T>(
+            &self,
+            tb: &str,
+            id: &str,
+            record: T,
+            read_updated_at: &Datetime,
+        ) -> DatabaseResult<Option<T>>
+        where
+            T: DeserializeOwned + Serialize + Send + Sync + Unpin,
+        {
+            // The record is only updated if it wasn't changed since it was read, in a single
+            // statement, so a record deleted or updated concurrently is never overwritten.
+            let conn = self.conn.as_ref().unwrap();
+            let mut response = conn
+                .query(
+                    "UPDATE type::thing($tb, $id) CONTENT $content \
+                     WHERE updated_at = $updated_at RETURN AFTER",
+                )
+                .bind(("tb", tb))
+                .bind(("id", id))
+                .bind(("content", record))
+                .bind(("updated_at", read_updated_at.clone()))
+                .await?;
+            let mut records: Vec<T> = response.take(0)?;
+            Ok(records.pop())
+        }
+
+        async fn delete(&self, tb: &str, id: &str) -> DatabaseResult<()> {
+            // Delete the record.
+            let locator = (tb, id);
+            let conn = self.conn.as_ref().unwrap();
+            conn.delete::<Option<serde::de::IgnoredAny>>(locator)
+                .await?;
+            Ok(())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use surrealdb::sql::Thing;
+
+    use super::{surreal_db::*, *};
+    use crate::{migrations::latest_version, testing::unique_id};
+
+    /**
+    A method to get the versions of the migrations applied to a database.
+
+    ## Parameters:
+    - `database`: The database.
+    */
+    async fn get_versions(database: &SurrealDB) -> Vec<u32> {
+        database
+            .query(
+                "SELECT VALUE version FROM migration ORDER BY version",
+                QueryVars::new(),
+            )
+            .await
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn refuses_a_schema_newer_than_the_latest_migration() {
+        let mut database = SurrealDB::new(SurrealEngine::Memory, &unique_id(), &unique_id());
+        database.connect().await.unwrap();
+        database.run_migrations().await.unwrap();
+
+        database
+            .query::<Thing>(
+                "CREATE migration SET version = $version, name = 'unknown', applied_at = time::now() RETURN NONE",
+                QueryVars::from([(String::from("version"), (latest_version() + 1).into())]),
+            )
+            .await
+            .unwrap();
+
+        assert!(database.run_migrations().await.is_err());
+        assert!(database.rollback_migrations(0).await.is_err());
+        assert_eq!(
+            get_versions(&database).await.len() as u32,
+            latest_version() + 1
+        );
+    }
+
+    #[tokio::test]
+    async fn rollback_reverts_the_migrations_newer_than_the_version() {
+        let mut database = SurrealDB::new(SurrealEngine::Memory, &unique_id(), &unique_id());
+        database.connect().await.unwrap();
+        database.run_migrations().await.unwrap();
+
+        database.rollback_migrations(12).await.unwrap();
+        assert_eq!(
+            get_versions(&database).await,
+            (1..=12).collect::<Vec<u32>>()
+        );
+
+        // Every down step must leave a schema its previous migrations can be reverted from.
+        database.rollback_migrations(0).await.unwrap();
+        assert!(get_versions(&database).await.is_empty());
+
+        database.run_migrations().await.unwrap();
+        assert_eq!(
+            get_versions(&database).await,
+            (1..=latest_version()).collect::<Vec<u32>>()
+        );
     }
 }
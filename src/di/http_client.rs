@@ -1,9 +1,10 @@
 // Libs
 use async_trait::async_trait;
 use serde::de::DeserializeOwned;
-use tracing::debug;
+use tracing::{debug, info, warn};
 
 use crate::errors::{HTTPClientError, HTTPClientResult};
+use crate::utils::config::PokeAPIConfig;
 
 // Cache Trait.
 #[async_trait]
@@ -59,3 +60,226 @@ pub mod reqwest_httpclient {
         }
     }
 }
+
+// Local HTTP Client
+pub mod local_httpclient {
+    use std::{
+        fs,
+        path::{Path, PathBuf},
+    };
+
+    use super::*;
+    use crate::serializations::pokeapi::{
+        PokeAPIGeneration, PokeAPIGenerationCount, PokeAPIPokemon, PokeAPIPokemonEvolutionChain,
+        PokeAPIPokemonSpecies, PokeAPIPokemonSpeciesCount,
+    };
+
+    /**
+    A struct to represent a HTTP client that reads the resources from a local PokeAPI data dump.
+
+    The data dump must follow the PokeAPI's `api-data` layout, where each resource is stored in
+    a `<resource>/<id>/index.json` file.
+    */
+    pub struct LocalHTTPClient {
+        root: PathBuf,
+        base_url: String,
+    }
+
+    impl LocalHTTPClient {
+        /**
+        A method to create a new instance of the LocalHTTPClient.
+
+        ## Parameters:
+        - `root`: The directory where the data dump is stored.
+        - `base_url`: The PokeAPI's URL. It's stripped from the requested URLs.
+        */
+        pub fn new(root: impl Into<PathBuf>, base_url: &str) -> Self {
+            Self {
+                root: root.into(),
+                base_url: base_url.to_string(),
+            }
+        }
+
+        /**
+        A method to import a PokeAPI data dump into the client's directory.
+
+        Only the resources used by the bot are imported and each one is validated before being copied.
+        The invalid resources are skipped.
+
+        ## Parameters:
+        - `source`: The `api/v2` directory of the data dump to import.
+
+        ## Returns:
+        - The amount of imported resources.
+        */
+        pub fn import_dump(&self, source: &Path) -> HTTPClientResult<usize> {
+            info!(
+                "Importing the PokeAPI data dump from {}...",
+                source.display()
+            );
+            let mut imported = 0;
+
+            // The species' index is used to get the amount of pokemons.
+            self.import_resource_file::<PokeAPIPokemonSpeciesCount>(source, "pokemon-species")?;
+            imported += 1;
+
+            imported += self.import_resource::<PokeAPIPokemon>(source, "pokemon")?;
+            imported += self.import_resource::<PokeAPIPokemonSpecies>(source, "pokemon-species")?;
+            imported +=
+                self.import_resource::<PokeAPIPokemonEvolutionChain>(source, "evolution-chain")?;
+
+            // The generations' index is used to get the amount of generations.
+            self.import_resource_file::<PokeAPIGenerationCount>(source, "generation")?;
+            imported += 1;
+            imported += self.import_resource::<PokeAPIGeneration>(source, "generation")?;
+
+            info!("{} resources were imported.", imported);
+            Ok(imported)
+        }
+
+        /**
+        A method to import all the entries of a resource from a data dump.
+
+        ## Parameters:
+        - `source`: The `api/v2` directory of the data dump.
+        - `resource`: The name of the resource to import.
+        */
+        fn import_resource<T>(&self, source: &Path, resource: &str) -> HTTPClientResult<usize>
+        where
+            T: DeserializeOwned,
+        {
+            info!("Importing the {} resource...", resource);
+            let mut imported = 0;
+
+            for entry in fs::read_dir(source.join(resource))? {
+                let entry = entry?;
+                let identifier = entry.file_name().to_string_lossy().to_string();
+                if !entry.file_type()?.is_dir() || identifier.parse::<u32>().is_err() {
+                    continue;
+                }
+
+                let resource_path = format!("{}/{}", resource, identifier);
+                match self.import_resource_file::<T>(source, &resource_path) {
+                    Ok(()) => imported += 1,
+                    Err(e) => warn!("Skipping the resource {}: {}", resource_path, e),
+                }
+            }
+
+            Ok(imported)
+        }
+
+        /**
+        A method to validate and copy a single resource file from a data dump.
+
+        ## Parameters:
+        - `source`: The `api/v2` directory of the data dump.
+        - `resource_path`: The path of the resource, relative to the data dump.
+        */
+        fn import_resource_file<T>(
+            &self,
+            source: &Path,
+            resource_path: &str,
+        ) -> HTTPClientResult<()>
+        where
+            T: DeserializeOwned,
+        {
+            let content = fs::read_to_string(source.join(resource_path).join("index.json"))?;
+            serde_json::from_str::<T>(&content)?;
+
+            let destination = self.root.join(resource_path);
+            fs::create_dir_all(&destination)?;
+            fs::write(destination.join("index.json"), content)?;
+            Ok(())
+        }
+
+        /**
+        A method to get the path of the file that represents an URL.
+
+        ## Parameters:
+        - `url`: The URL of the resource. The PokeAPI's URL prefix is ignored.
+        */
+        fn resource_path(&self, url: &str) -> HTTPClientResult<PathBuf> {
+            let resource = url.strip_prefix(self.base_url.as_str()).unwrap_or(url);
+
+            let mut path = self.root.clone();
+            for segment in resource.split('/').filter(|segment| !segment.is_empty()) {
+                if segment == "." || segment == ".." {
+                    return Err(format!("The resource '{}' is not valid.", url).into());
+                }
+                path.push(segment);
+            }
+
+            path.push("index.json");
+            Ok(path)
+        }
+    }
+
+    #[async_trait]
+    impl HTTPClient for LocalHTTPClient {
+        async fn access<T>(&self, method: &str, url: &str) -> HTTPClientResult<T>
+        where
+            T: DeserializeOwned,
+        {
+            // Only the GET method is available for a data dump.
+            debug!("Reading the resource {} {}", method, url);
+            if method.to_uppercase() != "GET" {
+                return Err(HTTPClientError::MethodNotSupported);
+            }
+
+            // Read and parse the resource without blocking the runtime's workers.
+            let content = tokio::fs::read_to_string(self.resource_path(url)?).await?;
+            debug!("Parsing the resource");
+            Ok(serde_json::from_str::<T>(&content)?)
+        }
+    }
+}
+
+// HTTP Client Backend
+pub mod httpclient_backend {
+    use super::{local_httpclient::LocalHTTPClient, reqwest_httpclient::ReqwestHTTPClient, *};
+
+    /**
+    An enum to represent the HTTP client selected in the configuration.
+    */
+    pub enum HTTPClientBackend {
+        Reqwest(ReqwestHTTPClient),
+        Local(LocalHTTPClient),
+    }
+
+    impl HTTPClientBackend {
+        /**
+        A method to create the HTTP client defined in the configuration.
+
+        The available sources are `remote` and `local`.
+
+        ## Parameters:
+        - `config`: The PokeAPI's configuration.
+        */
+        pub fn new(config: &PokeAPIConfig) -> HTTPClientResult<Self> {
+            match config.source.as_str() {
+                "remote" => Ok(Self::Reqwest(ReqwestHTTPClient::new())),
+                "local" => {
+                    let data_dir = config
+                        .data_dir
+                        .as_deref()
+                        .ok_or("The PokeAPI data directory is not defined.")?;
+                    Ok(Self::Local(LocalHTTPClient::new(data_dir, &config.url)))
+                }
+                source => Err(format!("The PokeAPI source '{}' is not supported.", source).into()),
+            }
+        }
+    }
+
+    #[async_trait]
+    impl HTTPClient for HTTPClientBackend {
+        async fn access<T>(&self, method: &str, url: &str) -> HTTPClientResult<T>
+        where
+            T: DeserializeOwned,
+        {
+            match self {
+                Self::Reqwest(client) => client.access(method, url).await,
+                Self::Local(client) => client.access(method, url).await,
+            }
+        }
+    }
+}
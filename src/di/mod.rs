@@ -1,7 +1,12 @@
 // Libs
-pub use cache::{redis_cache::RedisCache, Cache};
-pub use database::{surreal_db::SurrealDB, Database};
-pub use http_client::{reqwest_httpclient::ReqwestHTTPClient, HTTPClient};
+pub use cache::{cache_backend::CacheBackend, Cache};
+pub use database::{
+    surreal_db::{SurrealDB, SurrealEngine},
+    Database, QueryVars, Transaction,
+};
+pub use http_client::{
+    httpclient_backend::HTTPClientBackend, local_httpclient::LocalHTTPClient, HTTPClient,
+};
 
 mod cache;
 mod database;
@@ -0,0 +1,42 @@
+// Libs
+
+// Types
+pub type ConfigResult<T> = Result<T, ConfigError>;
+
+// Config Error
+#[derive(Debug)]
+pub enum ConfigError {
+    Io(std::io::Error),
+    Toml(toml::de::Error),
+    InvalidKeys(Vec<String>),
+    NotInitialized,
+}
+
+impl From<std::io::Error> for ConfigError {
+    fn from(error: std::io::Error) -> Self {
+        ConfigError::Io(error)
+    }
+}
+
+impl From<toml::de::Error> for ConfigError {
+    fn from(error: toml::de::Error) -> Self {
+        ConfigError::Toml(error)
+    }
+}
+
+impl std::fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ConfigError::Io(e) => write!(f, "IO error: {}", e),
+            ConfigError::Toml(e) => write!(f, "TOML error: {}", e),
+            ConfigError::InvalidKeys(errors) => {
+                write!(f, "{} invalid configuration keys:", errors.len())?;
+                for error in errors {
+                    write!(f, "\n- {}", error)?;
+                }
+                Ok(())
+            }
+            ConfigError::NotInitialized => write!(f, "The configuration was not initialized."),
+        }
+    }
+}
@@ -6,13 +6,13 @@ pub type DatabaseResult<T> = Result<T, DatabaseError>;
 // Database Error
 #[derive(Debug)]
 pub enum DatabaseError {
-    SurrealDBError(surrealdb::Error),
+    SurrealDBError(Box<surrealdb::Error>),
     Other(String),
 }
 
 impl From<surrealdb::Error> for DatabaseError {
     fn from(error: surrealdb::Error) -> Self {
-        DatabaseError::SurrealDBError(error)
+        DatabaseError::SurrealDBError(Box::new(error))
     }
 }
 
@@ -1,5 +1,5 @@
 // Libs
-use super::{database_error::DatabaseError, PokeFinderError};
+use super::{database_error::DatabaseError, ConfigError, PokeFinderError};
 
 // Types
 pub type HandlerResult<T> = Result<T, HandlerError>;
@@ -10,6 +10,7 @@ pub enum HandlerError {
     PokeFinderError(PokeFinderError),
     SerenityError(serenity::Error),
     DatabaseError(DatabaseError),
+    ConfigError(ConfigError),
     Other(String),
 }
 
@@ -31,6 +32,12 @@ impl From<DatabaseError> for HandlerError {
     }
 }
 
+impl From<ConfigError> for HandlerError {
+    fn from(e: ConfigError) -> Self {
+        HandlerError::ConfigError(e)
+    }
+}
+
 impl From<String> for HandlerError {
     fn from(e: String) -> Self {
         HandlerError::Other(e)
@@ -49,6 +56,7 @@ impl std::fmt::Display for HandlerError {
             HandlerError::PokeFinderError(e) => write!(f, "PokeAPI Error: {:?}", e),
             HandlerError::DatabaseError(e) => write!(f, "Database Error: {:?}", e),
             HandlerError::SerenityError(e) => write!(f, "Serenity Error: {:?}", e),
+            HandlerError::ConfigError(e) => write!(f, "Config Error: {}", e),
             HandlerError::Other(e) => write!(f, "Other Error: {:?}", e),
         }
     }
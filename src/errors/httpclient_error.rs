@@ -7,6 +7,8 @@ pub type HTTPClientResult<T> = Result<T, HTTPClientError>;
 #[derive(Debug)]
 pub enum HTTPClientError {
     Reqwest(reqwest::Error),
+    Io(std::io::Error),
+    SerdeJson(serde_json::Error),
     MethodNotSupported,
     Other(String),
 }
@@ -17,6 +19,18 @@ impl From<reqwest::Error> for HTTPClientError {
     }
 }
 
+impl From<std::io::Error> for HTTPClientError {
+    fn from(error: std::io::Error) -> Self {
+        HTTPClientError::Io(error)
+    }
+}
+
+impl From<serde_json::Error> for HTTPClientError {
+    fn from(error: serde_json::Error) -> Self {
+        HTTPClientError::SerdeJson(error)
+    }
+}
+
 impl From<String> for HTTPClientError {
     fn from(error: String) -> Self {
         HTTPClientError::Other(error)
@@ -33,6 +47,8 @@ impl std::fmt::Display for HTTPClientError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             HTTPClientError::Reqwest(e) => write!(f, "Reqwest error: {}", e),
+            HTTPClientError::Io(e) => write!(f, "IO error: {}", e),
+            HTTPClientError::SerdeJson(e) => write!(f, "Serde JSON error: {}", e),
             HTTPClientError::MethodNotSupported => write!(f, "Method not supported."),
             HTTPClientError::Other(e) => write!(f, "Other error: {}", e),
         }
@@ -1,10 +1,12 @@
 pub use cache_error::{CacheError, CacheResult};
+pub use config_error::{ConfigError, ConfigResult};
 pub use database_error::DatabaseResult;
 pub use handler_error::HandlerResult;
 pub use httpclient_error::{HTTPClientError, HTTPClientResult};
 pub use pokefinder_error::{PokeFinderError, PokeFinderResult};
 
 mod cache_error;
+mod config_error;
 mod database_error;
 mod handler_error;
 mod httpclient_error;
@@ -0,0 +1,226 @@
+// Libs
+use serenity::all::{
+    CommandInteraction, Context, CreateInteractionResponse, CreateInteractionResponseMessage,
+    Timestamp,
+};
+use std::sync::Arc;
+use surrealdb::sql::Datetime;
+use tracing::{info, warn};
+
+use crate::{
+    di::Transaction,
+    errors::HandlerResult,
+    messages::{get_msg_daily_already_claimed, get_msg_daily_claimed},
+    models::{BallType, DBDailyClaim, DBLedgerEntry, DBModel, DBTrainer, LedgerReason},
+    services::DATABASE_SERVICE,
+    utils::{config::Config, economy::get_daily_reward, trainer::get_trainer_inventory},
+};
+
+// Daily Handler
+/**
+A struct to handle the `/daily` command, which gives a reward to a trainer once per UTC day.
+*/
+pub struct DailyHandler {
+    ctx: Arc<Context>,
+    command: CommandInteraction,
+}
+
+impl DailyHandler {
+    /**
+    A method to create a new DailyHandler.
+    */
+    pub fn new(ctx: Arc<Context>, command: CommandInteraction) -> Self {
+        Self { ctx, command }
+    }
+
+    /**
+    A method to handle the daily command.
+
+    The streak grows when the trainer claims the reward on consecutive days and restarts when
+    they miss one. The claim, the coins and the balls are saved in a single transaction.
+    */
+    pub async fn handle(&self) -> HandlerResult<()> {
+        info!("Handling the daily command...");
+        let guild_id = self
+            .command
+            .guild_id
+            .ok_or("Guild ID not found.")?
+            .to_string();
+        let user_id = self.command.user.id.to_string();
+        let trainer = DBTrainer::find_by_discord_id(&user_id, &guild_id).await?;
+
+        let today = DBDailyClaim::get_day(&Datetime::default());
+        let latest_claim = DBDailyClaim::get_latest_by_trainer_id(&trainer.id).await?;
+        if let Some(claim) = latest_claim.as_ref().filter(|claim| claim.day == today) {
+            info!("The daily reward was already claimed today. Ignoring...");
+            return self.send_already_claimed(today, claim.streak).await;
+        }
+        let (streak, broken_streak) = get_streak(latest_claim.as_ref(), today);
+
+        info!("Claiming the daily reward with a {} day streak...", streak);
+        let config = &Config::global()?.bot;
+        let reward = get_daily_reward(config, streak);
+        let poke_balls = config.daily_poke_balls;
+        let claim = DBDailyClaim::new(&trainer.id, today, streak, reward, poke_balls);
+        let mut transaction = Transaction::new();
+        claim.create_in_transaction(&mut transaction)?;
+        if reward > 0 {
+            let details = format!("Daily reward with a {} day streak", streak);
+            DBLedgerEntry::new(&trainer.id, reward as i64, LedgerReason::Daily, details)
+                .apply_in_transaction(&mut transaction)?;
+        }
+        if poke_balls > 0 {
+            let inventory = get_trainer_inventory(config, &trainer).await?;
+            let item = inventory
+                .iter()
+                .find(|item| item.ball == BallType::Poke)
+                .ok_or("Inventory item not found.")?;
+            transaction.increment(
+                "inventory",
+                &item.id.id.to_string(),
+                "quantity",
+                poke_balls as i64,
+            );
+        }
+
+        // The claim's ID is unique per day, so a concurrent claim makes the transaction fail.
+        let committed = DATABASE_SERVICE
+            .clone()
+            .commit_transaction(transaction)
+            .await;
+        if let Err(e) = committed {
+            let latest_claim = DBDailyClaim::get_latest_by_trainer_id(&trainer.id).await?;
+            let Some(latest_claim) = latest_claim.filter(|claim| claim.day == today) else {
+                return Err(e.into());
+            };
+
+            warn!("The daily reward was claimed concurrently. {}", e);
+            return self.send_already_claimed(today, latest_claim.streak).await;
+        }
+
+        let balance = DBTrainer::get_by_discord_id(&user_id, &guild_id)
+            .await?
+            .ok_or("Trainer not found.")?
+            .balance;
+        let message = get_msg_daily_claimed(&claim, broken_streak, balance);
+        self.send_response(message).await
+    }
+
+    /**
+    A method to tell the user they already claimed today's reward.
+
+    ## Parameters:
+    - `today`: The current UTC day.
+    - `streak`: The current streak of the user.
+    */
+    async fn send_already_claimed(&self, today: i64, streak: u32) -> HandlerResult<()> {
+        let next_claim_at = Timestamp::from_unix_timestamp(DBDailyClaim::get_day_start(today + 1))
+            .map_err(|_| "Invalid next claim timestamp.")?;
+        let message = get_msg_daily_already_claimed(streak, next_claim_at);
+        self.send_response(message).await
+    }
+
+    /**
+    A method to respond to the command.
+
+    ## Parameters:
+    - `message`: The response message.
+    */
+    async fn send_response(&self, message: CreateInteractionResponseMessage) -> HandlerResult<()> {
+        let response = CreateInteractionResponse::Message(message);
+        self.command.create_response(&self.ctx, response).await?;
+        Ok(())
+    }
+}
+
+// Functions
+/**
+A method to get the streak of a trainer who claims the daily reward.
+
+## Parameters:
+- `latest_claim`: The most recent daily reward claimed by the trainer, before today.
+- `today`: The current UTC day.
+
+## Returns:
+- A (streak, broken_streak) tuple. The broken streak is the streak that was lost by missing a day.
+*/
+fn get_streak(latest_claim: Option<&DBDailyClaim>, today: i64) -> (u32, Option<u32>) {
+    match latest_claim {
+        Some(claim) if claim.day == today - 1 => (claim.streak + 1, None),
+        Some(claim) if claim.streak > 1 => (1, Some(claim.streak)),
+        _ => (1, None),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use surrealdb::sql::Thing;
+
+    use super::*;
+    use crate::testing::{run_test, unique_id};
+
+    const TODAY: i64 = 20_000;
+
+    /**
+    A method to get a daily claim of a new trainer.
+
+    ## Parameters:
+    - `day`: The UTC day of the claim.
+    - `streak`: The streak of the claim.
+    */
+    fn get_claim(day: i64, streak: u32) -> DBDailyClaim {
+        let trainer_id = Thing::from(("trainer", unique_id().as_str()));
+        DBDailyClaim::new(&trainer_id, day, streak, 100, 0)
+    }
+
+    #[test]
+    fn get_streak_continues_the_streak_of_yesterday() {
+        assert_eq!(get_streak(Some(&get_claim(TODAY - 1, 1)), TODAY), (2, None));
+        assert_eq!(get_streak(Some(&get_claim(TODAY - 1, 6)), TODAY), (7, None));
+    }
+
+    #[test]
+    fn get_streak_resets_the_streak_after_a_missed_day() {
+        assert_eq!(get_streak(None, TODAY), (1, None));
+        assert_eq!(get_streak(Some(&get_claim(TODAY - 2, 1)), TODAY), (1, None));
+        assert_eq!(
+            get_streak(Some(&get_claim(TODAY - 2, 6)), TODAY),
+            (1, Some(6))
+        );
+        assert_eq!(
+            get_streak(Some(&get_claim(TODAY - 30, 3)), TODAY),
+            (1, Some(3))
+        );
+    }
+
+    #[test]
+    fn a_second_claim_on_the_same_day_is_rejected() {
+        run_test(async {
+            let claim = get_claim(TODAY, 1);
+            let db_svc = DATABASE_SERVICE.clone();
+            let mut transaction = Transaction::new();
+            claim.create_in_transaction(&mut transaction).unwrap();
+            db_svc.commit_transaction(transaction).await.unwrap();
+
+            // The ID is made of the trainer and the day, so a concurrent claim can't be saved.
+            let second_claim = DBDailyClaim::new(&claim.trainer_id, TODAY, 1, 100, 0);
+            assert_eq!(second_claim.id, claim.id);
+            let mut transaction = Transaction::new();
+            second_claim
+                .create_in_transaction(&mut transaction)
+                .unwrap();
+            assert!(db_svc.commit_transaction(transaction).await.is_err());
+
+            // The next day can still be claimed.
+            let next_claim = DBDailyClaim::new(&claim.trainer_id, TODAY + 1, 2, 100, 0);
+            let mut transaction = Transaction::new();
+            next_claim.create_in_transaction(&mut transaction).unwrap();
+            db_svc.commit_transaction(transaction).await.unwrap();
+            let latest_claim = DBDailyClaim::get_latest_by_trainer_id(&claim.trainer_id)
+                .await
+                .unwrap()
+                .unwrap();
+            assert_eq!(latest_claim.streak, 2);
+        });
+    }
+}
@@ -7,6 +7,7 @@ use super::poke_spawn::PokeSpawnHandler;
 use crate::{
     errors::{HandlerError, HandlerResult},
     messages::get_dev_error_msg_poke_spawn,
+    utils::spawn_model::SpawnModel,
 };
 
 // DevCommandsHandler
@@ -99,10 +100,15 @@ impl DevCommandsHandler {
         // Create the PokeSpawnHandler with custom values.
         let shiny_rate = if shiny_rate { 1 } else { 10000 };
         let mut poke_spawn_handler =
-            PokeSpawnHandler::new(self.ctx.clone(), self.channel_id, self.guild_id);
+            PokeSpawnHandler::new(self.ctx.clone(), self.channel_id, self.guild_id, None).await?;
+        poke_spawn_handler.is_spawn_channel = true;
         poke_spawn_handler.bot_poke_spawn_rate = 1;
+        poke_spawn_handler.bot_poke_spawn_model = SpawnModel::Message;
         poke_spawn_handler.bot_poke_flee_time_secs = flee_time_secs;
         poke_spawn_handler.bot_poke_shiny_rate = shiny_rate;
+        poke_spawn_handler.bot_poke_spawn_cooldown_secs = 0;
+        poke_spawn_handler.bot_poke_max_spawns_per_channel = 0;
+        poke_spawn_handler.bot_poke_max_spawns_per_guild = 0;
 
         // Generate the Send the message to the channel.
         poke_spawn_handler.handle().await?;
@@ -1,18 +1,42 @@
 // Libs
 use async_trait::async_trait;
 use serenity::all::{
-    Command, CommandOptionType, Context, CreateCommand, CreateCommandOption, Interaction, Message,
+    ChannelType, Command, CommandOptionType, Context, CreateCommand, CreateCommandOption,
+    Interaction, Message, Permissions,
+};
+use std::sync::{
+    atomic::{AtomicBool, Ordering},
+    Arc,
 };
-use std::sync::Arc;
 use tracing::{error, info, Instrument};
 
-use crate::handlers::pokedex::PokedexHandler;
+use crate::{
+    handlers::{
+        daily::DailyHandler,
+        guild_config::GuildConfigHandler,
+        inventory::InventoryHandler,
+        pokedex::PokedexHandler,
+        shop::{ShopHandler, MAX_PURCHASE_QUANTITY},
+        spawn_channels::SpawnChannelsHandler,
+        spawn_generations::SpawnGenerationsHandler,
+        spawns::{SpawnsHandler, MAX_RECENT_SPAWNS},
+        trade::TradeHandler,
+    },
+    models::{BallType, DBActiveSpawn, GUILD_SETTING_KEYS},
+};
 
 use super::poke_spawn::PokeSpawnHandler;
 
 #[cfg(feature = "dev_commands")]
 use super::dev_commands::DevCommandsHandler;
 
+// Data
+/**
+If the spawns that were active when the bot stopped were already recovered.
+The `ready` event is sent again on reconnections, while the current spawns are still being handled.
+*/
+static SPAWNS_RECOVERED: AtomicBool = AtomicBool::new(false);
+
 // Event Handler
 pub struct EventHandler;
 
@@ -37,6 +61,286 @@ impl serenity::all::EventHandler for EventHandler {
         if let Err(e) = Command::create_global_command(&ctx.http, pokedex_command).await {
             error!("Error creating the pokedex command: {:?}", e);
         }
+
+        let inventory_command = CreateCommand::new("inventory")
+            .description("A command to show the balls in your inventory")
+            .dm_permission(false);
+        if let Err(e) = Command::create_global_command(&ctx.http, inventory_command).await {
+            error!("Error creating the inventory command: {:?}", e);
+        }
+
+        let item_option = BallType::ALL.iter().fold(
+            CreateCommandOption::new(CommandOptionType::String, "item", "The item to buy")
+                .required(true),
+            |option, ball| option.add_string_choice(ball.get_name(), ball.as_str()),
+        );
+        let shop_command = CreateCommand::new("shop")
+            .description("A command to spend your coins on balls")
+            .dm_permission(false)
+            .add_option(CreateCommandOption::new(
+                CommandOptionType::SubCommand,
+                "list",
+                "Show the items sold in the shop and your balance",
+            ))
+            .add_option(
+                CreateCommandOption::new(CommandOptionType::SubCommand, "buy", "Buy an item")
+                    .add_sub_option(item_option)
+                    .add_sub_option(
+                        CreateCommandOption::new(
+                            CommandOptionType::Integer,
+                            "quantity",
+                            "The amount to buy",
+                        )
+                        .min_int_value(1)
+                        .max_int_value(MAX_PURCHASE_QUANTITY),
+                    ),
+            );
+        if let Err(e) = Command::create_global_command(&ctx.http, shop_command).await {
+            error!("Error creating the shop command: {:?}", e);
+        }
+
+        let daily_command = CreateCommand::new("daily")
+            .description("A command to claim your daily reward")
+            .dm_permission(false);
+        if let Err(e) = Command::create_global_command(&ctx.http, daily_command).await {
+            error!("Error creating the daily command: {:?}", e);
+        }
+
+        let trade_command = CreateCommand::new("trade")
+            .description("A command to trade pokemons with another trainer")
+            .dm_permission(false)
+            .add_option(
+                CreateCommandOption::new(
+                    CommandOptionType::User,
+                    "user",
+                    "The trainer to trade with",
+                )
+                .required(true),
+            );
+        if let Err(e) = Command::create_global_command(&ctx.http, trade_command).await {
+            error!("Error creating the trade command: {:?}", e);
+        }
+
+        // Only the users who can manage the server can see the config command.
+        let key_option = |required: bool| {
+            GUILD_SETTING_KEYS.iter().fold(
+                CreateCommandOption::new(CommandOptionType::String, "key", "The setting's key")
+                    .required(required),
+                |option, key| option.add_string_choice(*key, *key),
+            )
+        };
+        let config_command = CreateCommand::new("config")
+            .description("A command to manage the bot's settings in this server")
+            .default_member_permissions(Permissions::MANAGE_GUILD)
+            .dm_permission(false)
+            .add_option(
+                CreateCommandOption::new(
+                    CommandOptionType::SubCommand,
+                    "get",
+                    "Show the bot's settings in this server",
+                )
+                .add_sub_option(key_option(false)),
+            )
+            .add_option(
+                CreateCommandOption::new(
+                    CommandOptionType::SubCommand,
+                    "set",
+                    "Override a bot's setting in this server",
+                )
+                .add_sub_option(key_option(true))
+                .add_sub_option(
+                    CreateCommandOption::new(
+                        CommandOptionType::String,
+                        "value",
+                        "The setting's new value",
+                    )
+                    .required(true),
+                ),
+            )
+            .add_option(
+                CreateCommandOption::new(
+                    CommandOptionType::SubCommand,
+                    "reset",
+                    "Reset a bot's setting, or all of them, to the default in this server",
+                )
+                .add_sub_option(key_option(false)),
+            );
+        if let Err(e) = Command::create_global_command(&ctx.http, config_command).await {
+            error!("Error creating the config command: {:?}", e);
+        }
+
+        // Only the users who can manage the server can see the spawn-channels command.
+        let channel_option = |required: bool| {
+            CreateCommandOption::new(CommandOptionType::Channel, "channel", "The text channel")
+                .channel_types(vec![ChannelType::Text])
+                .required(required)
+        };
+        let spawn_channels_command = CreateCommand::new("spawn-channels")
+            .description("A command to manage where the pokémons can spawn in this server")
+            .default_member_permissions(Permissions::MANAGE_GUILD)
+            .dm_permission(false)
+            .add_option(CreateCommandOption::new(
+                CommandOptionType::SubCommand,
+                "list",
+                "Show where the pokémons can spawn in this server",
+            ))
+            .add_option(
+                CreateCommandOption::new(
+                    CommandOptionType::SubCommand,
+                    "mode",
+                    "Choose how the listed channels are used",
+                )
+                .add_sub_option(
+                    CreateCommandOption::new(CommandOptionType::String, "mode", "The mode")
+                        .add_string_choice("Every channel", "all")
+                        .add_string_choice("Only the listed channels", "allow")
+                        .add_string_choice("Every channel but the listed ones", "deny")
+                        .required(true),
+                ),
+            )
+            .add_option(
+                CreateCommandOption::new(
+                    CommandOptionType::SubCommand,
+                    "add",
+                    "Add a channel to the list",
+                )
+                .add_sub_option(channel_option(true)),
+            )
+            .add_option(
+                CreateCommandOption::new(
+                    CommandOptionType::SubCommand,
+                    "remove",
+                    "Remove a channel from the list",
+                )
+                .add_sub_option(channel_option(true)),
+            )
+            .add_option(
+                CreateCommandOption::new(
+                    CommandOptionType::SubCommand,
+                    "wild-area",
+                    "Spawn all the pokémons in a channel. Leave it empty to disable it",
+                )
+                .add_sub_option(channel_option(false)),
+            );
+        if let Err(e) = Command::create_global_command(&ctx.http, spawn_channels_command).await {
+            error!("Error creating the spawn-channels command: {:?}", e);
+        }
+
+        // Only the users who can manage the server can see the spawn-generations command.
+        let generation_option = || {
+            CreateCommandOption::new(
+                CommandOptionType::String,
+                "generation",
+                "The generation's number, name or region (e.g. 1, generation-i or kanto)",
+            )
+            .required(true)
+        };
+        let spawn_generations_command = CreateCommand::new("spawn-generations")
+            .description("A command to manage which generations of pokémons spawn in this server")
+            .default_member_permissions(Permissions::MANAGE_GUILD)
+            .dm_permission(false)
+            .add_option(CreateCommandOption::new(
+                CommandOptionType::SubCommand,
+                "list",
+                "Show which generations can spawn in this server",
+            ))
+            .add_option(
+                CreateCommandOption::new(
+                    CommandOptionType::SubCommand,
+                    "enable",
+                    "Let the pokémons of a generation spawn",
+                )
+                .add_sub_option(generation_option()),
+            )
+            .add_option(
+                CreateCommandOption::new(
+                    CommandOptionType::SubCommand,
+                    "disable",
+                    "Stop the pokémons of a generation from spawning",
+                )
+                .add_sub_option(generation_option()),
+            )
+            .add_option(
+                CreateCommandOption::new(
+                    CommandOptionType::SubCommand,
+                    "only",
+                    "Only let the pokémons of a generation spawn",
+                )
+                .add_sub_option(generation_option()),
+            )
+            .add_option(CreateCommandOption::new(
+                CommandOptionType::SubCommand,
+                "reset",
+                "Let the pokémons of every generation spawn",
+            ));
+        if let Err(e) = Command::create_global_command(&ctx.http, spawn_generations_command).await {
+            error!("Error creating the spawn-generations command: {:?}", e);
+        }
+
+        // Only the moderators can see the spawns command.
+        let spawns_command = CreateCommand::new("spawns")
+            .description("A command to inspect the pokémons that spawned in this server")
+            .default_member_permissions(Permissions::MANAGE_MESSAGES)
+            .dm_permission(false)
+            .add_option(
+                CreateCommandOption::new(
+                    CommandOptionType::SubCommand,
+                    "recent",
+                    "Show the most recent spawns and how they ended",
+                )
+                .add_sub_option(
+                    CreateCommandOption::new(
+                        CommandOptionType::Integer,
+                        "limit",
+                        "The amount of spawns to show",
+                    )
+                    .min_int_value(1)
+                    .max_int_value(MAX_RECENT_SPAWNS.into()),
+                ),
+            )
+            .add_option(
+                CreateCommandOption::new(
+                    CommandOptionType::SubCommand,
+                    "rates",
+                    "Show the chance of each rarity tier, or of a pokémon, to spawn",
+                )
+                .add_sub_option(CreateCommandOption::new(
+                    CommandOptionType::String,
+                    "pokemon",
+                    "The name or the ID of a pokémon",
+                )),
+            );
+        if let Err(e) = Command::create_global_command(&ctx.http, spawns_command).await {
+            error!("Error creating the spawns command: {:?}", e);
+        }
+
+        // Recover the spawns that were active when the bot stopped.
+        if SPAWNS_RECOVERED.load(Ordering::SeqCst) {
+            return;
+        }
+        let active_spawns = match DBActiveSpawn::get_all().await {
+            Ok(active_spawns) => active_spawns,
+            Err(e) => {
+                error!("Error getting the active spawns: {:?}", e);
+                return;
+            }
+        };
+
+        // The spawns are only marked as recovered once they're read, so a failed read is retried
+        // on the next `ready` event. A concurrent `ready` event may have read them too.
+        if SPAWNS_RECOVERED.swap(true, Ordering::SeqCst) {
+            return;
+        }
+        info!("Recovering {} active spawns...", active_spawns.len());
+        let ctx = Arc::new(ctx);
+        for active_spawn in active_spawns {
+            let ctx = ctx.clone();
+            tokio::spawn(async move {
+                if let Err(e) = PokeSpawnHandler::recover(ctx, active_spawn).await {
+                    error!("Error recovering the spawn: {:?}", e);
+                }
+            });
+        }
     }
 
     /**
@@ -63,11 +367,62 @@ impl serenity::all::EventHandler for EventHandler {
         let _guard = span.enter();
 
         info!("A user started a new command.");
-        if command.data.name.as_str() == "pokedex" {
-            let pokedex_handler = PokedexHandler::new(ctx, command);
-            if let Err(e) = pokedex_handler.handle().in_current_span().await {
-                error!("Error handling pokedex command: {:?}", e);
+        match command.data.name.as_str() {
+            "pokedex" => {
+                let pokedex_handler = PokedexHandler::new(ctx, command);
+                if let Err(e) = pokedex_handler.handle().in_current_span().await {
+                    error!("Error handling pokedex command: {:?}", e);
+                }
+            }
+            "inventory" => {
+                let inventory_handler = InventoryHandler::new(ctx, command);
+                if let Err(e) = inventory_handler.handle().in_current_span().await {
+                    error!("Error handling inventory command: {:?}", e);
+                }
+            }
+            "shop" => {
+                let shop_handler = ShopHandler::new(ctx, command);
+                if let Err(e) = shop_handler.handle().in_current_span().await {
+                    error!("Error handling shop command: {:?}", e);
+                }
+            }
+            "daily" => {
+                let daily_handler = DailyHandler::new(ctx, command);
+                if let Err(e) = daily_handler.handle().in_current_span().await {
+                    error!("Error handling daily command: {:?}", e);
+                }
+            }
+            "trade" => {
+                let trade_handler = TradeHandler::new(ctx, command);
+                if let Err(e) = trade_handler.handle().in_current_span().await {
+                    error!("Error handling trade command: {:?}", e);
+                }
+            }
+            "config" => {
+                let config_handler = GuildConfigHandler::new(ctx, command);
+                if let Err(e) = config_handler.handle().in_current_span().await {
+                    error!("Error handling config command: {:?}", e);
+                }
+            }
+            "spawn-channels" => {
+                let spawn_channels_handler = SpawnChannelsHandler::new(ctx, command);
+                if let Err(e) = spawn_channels_handler.handle().in_current_span().await {
+                    error!("Error handling spawn-channels command: {:?}", e);
+                }
             }
+            "spawn-generations" => {
+                let spawn_generations_handler = SpawnGenerationsHandler::new(ctx, command);
+                if let Err(e) = spawn_generations_handler.handle().in_current_span().await {
+                    error!("Error handling spawn-generations command: {:?}", e);
+                }
+            }
+            "spawns" => {
+                let spawns_handler = SpawnsHandler::new(ctx, command);
+                if let Err(e) = spawns_handler.handle().in_current_span().await {
+                    error!("Error handling spawns command: {:?}", e);
+                }
+            }
+            _ => {}
         };
     }
 
@@ -107,9 +462,21 @@ impl serenity::all::EventHandler for EventHandler {
         }
 
         // Pass the handle responsibility to the PokeSpawnHandler.
-        let pokespawn_handler =
-            PokeSpawnHandler::new(ctx.clone(), msg.channel_id, msg.guild_id.unwrap());
-        if let Err(e) = pokespawn_handler.handle().await {
+        let pokespawn_handler = match PokeSpawnHandler::new(
+            ctx.clone(),
+            msg.channel_id,
+            msg.guild_id.unwrap(),
+            Some(msg.author.id),
+        )
+        .await
+        {
+            Ok(pokespawn_handler) => pokespawn_handler,
+            Err(e) => {
+                error!("Error getting the guild settings: {:?}", e);
+                return;
+            }
+        };
+        if let Err(e) = pokespawn_handler.handle_message(&msg).await {
             error!("Error handling message: {:?}", e);
         }
     }
@@ -0,0 +1,184 @@
+// Libs
+use serenity::all::{
+    CommandInteraction, Context, CreateInteractionResponse, CreateInteractionResponseMessage,
+};
+use std::sync::Arc;
+use tracing::info;
+
+use crate::{
+    errors::HandlerResult,
+    messages::{
+        get_msg_config_conflict, get_msg_config_invalid_value, get_msg_config_missing_permission,
+        get_msg_config_reset, get_msg_config_settings, get_msg_config_updated,
+    },
+    models::{DBGuildSettings, GUILD_SETTING_KEYS},
+    utils::{
+        config::{BotConfig, Config},
+        guild_settings::{GuildSettings, GUILD_SETTINGS_CACHE},
+        interaction::{can_manage_guild, get_string_option, get_subcommand},
+    },
+};
+
+// Guild Config Handler
+/**
+A struct to handle the `/config` command, which manages the settings of a guild.
+*/
+pub struct GuildConfigHandler {
+    ctx: Arc<Context>,
+    command: CommandInteraction,
+}
+
+impl GuildConfigHandler {
+    /**
+    A method to create a new GuildConfigHandler.
+    */
+    pub fn new(ctx: Arc<Context>, command: CommandInteraction) -> Self {
+        Self { ctx, command }
+    }
+
+    /**
+    A method to handle the config command.
+    */
+    pub async fn handle(&self) -> HandlerResult<()> {
+        info!("Handling the config command...");
+
+        // The command is hidden from other users by Discord, but the permission is checked anyway.
+        if !can_manage_guild(&self.command) {
+            info!("The user can't manage the guild. Ignoring...");
+            return self
+                .send_response(get_msg_config_missing_permission())
+                .await;
+        }
+
+        let guild_id = self
+            .command
+            .guild_id
+            .ok_or("Guild ID not found.")?
+            .to_string();
+        let overrides = DBGuildSettings::get_by_guild_id(&guild_id).await?;
+
+        let options = self.command.data.options();
+        let (subcommand, options) = get_subcommand(&options).ok_or("Subcommand not found.")?;
+
+        let key = get_string_option(options, "key");
+        let message = match subcommand {
+            "get" => self.get_settings(&Config::global()?.bot, overrides.as_ref(), key),
+            "set" => {
+                let key = key.ok_or("Key not found.")?;
+                let value = get_string_option(options, "value").ok_or("Value not found.")?;
+                self.set_setting(&guild_id, overrides, key, value).await?
+            }
+            "reset" => self.reset_settings(overrides, key).await?,
+            _ => return Err(format!("Unknown subcommand: {}", subcommand).into()),
+        };
+
+        self.send_response(message).await
+    }
+
+    /**
+    A method to mount the message with the effective settings of the guild.
+
+    ## Parameters:
+    - `config`: The bot's configuration.
+    - `overrides`: The guild's settings in the database, if any.
+    - `key`: The setting to show. If `None`, all the settings are shown.
+    */
+    fn get_settings(
+        &self,
+        config: &BotConfig,
+        overrides: Option<&DBGuildSettings>,
+        key: Option<&str>,
+    ) -> CreateInteractionResponseMessage {
+        info!("Getting the guild settings...");
+        let settings = GuildSettings::new(config, overrides);
+        let entries: Vec<(&str, String, bool)> = GUILD_SETTING_KEYS
+            .iter()
+            .filter(|setting_key| match key {
+                Some(key) => key == **setting_key,
+                None => true,
+            })
+            .map(|setting_key| {
+                let value = settings.get_value(setting_key).unwrap_or_default();
+                let is_overridden = overrides
+                    .and_then(|overrides| overrides.get_value(setting_key))
+                    .is_some();
+                (*setting_key, value, is_overridden)
+            })
+            .collect();
+
+        get_msg_config_settings(&entries)
+    }
+
+    /**
+    A method to override a setting of the guild.
+
+    ## Parameters:
+    - `guild_id`: The Discord ID of the guild.
+    - `overrides`: The guild's settings in the database, if any.
+    - `key`: The setting's key.
+    - `value`: The setting's raw value.
+    */
+    async fn set_setting(
+        &self,
+        guild_id: &str,
+        overrides: Option<DBGuildSettings>,
+        key: &str,
+        value: &str,
+    ) -> HandlerResult<CreateInteractionResponseMessage> {
+        info!("Setting the guild setting '{}'...", key);
+        let is_new = overrides.is_none();
+        let mut overrides = overrides.unwrap_or_else(|| DBGuildSettings::new(guild_id));
+        if let Err(reason) = overrides.set_value(key, value) {
+            info!("Invalid value for '{}'. {}", key, reason);
+            return Ok(get_msg_config_invalid_value(key, value, &reason));
+        }
+
+        if !overrides.save(is_new).await? {
+            return Ok(get_msg_config_conflict());
+        }
+
+        GUILD_SETTINGS_CACHE.invalidate(guild_id);
+        let value = overrides.get_value(key).unwrap_or_default();
+        Ok(get_msg_config_updated(key, &value))
+    }
+
+    /**
+    A method to reset the settings of the guild.
+
+    ## Parameters:
+    - `overrides`: The guild's settings in the database, if any.
+    - `key`: The setting to reset. If `None`, all the settings are reset.
+    */
+    async fn reset_settings(
+        &self,
+        overrides: Option<DBGuildSettings>,
+        key: Option<&str>,
+    ) -> HandlerResult<CreateInteractionResponseMessage> {
+        info!("Resetting the guild settings...");
+        if let Some(mut overrides) = overrides {
+            match key {
+                Some(key) => overrides.reset_value(key),
+                None => overrides.reset_values(),
+            }
+            if !overrides.save(false).await? {
+                return Ok(get_msg_config_conflict());
+            }
+
+            GUILD_SETTINGS_CACHE.invalidate(&overrides.discord_guild_id);
+        }
+
+        Ok(get_msg_config_reset(key))
+    }
+
+    /**
+    A method to respond to the command.
+
+    ## Parameters:
+    - `message`: The response message.
+    */
+    async fn send_response(&self, message: CreateInteractionResponseMessage) -> HandlerResult<()> {
+        let response = CreateInteractionResponse::Message(message);
+        self.command.create_response(&self.ctx, response).await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,50 @@
+// Libs
+use serenity::all::{CommandInteraction, Context, CreateInteractionResponse};
+use std::sync::Arc;
+use tracing::info;
+
+use crate::{
+    errors::HandlerResult,
+    messages::get_msg_inventory,
+    models::DBTrainer,
+    utils::{config::Config, trainer::get_trainer_inventory},
+};
+
+// Inventory Handler
+/**
+A struct to handle the `/inventory` command, which shows the balls owned by a trainer.
+*/
+pub struct InventoryHandler {
+    ctx: Arc<Context>,
+    command: CommandInteraction,
+}
+
+impl InventoryHandler {
+    /**
+    A method to create a new InventoryHandler.
+    */
+    pub fn new(ctx: Arc<Context>, command: CommandInteraction) -> Self {
+        Self { ctx, command }
+    }
+
+    /**
+    A method to handle the inventory command.
+    */
+    pub async fn handle(&self) -> HandlerResult<()> {
+        info!("Handling the inventory command...");
+        let guild_id = self
+            .command
+            .guild_id
+            .ok_or("Guild ID not found.")?
+            .to_string();
+        let user_id = self.command.user.id.to_string();
+        let trainer = DBTrainer::find_by_discord_id(&user_id, &guild_id).await?;
+        let config = &Config::global()?.bot;
+        let inventory = get_trainer_inventory(config, &trainer).await?;
+
+        let message = get_msg_inventory(&self.command.user, trainer.balance, &inventory);
+        let response = CreateInteractionResponse::Message(message);
+        self.command.create_response(&self.ctx, response).await?;
+        Ok(())
+    }
+}
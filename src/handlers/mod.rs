@@ -1,5 +1,13 @@
+pub mod daily;
 #[cfg(feature = "dev_commands")]
 pub mod dev_commands;
 pub mod event;
+pub mod guild_config;
+pub mod inventory;
 pub mod poke_spawn;
 pub mod pokedex;
+pub mod shop;
+pub mod spawn_channels;
+pub mod spawn_generations;
+pub mod spawns;
+pub mod trade;
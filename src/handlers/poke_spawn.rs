@@ -1,23 +1,61 @@
 // Libs
 use rand::Rng;
 use serenity::{
-    all::{parse_emoji, ChannelId, Context, EmojiIdentifier, GuildId, Mention, Mentionable, User},
+    all::{
+        ChannelId, Context, CreateInteractionResponse, CreateInteractionResponseMessage,
+        EmojiIdentifier, GuildId, Mention, Mentionable, MessageId, ReactionType, Timestamp, User,
+        UserId,
+    },
+    futures::StreamExt,
     model::channel::Message,
 };
-use std::sync::Arc;
-use tracing::info;
+use std::{
+    collections::HashMap,
+    sync::Arc,
+    time::{Duration, Instant},
+};
+use tracing::{info, warn};
 
 use crate::{
+    di::Transaction,
     errors::HandlerResult,
-    models::{DBModel, DBPoke, DBTrainer},
+    models::{
+        BallType, DBActiveSpawn, DBInventoryItem, DBLedgerEntry, DBModel, DBMutableModel, DBPoke,
+        DBSpawnEvent, DBTrainer, LedgerReason, SpawnOutcome,
+    },
+    services::DATABASE_SERVICE,
 };
 use crate::{
     messages::{
-        get_msg_wild_pokemon_appeared, get_msg_wild_pokemon_caught, get_msg_wild_pokemon_fled,
+        get_edit_wild_pokemon_caught, get_edit_wild_pokemon_fled, get_msg_poke_broke_free,
+        get_msg_poke_broke_free_reaction, get_msg_poke_catch_cooldown, get_msg_poke_catch_failed,
+        get_msg_poke_out_of_balls, get_msg_poke_out_of_balls_reaction,
+        get_msg_poke_spawn_run_denied, get_msg_poke_spawn_too_late, get_msg_wild_pokemon_appeared,
+        get_msg_wild_pokemon_caught, get_msg_wild_pokemon_fled,
     },
     serializations::cache::CachedPokemon,
 };
-use crate::{services::POKEFINDER_SERVICE, utils::EnvManager};
+use crate::{
+    services::POKEFINDER_SERVICE,
+    utils::{
+        config::{BotConfig, Config},
+        economy::get_catch_reward,
+        guild_settings::get_guild_settings,
+        spawn_eligibility::{
+            get_eligibility_filters, EligibilityFilter, RecentMessage, MESSAGE_HISTORY,
+        },
+        spawn_limiter::{SpawnLimits, SPAWN_LIMITER},
+        spawn_model::{get_activity_spawn_chance, SpawnModel, CHANNEL_ACTIVITY},
+        spawn_table::get_guild_spawn_table,
+        trainer::{get_trainer_inventory, trainer_owns_species},
+    },
+};
+
+// Constants
+/**
+The time in seconds to answer the users who press a button after the capture event ended.
+*/
+const LATE_INTERACTIONS_TIMEOUT_SECS: u64 = 10;
 
 // Gen Poke Handler
 /**
@@ -27,50 +65,129 @@ pub struct PokeSpawnHandler {
     ctx: Arc<Context>,
     channel_id: ChannelId,
     guild_id: GuildId,
+    spawner_id: Option<UserId>,
+    eligibility_filters: Vec<Box<dyn EligibilityFilter>>,
+    spawn_generations: Vec<u8>,
+    config: &'static BotConfig,
 
+    pub is_spawn_channel: bool,
     pub bot_pokeball_emoji: EmojiIdentifier,
     pub bot_poke_spawn_rate: u64,
+    pub bot_poke_spawn_model: SpawnModel,
+    pub bot_poke_spawns_per_hour: u64,
     pub bot_poke_flee_time_secs: u64,
     pub bot_poke_shiny_rate: u64,
+    pub bot_poke_follow_up_msg: bool,
+    pub bot_poke_spawn_cooldown_secs: u64,
+    pub bot_poke_max_spawns_per_channel: u64,
+    pub bot_poke_max_spawns_per_guild: u64,
+    pub bot_catch_attempt_cooldown_secs: u64,
+    pub bot_poke_activity_window_secs: u64,
 }
 
 impl PokeSpawnHandler {
     /**
-    A method to create a new PokeSpawnHandler.
+    A method to create a new PokeSpawnHandler with the guild's effective settings.
+
+    The pokémon'll spawn in the guild's wild area channel if it's defined.
+
+    ## Parameters:
+    - `ctx`: The context of the event.
+    - `channel_id`: The channel where the spawn was triggered.
+    - `guild_id`: The guild where the spawn was triggered.
+    - `spawner_id`: The user who triggered the spawn, if any. Only they can make the pokémon run.
     */
-    pub fn new(ctx: Arc<Context>, channel_id: ChannelId, guild_id: GuildId) -> Self {
-        Self {
+    pub async fn new(
+        ctx: Arc<Context>,
+        channel_id: ChannelId,
+        guild_id: GuildId,
+        spawner_id: Option<UserId>,
+    ) -> HandlerResult<Self> {
+        let config = &Config::global()?.bot;
+        let settings = get_guild_settings(config, &guild_id.to_string()).await?;
+        let spawn_channel_id = settings.get_spawn_channel(channel_id);
+        Ok(Self {
             ctx,
-            channel_id,
+            channel_id: spawn_channel_id.unwrap_or(channel_id),
             guild_id,
-            bot_pokeball_emoji: parse_emoji(EnvManager::get_var::<String>("BOT_POKEBALL_EMOJI_ID"))
-                .unwrap(),
-            bot_poke_spawn_rate: EnvManager::get_var("BOT_POKE_SPAWN_RATE"),
-            bot_poke_flee_time_secs: EnvManager::get_var("BOT_POKE_FLEE_TIME_SECS"),
-            bot_poke_shiny_rate: EnvManager::get_var("BOT_POKE_SHINY_RATE"),
+            spawner_id,
+            eligibility_filters: get_eligibility_filters(&settings),
+            spawn_generations: settings.spawn_generations.clone(),
+            config,
+            is_spawn_channel: spawn_channel_id.is_some(),
+            bot_pokeball_emoji: settings.pokeball_emoji,
+            bot_poke_spawn_rate: settings.poke_spawn_rate,
+            bot_poke_spawn_model: settings.poke_spawn_model,
+            bot_poke_spawns_per_hour: settings.poke_spawns_per_hour,
+            bot_poke_flee_time_secs: settings.poke_flee_time_secs,
+            bot_poke_shiny_rate: settings.poke_shiny_rate,
+            bot_poke_follow_up_msg: settings.poke_follow_up_msg,
+            bot_poke_spawn_cooldown_secs: settings.poke_spawn_cooldown_secs,
+            bot_poke_max_spawns_per_channel: settings.poke_max_spawns_per_channel,
+            bot_poke_max_spawns_per_guild: settings.poke_max_spawns_per_guild,
+            bot_catch_attempt_cooldown_secs: config.catch_attempt_cooldown_secs,
+            bot_poke_activity_window_secs: config.poke_activity_window_secs,
+        })
+    }
+
+    /**
+    A method to handle a message sent in a guild. The message only counts toward a spawn roll if it
+    follows the guild's eligibility filters.
+
+    ## Parameters:
+    - `msg`: The message that triggered the spawn roll.
+    */
+    pub async fn handle_message(&self, msg: &Message) -> HandlerResult<()> {
+        if !self.is_spawn_channel {
+            info!("The channel can't spawn pokemons.");
+            return Ok(());
         }
+
+        // The history is kept per channel the message was sent, even with a wild area.
+        let recent_msg = RecentMessage::new(msg);
+        let rejected_by =
+            MESSAGE_HISTORY.check(msg.channel_id, recent_msg, &self.eligibility_filters);
+        if let Some(filter) = rejected_by {
+            info!("The message is not eligible for a spawn roll ({}).", filter);
+            return Ok(());
+        }
+
+        self.handle().await
     }
 
     /**
     A method to handle the generation of a pokemon. If the chance was not met, return.
     */
     pub async fn handle(&self) -> HandlerResult<()> {
+        if !self.is_spawn_channel {
+            info!("The channel can't spawn pokemons.");
+            return Ok(());
+        }
+
         // Check the chance of the pokemon to be spawned.
-        info!(
-            "Checking the chance 1 to {} of a pokemon to be spawned...",
-            self.bot_poke_spawn_rate
-        );
         if !self.can_spawn() {
             info!("The chance was not met, a pokemon will not be spawned.");
             return Ok(());
         }
 
+        // The spawn is counted until its capture event ends.
+        let limits = SpawnLimits {
+            cooldown: Duration::from_secs(self.bot_poke_spawn_cooldown_secs),
+            max_per_channel: self.bot_poke_max_spawns_per_channel,
+            max_per_guild: self.bot_poke_max_spawns_per_guild,
+        };
+        let Some(_reservation) = SPAWN_LIMITER.try_reserve(self.guild_id, self.channel_id, limits)
+        else {
+            info!("The channel is in cooldown or has too many active spawns.");
+            return Ok(());
+        };
+
         // Spawn a new pokemon and send it to the channel.
         info!("The chance was met! Spawning a new pokemon...");
-        let spawned_poke_info = self.generate_new_poke().await?;
+        let (is_shiny, poke_id) = self.generate_new_poke().await?;
         let cached_poke = POKEFINDER_SERVICE
             .clone()
-            .find_poke(&spawned_poke_info.1.to_string())
+            .find_poke(&poke_id.to_string())
             .await?;
 
         // // Check the spawned pokemon's emoji.
@@ -78,52 +195,268 @@ impl PokeSpawnHandler {
 
         // Send the pokemon to the channel.
         info!("Sending the pokemon to the channel...");
-        let poke_msg = self
-            .create_poke_msg(spawned_poke_info.0, &cached_poke)
+        let flee_time = Duration::from_secs(self.bot_poke_flee_time_secs);
+        let mut poke_msg = self
+            .create_poke_msg(is_shiny, &cached_poke, flee_time)
+            .await?;
+
+        // Keep track of the spawn, so it can be recovered if the bot restarts.
+        let mut active_spawn = DBActiveSpawn::new(
+            self.guild_id.to_string(),
+            self.channel_id.to_string(),
+            poke_msg.id.to_string(),
+            self.spawner_id.map(|spawner_id| spawner_id.to_string()),
+            poke_id,
+            is_shiny,
+            flee_time,
+        );
+        if let Err(e) = active_spawn.create().await {
+            // The capture can't be tracked, so the pokemon flees before anyone can catch it.
+            warn!(
+                "Error saving the active spawn. Removing the pokemon... {}",
+                e
+            );
+            let result = self
+                .update_poke_msg(&mut poke_msg, is_shiny, &cached_poke, None)
+                .await;
+            if let Err(e) = result {
+                warn!("Error removing the pokemon's message buttons. {}", e);
+            }
+            return Err(e.into());
+        }
+
+        self.run_capture(active_spawn, poke_msg, &cached_poke).await
+    }
+
+    /**
+    A method to recover a spawn that was active when the bot stopped.
+
+    If the pokemon should have fled while the bot was offline, its message is updated. Otherwise,
+    the capture event is resumed for the remaining time.
+
+    ## Parameters:
+    - `ctx`: The context of the event.
+    - `active_spawn`: The spawn to recover.
+    */
+    pub async fn recover(ctx: Arc<Context>, active_spawn: DBActiveSpawn) -> HandlerResult<()> {
+        info!("Recovering the spawn#{}...", active_spawn.id);
+        let channel_id: ChannelId = active_spawn
+            .discord_channel_id
+            .parse()
+            .map_err(|_| "Invalid channel ID.")?;
+        let guild_id: GuildId = active_spawn
+            .discord_guild_id
+            .parse()
+            .map_err(|_| "Invalid guild ID.")?;
+        let message_id: MessageId = active_spawn
+            .discord_message_id
+            .parse()
+            .map_err(|_| "Invalid message ID.")?;
+        let spawner_id = active_spawn
+            .spawner_id
+            .as_deref()
+            .and_then(|spawner_id| spawner_id.parse().ok());
+
+        // The spawn must be recovered in its original channel.
+        let mut handler = Self::new(ctx, channel_id, guild_id, spawner_id).await?;
+        handler.channel_id = channel_id;
+
+        let mut poke_msg = match channel_id.message(&handler.ctx, message_id).await {
+            Ok(poke_msg) => poke_msg,
+            Err(e) => {
+                warn!("The spawn's message is not available anymore. {}", e);
+                return Ok(active_spawn.delete().await?);
+            }
+        };
+        let cached_poke = POKEFINDER_SERVICE
+            .clone()
+            .find_poke(&active_spawn.poke_id.to_string())
             .await?;
 
+        if active_spawn.get_remaining_time().is_none() {
+            info!("The pokemon fled while the bot was offline.");
+            handler
+                .update_poke_msg(&mut poke_msg, active_spawn.is_shiny, &cached_poke, None)
+                .await?;
+
+            let mut transaction = Transaction::new();
+            active_spawn.delete_in_transaction(&mut transaction);
+            DBSpawnEvent::new(&active_spawn, SpawnOutcome::Fled, None)
+                .create_in_transaction(&mut transaction)?;
+            return Ok(DATABASE_SERVICE
+                .clone()
+                .commit_transaction(transaction)
+                .await?);
+        }
+
+        info!("Resuming the capture event of the pokemon...");
+        let _reservation = SPAWN_LIMITER.reserve(guild_id, channel_id);
+        handler
+            .run_capture(active_spawn, poke_msg, &cached_poke)
+            .await
+    }
+
+    /**
+    A method to run the capture event of a spawned pokemon until it's caught or flees.
+
+    The messages are only updated once the capture is saved. The ball that caught the pokemon is
+    given back if it can't be saved.
+
+    ## Parameters:
+    - `active_spawn`: The spawn of the pokemon.
+    - `poke_msg`: The message of the pokemon.
+    - `cached_poke`: The cached pokemon.
+    */
+    async fn run_capture(
+        &self,
+        active_spawn: DBActiveSpawn,
+        mut poke_msg: Message,
+        cached_poke: &CachedPokemon,
+    ) -> HandlerResult<()> {
         // Start the capture event of the pokemon.
         info!("Starting the capture event of the pokemon...");
-        let user_who_captured = self.start_capture(&poke_msg).await?;
-        self.send_final_capture_msg(
-            spawned_poke_info.0,
-            &cached_poke.name,
-            user_who_captured.as_ref().map(|user| user.mention()),
-        )
-        .await?;
-        if user_who_captured.is_none() {
-            return Ok(());
+        let is_shiny = active_spawn.is_shiny;
+        let flee_time = active_spawn.get_remaining_time().unwrap_or_default();
+        let (outcome, catch) = self
+            .start_capture(&poke_msg, cached_poke, flee_time)
+            .await?;
+        let user_who_captured = catch.as_ref().map(|(user, _)| user);
+        let saved = self
+            .save_capture(&active_spawn, outcome, user_who_captured, cached_poke)
+            .await;
+        let reward = match (saved, &catch) {
+            (Ok(reward), _) => reward,
+            (Err(e), Some((user, ball))) => {
+                warn!(
+                    "Error saving the capture. Giving the {} back...",
+                    ball.as_str()
+                );
+                if let Err(e) = self.refund_ball(user.id, *ball).await {
+                    warn!("Error giving the {} back. {}", ball.as_str(), e);
+                }
+                return Err(e);
+            }
+            (Err(e), None) => return Err(e),
+        };
+
+        // The capture is already saved, so a Discord error must not fail it.
+        let catcher = user_who_captured.map(|user| (user.mention(), reward));
+        let updated = self
+            .update_poke_msg(&mut poke_msg, is_shiny, cached_poke, catcher)
+            .await;
+        if let Err(e) = updated {
+            warn!("Error updating the message of the pokemon. {}", e);
+        }
+        if self.bot_poke_follow_up_msg {
+            let user_mention = catcher.map(|(user_mention, _)| user_mention);
+            let sent = self
+                .send_final_capture_msg(is_shiny, &cached_poke.name, user_mention)
+                .await;
+            if let Err(e) = sent {
+                warn!("Error sending the final message of the capture. {}", e);
+            }
         }
-
-        // Save the pokemon to the trainer's pokedex.
-        let trainer_id = user_who_captured.unwrap().id.to_string();
-        let trainer =
-            DBTrainer::find_by_discord_id(&trainer_id, &self.guild_id.to_string()).await?;
-        let mut poke = DBPoke::new(&trainer.id, &spawned_poke_info.1, spawned_poke_info.0);
-        poke.create().await?;
 
         Ok(())
     }
 
+    /**
+    A method to save the outcome of a capture event.
+
+    The spawn stops being active in the same transaction that records its outcome, saves the
+    pokemon to the trainer's pokedex and pays the trainer's reward.
+
+    ## Parameters:
+    - `active_spawn`: The spawn of the pokemon.
+    - `outcome`: How the capture event ended.
+    - `user_who_captured`: The user who captured the pokemon.
+    - `cached_poke`: The cached pokemon.
+
+    ## Returns:
+    - The coins paid to the user who captured the pokemon.
+    */
+    async fn save_capture(
+        &self,
+        active_spawn: &DBActiveSpawn,
+        outcome: SpawnOutcome,
+        user_who_captured: Option<&User>,
+        cached_poke: &CachedPokemon,
+    ) -> HandlerResult<u64> {
+        let mut transaction = Transaction::new();
+        active_spawn.delete_in_transaction(&mut transaction);
+        let catcher_id = user_who_captured.map(|user| user.id.to_string());
+        DBSpawnEvent::new(active_spawn, outcome, catcher_id)
+            .create_in_transaction(&mut transaction)?;
+
+        // Save the pokemon to the trainer's pokedex and pay the trainer's reward.
+        let mut reward = 0;
+        if let Some(user_who_captured) = user_who_captured {
+            let trainer_id = user_who_captured.id.to_string();
+            let guild_id = self.guild_id.to_string();
+            let trainer = DBTrainer::find_by_discord_id(&trainer_id, &guild_id).await?;
+            let is_new_species = !trainer_owns_species(&trainer.id, active_spawn.poke_id).await?;
+            let is_shiny = active_spawn.is_shiny;
+            let poke = DBPoke::new(&trainer.id, &active_spawn.poke_id, is_shiny);
+            poke.create_in_transaction(&mut transaction)?;
+
+            reward = get_catch_reward(self.config, is_shiny, is_new_species);
+            if reward > 0 {
+                let details = format!("Caught {} ({})", cached_poke.name, poke.id);
+                DBLedgerEntry::new(&trainer.id, reward as i64, LedgerReason::Catch, details)
+                    .apply_in_transaction(&mut transaction)?;
+            }
+        }
+
+        DATABASE_SERVICE
+            .clone()
+            .commit_transaction(transaction)
+            .await?;
+        Ok(reward)
+    }
+
     /**
     A method to check a pokemon can be spawned.
-    It uses the bot_poke_spawn_rate to check if a pokemon can be spawned.
+
+    With the `message` spawn model, it uses the bot_poke_spawn_rate to check if a pokemon can be spawned.
+    With the `activity` spawn model, the message is recorded in the channel's activity and the chance is
+    scaled by the time since the channel's previous message to reach the bot_poke_spawns_per_hour.
     */
     pub fn can_spawn(&self) -> bool {
-        let chance = rand::thread_rng().gen_range(0..self.bot_poke_spawn_rate);
-        chance == 0
+        match self.bot_poke_spawn_model {
+            SpawnModel::Message => {
+                info!(
+                    "Checking the chance 1 to {} of a pokemon to be spawned...",
+                    self.bot_poke_spawn_rate
+                );
+                let chance = rand::thread_rng().gen_range(0..self.bot_poke_spawn_rate);
+                chance == 0
+            }
+            SpawnModel::Activity => {
+                let window = Duration::from_secs(self.bot_poke_activity_window_secs);
+                let elapsed = CHANNEL_ACTIVITY.record(self.channel_id, window);
+                let chance = get_activity_spawn_chance(self.bot_poke_spawns_per_hour, elapsed);
+                info!(
+                    "Checking the chance {:.4} of a pokemon to be spawned ({:.0}s since the previous message)...",
+                    chance,
+                    elapsed.as_secs_f64()
+                );
+                rand::thread_rng().gen_bool(chance)
+            }
+        }
     }
 
     /**
     A method to generate a new pokemon to be spawned.
+    The species is picked from the guild's spawn table, weighted by its rarity.
 
     ## Returns:
     - A (is_shiny, poke_id) tuple.
     */
     pub async fn generate_new_poke(&self) -> HandlerResult<(bool, u16)> {
-        let poke_svc = POKEFINDER_SERVICE.clone();
-        let poke_amount = poke_svc.get_poke_count().await?;
-        let poke_id = rand::thread_rng().gen_range(1..=poke_amount);
+        let spawn_table = get_guild_spawn_table(self.config, &self.spawn_generations).await?;
+        let poke_id = spawn_table
+            .choose()
+            .ok_or("No pokemon can spawn with the current spawn weights and generations.")?;
         let is_shiny = rand::thread_rng().gen_range(0..self.bot_poke_shiny_rate) == 0;
         Ok((is_shiny, poke_id))
     }
@@ -167,6 +500,7 @@ impl PokeSpawnHandler {
     ## Parameters:
     - `is_shiny`: A `bool` type. If the pokemon is shiny.
     - `cached_poke`: A `CachedPokemon` type. The cached pokemon.
+    - `flee_time`: A `Duration` type. The time until the pokemon flees.
 
     ## Returns:
     - A `Message` type.
@@ -175,15 +509,21 @@ impl PokeSpawnHandler {
         &self,
         is_shiny: bool,
         cached_poke: &CachedPokemon,
+        flee_time: Duration,
     ) -> HandlerResult<Message> {
-        let message = get_msg_wild_pokemon_appeared(is_shiny, cached_poke);
+        let flees_at = Timestamp::now().unix_timestamp() + flee_time.as_secs() as i64;
+        let flees_at =
+            Timestamp::from_unix_timestamp(flees_at).unwrap_or_else(|_| Timestamp::now());
+        let message = get_msg_wild_pokemon_appeared(
+            is_shiny,
+            cached_poke,
+            self.spawner_id.is_some(),
+            flees_at,
+        );
         let message = self
             .channel_id
             .send_message(&self.ctx.http, message)
             .await?;
-        message
-            .react(&self.ctx.http, self.bot_pokeball_emoji.clone())
-            .await?;
 
         Ok(message)
     }
@@ -191,41 +531,275 @@ impl PokeSpawnHandler {
     /**
     A method to start the capture of a pokemon.
 
+    The capture is driven by the gateway's events. Pressing a ball's button, or reacting with the
+    pokéball emoji to throw a Poké Ball, is a catch attempt: the ball is taken from the user's
+    inventory and succeeds with the pokemon's catch chance for that ball. When it fails, the
+    pokemon breaks free and the user can try again after `catch_attempt_cooldown_secs` until it
+    flees. The spawner can make it run with the run button. An error in a single interaction is
+    logged and never ends the capture.
+
     ## Parameters:
     - `poke_msg`: A `Message` type. The message of the pokemon.
+    - `cached_poke`: A `CachedPokemon` type. The cached pokemon, used to get its catch chance.
+    - `flee_time`: A `Duration` type. The time until the pokemon flees.
 
     ## Returns:
-    - A `Option<User>` type. The user who captured the pokemon.
+    - A `(SpawnOutcome, Option<(User, BallType)>)` type. How the capture event ended, and the user who
+      captured the pokemon with the ball they threw.
     */
-    pub async fn start_capture(&self, poke_msg: &Message) -> HandlerResult<Option<User>> {
-        let start_instant = std::time::Instant::now();
-
-        while start_instant.elapsed().as_secs() < self.bot_poke_flee_time_secs {
-            // Check the reactions of the message.
-            info!("Checking the reactions of the message...");
-            let users_that_reacted = &poke_msg
-                .reaction_users(
-                    &self.ctx.http,
-                    self.bot_pokeball_emoji.clone(),
-                    Some(2),
-                    None,
-                )
-                .await?;
+    pub async fn start_capture(
+        &self,
+        poke_msg: &Message,
+        cached_poke: &CachedPokemon,
+        flee_time: Duration,
+    ) -> HandlerResult<(SpawnOutcome, Option<(User, BallType)>)> {
+        // Listen to the events before the bot reacts, so no reaction is missed.
+        let bot_id = poke_msg.author.id;
+        let pokeball_emoji_id = self.bot_pokeball_emoji.id;
+        let mut reactions = poke_msg
+            .await_reaction(&self.ctx.shard)
+            .timeout(flee_time)
+            .filter(move |reaction| {
+                let is_pokeball = matches!(
+                    reaction.emoji,
+                    ReactionType::Custom { id, .. } if id == pokeball_emoji_id
+                );
+                let is_bot = reaction.user_id == Some(bot_id)
+                    || reaction
+                        .member
+                        .as_ref()
+                        .is_some_and(|member| member.user.bot);
+                is_pokeball && !is_bot
+            })
+            .stream();
+        let mut interactions = poke_msg
+            .await_component_interaction(&self.ctx.shard)
+            .timeout(flee_time)
+            .stream();
+
+        // The buttons still work if the emoji isn't available in the guild.
+        if let Err(e) = poke_msg
+            .react(&self.ctx.http, self.bot_pokeball_emoji.clone())
+            .await
+        {
+            warn!("Error reacting with the pokeball emoji. {}", e);
+        }
 
-            // If someone reacted to the message, return.
-            if users_that_reacted.len() > 1 {
-                info!("The pokemon has been captured!");
-                return Ok(Some(
-                    users_that_reacted[users_that_reacted.len() - 2].clone(),
-                ));
+        // Wait for a successful catch attempt until the pokemon flees.
+        info!("Waiting for the pokemon to be captured...");
+        let attempt_cooldown = Duration::from_secs(self.bot_catch_attempt_cooldown_secs);
+        let mut last_attempts: HashMap<UserId, Instant> = HashMap::new();
+        let (outcome, catch) = loop {
+            tokio::select! {
+                Some(reaction) = reactions.next() => {
+                    // A failed catch attempt never ends the capture, the other users can still try.
+                    let user = match reaction.member.clone() {
+                        Some(member) => member.user,
+                        None => match reaction.user(&self.ctx).await {
+                            Ok(user) => user,
+                            Err(e) => {
+                                warn!("Error getting the user of a reaction. {}", e);
+                                continue;
+                            }
+                        },
+                    };
+                    let ball = BallType::Poke;
+                    let remaining = get_attempt_cooldown(&last_attempts, user.id, attempt_cooldown);
+                    let message = if remaining.is_some() {
+                        info!("The user is still on cooldown. Ignoring...");
+                        None
+                    } else {
+                        match self.throw_ball(user.id, ball).await {
+                            Ok(Some(_)) => {
+                                last_attempts.insert(user.id, Instant::now());
+                                if rand::thread_rng().gen_bool(cached_poke.get_catch_chance(ball)) {
+                                    info!("The pokemon has been captured by a reaction!");
+                                    break (SpawnOutcome::Caught, Some((user, ball)));
+                                }
+
+                                info!("The pokemon broke free from a reaction!");
+                                let mention = user.mention();
+                                Some(get_msg_poke_broke_free_reaction(&cached_poke.name, mention))
+                            }
+                            Ok(None) => {
+                                info!("The user doesn't have any {}. Ignoring...", ball.as_str());
+                                Some(get_msg_poke_out_of_balls_reaction(user.mention()))
+                            }
+                            Err(e) => {
+                                warn!("Error throwing the {}. {}", ball.as_str(), e);
+                                None
+                            }
+                        }
+                    };
+
+                    // Remove the reaction, so the user can react again to retry.
+                    if let Err(e) = reaction.delete(&self.ctx.http).await {
+                        warn!("Error removing a failed catch attempt's reaction. {}", e);
+                    }
+                    if let Some(message) = message {
+                        let sent = self.channel_id.send_message(&self.ctx.http, message).await;
+                        if let Err(e) = sent {
+                            warn!("Error sending the catch attempt's message. {}", e);
+                        }
+                    }
+                }
+                Some(interaction) = interactions.next() => {
+                    match interaction.data.custom_id.as_str() {
+                        custom_id if custom_id.starts_with("poke_spawn_catch") => {
+                            // Throwing a ball reads the database, so the response is deferred to
+                            // answer within Discord's deadline.
+                            let response = CreateInteractionResponse::Defer(
+                                CreateInteractionResponseMessage::new().ephemeral(true),
+                            );
+                            if let Err(e) = interaction.create_response(&self.ctx, response).await {
+                                warn!("Error deferring the catch attempt's response. {}", e);
+                                continue;
+                            }
+
+                            let user_id = interaction.user.id;
+                            let ball = get_thrown_ball(custom_id);
+                            let remaining =
+                                get_attempt_cooldown(&last_attempts, user_id, attempt_cooldown);
+                            let message = if let Some(remaining) = remaining {
+                                info!("The user is still on cooldown. Ignoring...");
+                                let remaining_secs = remaining.as_secs_f64().ceil() as u64;
+                                get_msg_poke_catch_cooldown(remaining_secs)
+                            } else {
+                                match self.throw_ball(user_id, ball).await {
+                                    Ok(Some(balls_left)) => {
+                                        last_attempts.insert(user_id, Instant::now());
+                                        if rand::thread_rng()
+                                            .gen_bool(cached_poke.get_catch_chance(ball))
+                                        {
+                                            info!("The pokemon has been captured by a button!");
+                                            let deleted =
+                                                interaction.delete_response(&self.ctx.http).await;
+                                            if let Err(e) = deleted {
+                                                warn!("Error deleting the deferred response. {}", e);
+                                            }
+                                            break (SpawnOutcome::Caught, Some((interaction.user, ball)));
+                                        }
+
+                                        info!("The pokemon broke free from a button!");
+                                        get_msg_poke_broke_free(&cached_poke.name, ball, balls_left)
+                                    }
+                                    Ok(None) => {
+                                        let ball_name = ball.as_str();
+                                        info!("The user doesn't have any {}. Ignoring...", ball_name);
+                                        get_msg_poke_out_of_balls(ball)
+                                    }
+                                    Err(e) => {
+                                        warn!("Error throwing the {}. {}", ball.as_str(), e);
+                                        get_msg_poke_catch_failed(ball)
+                                    }
+                                }
+                            };
+
+                            if let Err(e) = interaction.edit_response(&self.ctx, message).await {
+                                warn!("Error answering the catch attempt. {}", e);
+                            }
+                        }
+                        "poke_spawn_run" if Some(interaction.user.id) == self.spawner_id => {
+                            info!("The spawner made the pokemon run!");
+                            let response = CreateInteractionResponse::Acknowledge;
+                            if let Err(e) = interaction.create_response(&self.ctx, response).await {
+                                warn!("Error acknowledging the run. {}", e);
+                            }
+                            break (SpawnOutcome::Ran, None);
+                        }
+                        _ => {
+                            info!("Only the spawner can make the pokemon run. Ignoring...");
+                            let message = get_msg_poke_spawn_run_denied();
+                            let response = CreateInteractionResponse::Message(message);
+                            if let Err(e) = interaction.create_response(&self.ctx, response).await {
+                                warn!("Error answering the denied run. {}", e);
+                            }
+                        }
+                    }
+                }
+                else => {
+                    info!("The pokemon has fled!");
+                    break (SpawnOutcome::Fled, None);
+                }
             }
+        };
+
+        // Answer the users who were too late while the message is being updated.
+        let ctx = self.ctx.clone();
+        let is_captured = catch.is_some();
+        tokio::spawn(async move {
+            let late_timeout = Duration::from_secs(LATE_INTERACTIONS_TIMEOUT_SECS);
+            while let Ok(Some(interaction)) =
+                tokio::time::timeout(late_timeout, interactions.next()).await
+            {
+                let message = get_msg_poke_spawn_too_late(is_captured);
+                let response = CreateInteractionResponse::Message(message);
+                if let Err(e) = interaction.create_response(&ctx, response).await {
+                    warn!("Error answering a late interaction. {}", e);
+                }
+            }
+        });
 
-            // Wait for a second.
-            tokio::time::sleep(std::time::Duration::from_secs(1)).await;
-        }
+        Ok((outcome, catch))
+    }
+
+    /**
+    A method to take a ball from a user's inventory to throw it at the pokemon.
+
+    ## Parameters:
+    - `user_id`: The user who is throwing the ball.
+    - `ball`: The type of the ball.
+
+    ## Returns:
+    - The amount of the ball the user has left, or `None` if they had none.
+    */
+    async fn throw_ball(&self, user_id: UserId, ball: BallType) -> HandlerResult<Option<u64>> {
+        let trainer =
+            DBTrainer::find_by_discord_id(&user_id.to_string(), &self.guild_id.to_string()).await?;
+        get_trainer_inventory(self.config, &trainer).await?;
+        Ok(DBInventoryItem::consume(&trainer.id, ball).await?)
+    }
 
-        info!("The pokemon has fled!");
-        Ok(None)
+    /**
+    A method to give a thrown ball back to a user.
+
+    ## Parameters:
+    - `user_id`: The user who threw the ball.
+    - `ball`: The type of the ball.
+    */
+    async fn refund_ball(&self, user_id: UserId, ball: BallType) -> HandlerResult<()> {
+        let trainer =
+            DBTrainer::find_by_discord_id(&user_id.to_string(), &self.guild_id.to_string()).await?;
+        Ok(DBInventoryItem::refund(&trainer.id, ball).await?)
+    }
+
+    /**
+    A method to update the pokemon's message with the result of the capture event.
+    The buttons are removed.
+
+    ## Parameters:
+    - `poke_msg`: A `Message` type. The message of the pokemon.
+    - `is_shiny`: A `bool` type. If the pokemon is shiny.
+    - `cached_poke`: A `CachedPokemon` type. The cached pokemon.
+    - `catcher`: A `Option<(Mention, u64)>` type. The mention of the user who captured the pokemon
+      and the coins they earned.
+    */
+    pub async fn update_poke_msg(
+        &self,
+        poke_msg: &mut Message,
+        is_shiny: bool,
+        cached_poke: &CachedPokemon,
+        catcher: Option<(Mention, u64)>,
+    ) -> HandlerResult<()> {
+        info!("Updating the pokemon's message...");
+        let message = match catcher {
+            Some((user_mention, reward)) => {
+                get_edit_wild_pokemon_caught(is_shiny, cached_poke, user_mention, reward)
+            }
+            None => get_edit_wild_pokemon_fled(is_shiny, cached_poke),
+        };
+        poke_msg.edit(&self.ctx, message).await?;
+        Ok(())
     }
 
     /**
@@ -260,3 +834,42 @@ impl PokeSpawnHandler {
         Ok(())
     }
 }
+
+// Functions
+/**
+A method to get the ball thrown with a catch button.
+
+The spawns from older versions only have a single catch button, which throws a Poké Ball.
+
+## Parameters:
+- `custom_id`: The custom ID of the button.
+*/
+fn get_thrown_ball(custom_id: &str) -> BallType {
+    custom_id
+        .strip_prefix("poke_spawn_catch_")
+        .and_then(BallType::parse)
+        .unwrap_or(BallType::Poke)
+}
+
+/**
+A method to check if a user can make a catch attempt.
+
+## Parameters:
+- `last_attempts`: The time of the last catch attempt of each user.
+- `user_id`: The user who is trying to catch the pokemon.
+- `cooldown`: The time between two catch attempts of a user.
+
+## Returns:
+- The remaining time until the user can try again, or `None` if they can try now.
+*/
+fn get_attempt_cooldown(
+    last_attempts: &HashMap<UserId, Instant>,
+    user_id: UserId,
+    cooldown: Duration,
+) -> Option<Duration> {
+    let elapsed = last_attempts.get(&user_id)?.elapsed();
+    match elapsed < cooldown {
+        true => Some(cooldown - elapsed),
+        false => None,
+    }
+}
@@ -1,8 +1,8 @@
 // Libs
 use serenity::{
     all::{
-        parse_emoji, CommandInteraction, Context, CreateButton, CreateEmbed,
-        CreateInteractionResponse, CreateInteractionResponseMessage, CreateMessage, Message, User,
+        CommandInteraction, Context, CreateButton, CreateEmbed, CreateInteractionResponse,
+        CreateInteractionResponseMessage, CreateMessage, EmojiIdentifier, Message, User,
     },
     futures::StreamExt,
 };
@@ -15,18 +15,18 @@ use crate::{
     models::{DBPoke, DBTrainer},
     serializations::cache::CachedPokemon,
     services::POKEFINDER_SERVICE,
-    utils::{trainer::get_trainer_owned_pokes, EnvManager},
+    utils::{config::Config, guild_settings::get_guild_settings, trainer::get_trainer_owned_pokes},
 };
 
 /**
 A method to extract a `page` from a list of pokémons.
-This page is created based on the environment variable `BOT_POKEDEX_POKES_PER_PAGE`.
 
 If the requested page doesn't exist, an empty array'll be returned.
 
 ## Parameters:
 - `total_pages`: The total number of pages.
 - `current_page`: The requested page.
+- `poke_per_page`: The amount of pokémons per page, from the `bot.pokedex_pokes_per_page` configuration.
 - `pokelist`: A list of pokémons.
 
 ## Returns:
@@ -35,15 +35,13 @@ If the requested page doesn't exist, an empty array'll be returned.
 fn get_pokedex_page(
     total_pages: impl Into<u16>,
     current_page: impl Into<u16>,
+    poke_per_page: u16,
     pokelist: &[DBPoke],
 ) -> Vec<DBPoke> {
     // Convert the parameters into the correct types.
     let total_pages: u16 = total_pages.into();
     let current_page: u16 = current_page.into();
 
-    // Get the total number of pokemons per page and select the current page.
-    let poke_per_page: u16 = EnvManager::get_var("BOT_POKEDEX_POKES_PER_PAGE");
-
     info!("Getting the pokedex page {current_page}/{total_pages}...");
     if current_page > total_pages {
         info!("Page not found. Returning an empty array.");
@@ -75,6 +73,7 @@ The poke_cache'll be updated if the pokémon list is not found.
 ## Parameters:
 - `pokelist`: A list of pokémons.
 - `poke_cache`: A list of all cached pokémons.
+- `pokeball_emoji`: The guild's pokéball emoji.
 
 ## Returns:
 - An array of strings with each description.
@@ -83,6 +82,7 @@ The poke_cache'll be updated if the pokémon list is not found.
 async fn describe_trainer_pokes(
     trainer_pokes: &[DBPoke],
     poke_cache: &mut Vec<CachedPokemon>,
+    pokeball_emoji: &EmojiIdentifier,
 ) -> HandlerResult<Vec<String>> {
     let pokefinder_svc = POKEFINDER_SERVICE.clone();
     let mut descriptions: Vec<String> = Vec::new();
@@ -122,7 +122,7 @@ async fn describe_trainer_pokes(
     index + 1,
     trainer_poke.poke_id,
     cached_poke.name.to_uppercase(),
-    parse_emoji(EnvManager::get_var::<String>("BOT_POKEBALL_EMOJI_ID")).unwrap(),
+    pokeball_emoji,
     total_caught,
     total_shiny_caught,
     last_captured.format("%Y/%m/%d %H:%M")))
@@ -146,10 +146,15 @@ async fn mount_pokedex_content_embed(
     let pokedex_page_pokes = get_pokedex_page(
         pokedex_info.total_pages,
         pokedex_info.current_page,
+        pokedex_info.pokes_per_page,
         &pokedex_info.trainer_pokes,
     );
-    let pokedex_page =
-        describe_trainer_pokes(&pokedex_page_pokes, &mut pokedex_info.poke_cache).await?;
+    let pokedex_page = describe_trainer_pokes(
+        &pokedex_page_pokes,
+        &mut pokedex_info.poke_cache,
+        &pokedex_info.pokeball_emoji,
+    )
+    .await?;
     let embed = get_embed_pokedex_content(
         user,
         pokedex_info.trainer_pokes.len() as u16,
@@ -257,7 +262,7 @@ impl PokedexHandler {
     ) -> HandlerResult<()> {
         info!("Waiting for pokedex interactions...");
 
-        let timeout = Duration::from_secs(EnvManager::get_var("BOT_POKEDEX_TIMEOUT_SECS"));
+        let timeout = Duration::from_secs(Config::global()?.bot.pokedex_timeout_secs);
         let mut interactions = pokedex_msg
             .await_component_interaction(&self.ctx.shard)
             .timeout(timeout)
@@ -317,6 +322,7 @@ struct PokedexInfo {
     pub trainer_pokes: Vec<DBPoke>,
     pub trainer_species: Vec<DBPoke>,
     pub poke_cache: Vec<CachedPokemon>,
+    pub pokeball_emoji: EmojiIdentifier,
 }
 
 impl PokedexInfo {
@@ -330,18 +336,20 @@ impl PokedexInfo {
     */
     async fn new(command: CommandInteraction) -> HandlerResult<PokedexInfo> {
         // Get the trainer and its pokemons.
+        let guild_id = command.guild_id.ok_or("Guild ID not found.")?.to_string();
         let trainer = {
             let user_id = command.user.id.to_string();
-            let guild_id = command.guild_id.ok_or("Guild ID not found.")?;
-            DBTrainer::find_by_discord_id(&user_id, &guild_id.to_string()).await?
+            DBTrainer::find_by_discord_id(&user_id, &guild_id).await?
         };
         let (trainer_pokes, trainer_species) = get_trainer_owned_pokes(trainer).await?;
 
         // Define the other pokedex information.
         let total_pokes = POKEFINDER_SERVICE.clone().get_poke_count().await?;
-        let pokes_per_page = EnvManager::get_var("BOT_POKEDEX_POKES_PER_PAGE");
+        let config = &Config::global()?.bot;
+        let pokes_per_page = config.pokedex_pokes_per_page;
         let total_pages = trainer_species.len() as u16 / pokes_per_page + 1;
         let poke_cache = Vec::new();
+        let pokeball_emoji = get_guild_settings(config, &guild_id).await?.pokeball_emoji;
 
         Ok(PokedexInfo {
             total_pokes,
@@ -351,6 +359,7 @@ impl PokedexInfo {
             trainer_pokes,
             trainer_species,
             poke_cache,
+            pokeball_emoji,
         })
     }
 }
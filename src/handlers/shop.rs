@@ -0,0 +1,171 @@
+// Libs
+use serenity::all::{
+    CommandInteraction, Context, CreateInteractionResponse, CreateInteractionResponseMessage,
+    ResolvedOption,
+};
+use std::sync::Arc;
+use tracing::{info, warn};
+
+use crate::{
+    di::Transaction,
+    errors::HandlerResult,
+    messages::{
+        get_msg_shop, get_msg_shop_cost_too_high, get_msg_shop_insufficient_balance,
+        get_msg_shop_not_for_sale, get_msg_shop_purchased,
+    },
+    models::{BallType, DBLedgerEntry, DBTrainer, LedgerReason},
+    services::DATABASE_SERVICE,
+    utils::{
+        config::{BotConfig, Config},
+        economy::{get_ball_price, get_purchase_cost},
+        interaction::{get_integer_option, get_string_option, get_subcommand},
+        trainer::get_trainer_inventory,
+    },
+};
+
+// Constants
+/**
+The maximum amount of an item that can be bought at once with `/shop buy`.
+*/
+pub const MAX_PURCHASE_QUANTITY: u64 = 100;
+
+// Shop Handler
+/**
+A struct to handle the `/shop` command, where the trainers spend their coins on balls.
+*/
+pub struct ShopHandler {
+    ctx: Arc<Context>,
+    command: CommandInteraction,
+}
+
+impl ShopHandler {
+    /**
+    A method to create a new ShopHandler.
+    */
+    pub fn new(ctx: Arc<Context>, command: CommandInteraction) -> Self {
+        Self { ctx, command }
+    }
+
+    /**
+    A method to handle the shop command.
+    */
+    pub async fn handle(&self) -> HandlerResult<()> {
+        info!("Handling the shop command...");
+        let guild_id = self
+            .command
+            .guild_id
+            .ok_or("Guild ID not found.")?
+            .to_string();
+        let user_id = self.command.user.id.to_string();
+        let trainer = DBTrainer::find_by_discord_id(&user_id, &guild_id).await?;
+        let config = &Config::global()?.bot;
+
+        let options = self.command.data.options();
+        let (subcommand, options) = get_subcommand(&options).ok_or("Subcommand not found.")?;
+        match subcommand {
+            "list" => {
+                let prices: Vec<(BallType, Option<u64>)> = BallType::ALL
+                    .into_iter()
+                    .map(|ball| (ball, get_ball_price(config, ball)))
+                    .collect();
+                self.send_response(get_msg_shop(trainer.balance, &prices))
+                    .await
+            }
+            "buy" => self.buy(config, trainer, options).await,
+            _ => Err(format!("Unknown subcommand: {}", subcommand).into()),
+        }
+    }
+
+    /**
+    A method to buy an item for the trainer.
+
+    The coins are spent and the item is added to the trainer's inventory in a single transaction.
+    The purchase is recorded in the ledger.
+
+    ## Parameters:
+    - `config`: The bot's configuration.
+    - `trainer`: The trainer who is buying the item.
+    - `options`: The options of the `buy` subcommand.
+    */
+    async fn buy(
+        &self,
+        config: &BotConfig,
+        trainer: DBTrainer,
+        options: &[ResolvedOption<'_>],
+    ) -> HandlerResult<()> {
+        let ball = get_string_option(options, "item")
+            .and_then(BallType::parse)
+            .ok_or("Item not found.")?;
+        let quantity = get_integer_option(options, "quantity").map_or(1, |quantity| {
+            quantity.clamp(1, MAX_PURCHASE_QUANTITY as i64) as u64
+        });
+        let Some(price) = get_ball_price(config, ball) else {
+            info!("The {} isn't for sale. Ignoring...", ball.as_str());
+            return self.send_response(get_msg_shop_not_for_sale(ball)).await;
+        };
+
+        let Some(cost) = get_purchase_cost(price, quantity) else {
+            info!("The purchase's cost is too high. Ignoring...");
+            return self
+                .send_response(get_msg_shop_cost_too_high(ball, quantity))
+                .await;
+        };
+        if trainer.balance < cost {
+            info!("The trainer can't afford the purchase. Ignoring...");
+            let message = get_msg_shop_insufficient_balance(ball, quantity, cost, trainer.balance);
+            return self.send_response(message).await;
+        }
+
+        info!("Buying {}x {}...", quantity, ball.as_str());
+        let inventory = get_trainer_inventory(config, &trainer).await?;
+        let item = inventory
+            .iter()
+            .find(|item| item.ball == ball)
+            .ok_or("Inventory item not found.")?;
+        let mut transaction = Transaction::new();
+        let details = format!("Bought {}x {}", quantity, ball.get_name());
+        let amount = -i64::try_from(cost).map_err(|_| "Invalid purchase cost.")?;
+        DBLedgerEntry::new(&trainer.id, amount, LedgerReason::Purchase, details)
+            .apply_in_transaction(&mut transaction)?;
+        transaction.increment(
+            "inventory",
+            &item.id.id.to_string(),
+            "quantity",
+            quantity as i64,
+        );
+
+        // The balance can't go below 0, so a concurrent purchase may have spent the coins first.
+        let committed = DATABASE_SERVICE
+            .clone()
+            .commit_transaction(transaction)
+            .await;
+        let balance = DBTrainer::get_by_discord_id(&trainer.discord_id, &trainer.discord_guild_id)
+            .await?
+            .ok_or("Trainer not found.")?
+            .balance;
+        if let Err(e) = committed {
+            if balance >= cost {
+                return Err(e.into());
+            }
+
+            warn!("The purchase was cancelled. {}", e);
+            let message = get_msg_shop_insufficient_balance(ball, quantity, cost, balance);
+            return self.send_response(message).await;
+        }
+
+        let message = get_msg_shop_purchased(ball, quantity, cost, balance);
+        self.send_response(message).await
+    }
+
+    /**
+    A method to respond to the command.
+
+    ## Parameters:
+    - `message`: The response message.
+    */
+    async fn send_response(&self, message: CreateInteractionResponseMessage) -> HandlerResult<()> {
+        let response = CreateInteractionResponse::Message(message);
+        self.command.create_response(&self.ctx, response).await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,127 @@
+// Libs
+use serenity::all::{
+    CommandInteraction, Context, CreateInteractionResponse, CreateInteractionResponseMessage,
+};
+use std::sync::Arc;
+use tracing::info;
+
+use crate::{
+    errors::HandlerResult,
+    messages::{
+        get_msg_config_conflict, get_msg_config_missing_permission, get_msg_spawn_channels,
+    },
+    models::DBGuildSettings,
+    utils::{
+        config::Config,
+        guild_settings::{GuildSettings, SpawnChannelMode, GUILD_SETTINGS_CACHE},
+        interaction::{can_manage_guild, get_channel_option, get_string_option, get_subcommand},
+    },
+};
+
+// Spawn Channels Handler
+/**
+A struct to handle the `/spawn-channels` command, which manages where the pokémons of a guild can spawn.
+*/
+pub struct SpawnChannelsHandler {
+    ctx: Arc<Context>,
+    command: CommandInteraction,
+}
+
+impl SpawnChannelsHandler {
+    /**
+    A method to create a new SpawnChannelsHandler.
+    */
+    pub fn new(ctx: Arc<Context>, command: CommandInteraction) -> Self {
+        Self { ctx, command }
+    }
+
+    /**
+    A method to handle the spawn-channels command.
+    */
+    pub async fn handle(&self) -> HandlerResult<()> {
+        info!("Handling the spawn-channels command...");
+
+        // The command is hidden from other users by Discord, but the permission is checked anyway.
+        if !can_manage_guild(&self.command) {
+            info!("The user can't manage the guild. Ignoring...");
+            return self
+                .send_response(get_msg_config_missing_permission())
+                .await;
+        }
+
+        let guild_id = self
+            .command
+            .guild_id
+            .ok_or("Guild ID not found.")?
+            .to_string();
+        let config = &Config::global()?.bot;
+        let overrides = DBGuildSettings::get_by_guild_id(&guild_id).await?;
+        let is_new = overrides.is_none();
+        let mut overrides = overrides.unwrap_or_else(|| DBGuildSettings::new(&guild_id));
+
+        // Apply the subcommand to the guild's settings.
+        let options = self.command.data.options();
+        let (subcommand, options) = get_subcommand(&options).ok_or("Subcommand not found.")?;
+        match subcommand {
+            "list" => {
+                let settings = GuildSettings::new(config, Some(&overrides));
+                return self.send_response(get_msg_spawn_channels(&settings)).await;
+            }
+            "mode" => {
+                let mode = match get_string_option(options, "mode") {
+                    Some("allow") => SpawnChannelMode::Allow,
+                    Some("deny") => SpawnChannelMode::Deny,
+                    _ => SpawnChannelMode::All,
+                };
+                info!("Setting the spawn channel mode to {:?}...", mode);
+                overrides.spawn_channel_mode = mode.to_db();
+            }
+            "add" => {
+                let channel_id = get_channel_option(options, "channel")
+                    .ok_or("Channel not found.")?
+                    .to_string();
+                info!("Adding the channel {} to the spawn channels...", channel_id);
+                if !overrides.spawn_channel_ids.contains(&channel_id) {
+                    overrides.spawn_channel_ids.push(channel_id);
+                }
+            }
+            "remove" => {
+                let channel_id = get_channel_option(options, "channel")
+                    .ok_or("Channel not found.")?
+                    .to_string();
+                info!(
+                    "Removing the channel {} from the spawn channels...",
+                    channel_id
+                );
+                overrides.spawn_channel_ids.retain(|id| *id != channel_id);
+            }
+            "wild-area" => {
+                let channel_id = get_channel_option(options, "channel");
+                info!("Setting the wild area channel to {:?}...", channel_id);
+                overrides.wild_area_channel_id = channel_id.map(|id| id.to_string());
+            }
+            _ => return Err(format!("Unknown subcommand: {}", subcommand).into()),
+        }
+
+        if !overrides.save(is_new).await? {
+            return self.send_response(get_msg_config_conflict()).await;
+        }
+
+        GUILD_SETTINGS_CACHE.invalidate(&guild_id);
+
+        let settings = GuildSettings::new(config, Some(&overrides));
+        self.send_response(get_msg_spawn_channels(&settings)).await
+    }
+
+    /**
+    A method to respond to the command.
+
+    ## Parameters:
+    - `message`: The response message.
+    */
+    async fn send_response(&self, message: CreateInteractionResponseMessage) -> HandlerResult<()> {
+        let response = CreateInteractionResponse::Message(message);
+        self.command.create_response(&self.ctx, response).await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,142 @@
+// Libs
+use serenity::all::{
+    CommandInteraction, Context, CreateInteractionResponse, CreateInteractionResponseMessage,
+};
+use std::sync::Arc;
+use tracing::info;
+
+use crate::{
+    errors::HandlerResult,
+    messages::{
+        get_msg_config_conflict, get_msg_config_invalid_value, get_msg_config_missing_permission,
+        get_msg_spawn_generations,
+    },
+    models::DBGuildSettings,
+    services::POKEFINDER_SERVICE,
+    utils::{
+        guild_settings::GUILD_SETTINGS_CACHE,
+        interaction::{can_manage_guild, get_string_option, get_subcommand},
+    },
+};
+
+// Spawn Generations Handler
+/**
+A struct to handle the `/spawn-generations` command, which manages which generations of pokémons
+can spawn in a guild.
+*/
+pub struct SpawnGenerationsHandler {
+    ctx: Arc<Context>,
+    command: CommandInteraction,
+}
+
+impl SpawnGenerationsHandler {
+    /**
+    A method to create a new SpawnGenerationsHandler.
+    */
+    pub fn new(ctx: Arc<Context>, command: CommandInteraction) -> Self {
+        Self { ctx, command }
+    }
+
+    /**
+    A method to handle the spawn-generations command.
+    */
+    pub async fn handle(&self) -> HandlerResult<()> {
+        info!("Handling the spawn-generations command...");
+
+        // The command is hidden from other users by Discord, but the permission is checked anyway.
+        if !can_manage_guild(&self.command) {
+            info!("The user can't manage the guild. Ignoring...");
+            return self
+                .send_response(get_msg_config_missing_permission())
+                .await;
+        }
+
+        let guild_id = self
+            .command
+            .guild_id
+            .ok_or("Guild ID not found.")?
+            .to_string();
+        let overrides = DBGuildSettings::get_by_guild_id(&guild_id).await?;
+        let is_new = overrides.is_none();
+        let mut overrides = overrides.unwrap_or_else(|| DBGuildSettings::new(&guild_id));
+
+        // Without spawn generations, every generation is enabled.
+        let generations = POKEFINDER_SERVICE.clone().get_generations().await?;
+        let all_ids: Vec<u8> = generations.iter().map(|generation| generation.id).collect();
+        let mut enabled_ids = match overrides.spawn_generations.is_empty() {
+            true => all_ids.clone(),
+            false => overrides.spawn_generations.clone(),
+        };
+
+        // Apply the subcommand to the enabled generations.
+        let options = self.command.data.options();
+        let (subcommand, options) = get_subcommand(&options).ok_or("Subcommand not found.")?;
+        if subcommand == "list" {
+            let message = get_msg_spawn_generations(&generations, &enabled_ids);
+            return self.send_response(message).await;
+        }
+
+        if subcommand == "reset" {
+            info!("Enabling every generation...");
+            enabled_ids = all_ids.clone();
+        } else {
+            let identifier =
+                get_string_option(options, "generation").ok_or("Generation not found.")?;
+            let Some(generation) = generations.iter().find(|g| g.matches(identifier)) else {
+                let reason = "must be a generation's number, name or region";
+                let message = get_msg_config_invalid_value("generation", identifier, reason);
+                return self.send_response(message).await;
+            };
+
+            match subcommand {
+                "enable" => {
+                    info!("Enabling the generation {}...", generation.id);
+                    if !enabled_ids.contains(&generation.id) {
+                        enabled_ids.push(generation.id);
+                    }
+                }
+                "disable" => {
+                    info!("Disabling the generation {}...", generation.id);
+                    enabled_ids.retain(|id| *id != generation.id);
+                }
+                "only" => {
+                    info!("Enabling only the generation {}...", generation.id);
+                    enabled_ids = vec![generation.id];
+                }
+                _ => return Err(format!("Unknown subcommand: {}", subcommand).into()),
+            }
+
+            if enabled_ids.is_empty() {
+                let reason = "can't be disabled, at least one generation must be enabled";
+                let message = get_msg_config_invalid_value("generation", identifier, reason);
+                return self.send_response(message).await;
+            }
+        }
+
+        enabled_ids.sort_unstable();
+        overrides.spawn_generations = match enabled_ids == all_ids {
+            true => Vec::new(),
+            false => enabled_ids.clone(),
+        };
+        if !overrides.save(is_new).await? {
+            return self.send_response(get_msg_config_conflict()).await;
+        }
+
+        GUILD_SETTINGS_CACHE.invalidate(&guild_id);
+
+        let message = get_msg_spawn_generations(&generations, &enabled_ids);
+        self.send_response(message).await
+    }
+
+    /**
+    A method to respond to the command.
+
+    ## Parameters:
+    - `message`: The response message.
+    */
+    async fn send_response(&self, message: CreateInteractionResponseMessage) -> HandlerResult<()> {
+        let response = CreateInteractionResponse::Message(message);
+        self.command.create_response(&self.ctx, response).await?;
+        Ok(())
+    }
+}
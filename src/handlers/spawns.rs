@@ -0,0 +1,189 @@
+// Libs
+use serenity::all::{
+    CommandInteraction, Context, CreateInteractionResponse, CreateInteractionResponseMessage,
+    FormattedTimestamp, FormattedTimestampStyle, Permissions, Timestamp,
+};
+use std::sync::Arc;
+use tracing::info;
+
+use crate::{
+    errors::HandlerResult,
+    messages::{
+        get_msg_spawn_rates, get_msg_spawn_rates_not_found, get_msg_spawns_missing_permission,
+        get_msg_spawns_recent,
+    },
+    models::{DBSpawnEvent, SpawnOutcome},
+    serializations::cache::CachedPokemon,
+    services::POKEFINDER_SERVICE,
+    utils::{
+        config::Config,
+        guild_settings::get_guild_settings,
+        interaction::{get_integer_option, get_string_option, get_subcommand, has_permissions},
+        spawn_table::get_guild_spawn_table,
+    },
+};
+
+// Constants
+/**
+The amount of spawns shown by `/spawns recent` when no limit is given.
+*/
+pub const DEFAULT_RECENT_SPAWNS: u32 = 10;
+
+/**
+The maximum amount of spawns shown by `/spawns recent`.
+*/
+pub const MAX_RECENT_SPAWNS: u32 = 25;
+
+/**
+A method to transform a list of spawn events into their descriptions.
+
+The poke_cache'll be updated if the pokémon is not found.
+
+## Parameters:
+- `events`: A list of spawn events.
+- `poke_cache`: A list of all cached pokémons.
+
+## Returns:
+- An array of strings with each description.
+*/
+async fn describe_spawn_events(
+    events: &[DBSpawnEvent],
+    poke_cache: &mut Vec<CachedPokemon>,
+) -> HandlerResult<Vec<String>> {
+    let pokefinder_svc = POKEFINDER_SERVICE.clone();
+    let mut descriptions: Vec<String> = Vec::new();
+
+    for (index, event) in events.iter().enumerate() {
+        // Check if the pokémon is in the cache.
+        let cached_poke = match poke_cache.iter().find(|p| p.id == event.poke_id) {
+            Some(cached_poke) => cached_poke.to_owned(),
+            None => {
+                let cached_poke = pokefinder_svc.find_poke(&event.poke_id.to_string()).await?;
+
+                poke_cache.push(cached_poke.clone());
+                cached_poke
+            }
+        };
+
+        let shiny = match event.is_shiny {
+            true => " ✨",
+            false => "",
+        };
+        let outcome = match (event.outcome, &event.catcher_id, event.catch_latency_ms) {
+            (SpawnOutcome::Caught, Some(catcher_id), Some(latency_ms)) => format!(
+                "caught by <@{}> in {:.1}s",
+                catcher_id,
+                latency_ms as f64 / 1000.0
+            ),
+            (SpawnOutcome::Caught, Some(catcher_id), None) => {
+                format!("caught by <@{}>", catcher_id)
+            }
+            (SpawnOutcome::Caught, None, _) => String::from("caught"),
+            (SpawnOutcome::Fled, _, _) => String::from("fled"),
+            (SpawnOutcome::Ran, _, _) => String::from("ran away"),
+        };
+        let spawned_at = Timestamp::from_unix_timestamp(event.spawned_at.0.timestamp())
+            .map(|timestamp| {
+                FormattedTimestamp::new(timestamp, Some(FormattedTimestampStyle::RelativeTime))
+                    .to_string()
+            })
+            .unwrap_or_default();
+
+        descriptions.push(format!(
+            "{}) **[#{}] {}**{} in <#{}>, {} {}",
+            index + 1,
+            event.poke_id,
+            cached_poke.name.to_uppercase(),
+            shiny,
+            event.discord_channel_id,
+            outcome,
+            spawned_at
+        ));
+    }
+
+    Ok(descriptions)
+}
+
+// Spawns Handler
+/**
+A struct to handle the `/spawns` command, which lets the moderators inspect the spawns of a guild.
+*/
+pub struct SpawnsHandler {
+    ctx: Arc<Context>,
+    command: CommandInteraction,
+}
+
+impl SpawnsHandler {
+    /**
+    A method to create a new SpawnsHandler.
+    */
+    pub fn new(ctx: Arc<Context>, command: CommandInteraction) -> Self {
+        Self { ctx, command }
+    }
+
+    /**
+    A method to handle the spawns command.
+    */
+    pub async fn handle(&self) -> HandlerResult<()> {
+        info!("Handling the spawns command...");
+
+        // The command is hidden from other users by Discord, but the permission is checked anyway.
+        if !has_permissions(&self.command, Permissions::MANAGE_MESSAGES) {
+            info!("The user can't manage the messages. Ignoring...");
+            return self
+                .send_response(get_msg_spawns_missing_permission())
+                .await;
+        }
+
+        let guild_id = self
+            .command
+            .guild_id
+            .ok_or("Guild ID not found.")?
+            .to_string();
+
+        let options = self.command.data.options();
+        let (subcommand, options) = get_subcommand(&options).ok_or("Subcommand not found.")?;
+        match subcommand {
+            "recent" => {
+                let limit = get_integer_option(options, "limit")
+                    .and_then(|limit| u32::try_from(limit).ok())
+                    .unwrap_or(DEFAULT_RECENT_SPAWNS)
+                    .clamp(1, MAX_RECENT_SPAWNS);
+                info!("Getting the {} most recent spawns...", limit);
+                let events = DBSpawnEvent::get_recent_by_guild_id(&guild_id, limit).await?;
+                let descriptions = describe_spawn_events(&events, &mut Vec::new()).await?;
+                self.send_response(get_msg_spawns_recent(&descriptions))
+                    .await
+            }
+            "rates" => {
+                info!("Getting the spawn rates...");
+                let config = &Config::global()?.bot;
+                let settings = get_guild_settings(config, &guild_id).await?;
+                let spawn_table =
+                    get_guild_spawn_table(config, &settings.spawn_generations).await?;
+                let tier_stats = spawn_table.get_tier_stats();
+                let message = match get_string_option(options, "pokemon") {
+                    Some(identifier) => match spawn_table.get_entry(identifier) {
+                        Some(poke) => get_msg_spawn_rates(&tier_stats, Some(poke)),
+                        None => get_msg_spawn_rates_not_found(identifier),
+                    },
+                    None => get_msg_spawn_rates(&tier_stats, None),
+                };
+                self.send_response(message).await
+            }
+            _ => Err(format!("Unknown subcommand: {}", subcommand).into()),
+        }
+    }
+
+    /**
+    A method to respond to the command.
+
+    ## Parameters:
+    - `message`: The response message.
+    */
+    async fn send_response(&self, message: CreateInteractionResponseMessage) -> HandlerResult<()> {
+        let response = CreateInteractionResponse::Message(message);
+        self.command.create_response(&self.ctx, response).await?;
+        Ok(())
+    }
+}
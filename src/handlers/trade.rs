@@ -0,0 +1,361 @@
+// Libs
+use serenity::{
+    all::{
+        CommandInteraction, ComponentInteraction, ComponentInteractionDataKind, Context,
+        CreateInteractionResponse, CreateInteractionResponseMessage, Timestamp, User,
+    },
+    futures::StreamExt,
+};
+use std::{sync::Arc, time::Duration};
+use tracing::{info, warn};
+
+use crate::{
+    di::Transaction,
+    errors::{DatabaseResult, HandlerResult},
+    messages::{
+        get_edit_trade_ended, get_msg_trade, get_msg_trade_empty, get_msg_trade_no_pokes,
+        get_msg_trade_not_participant, get_msg_trade_not_your_offer, get_msg_trade_too_many_pokes,
+        get_msg_trade_with_bot, get_msg_trade_with_self,
+    },
+    models::{DBModel, DBMutableModel, DBTrade, DBTrainer, TradeStatus},
+    services::DATABASE_SERVICE,
+    utils::{
+        config::Config,
+        interaction::get_user_option,
+        trade::{get_trade_options, TradeSide},
+        trainer::get_trainer_owned_pokes,
+    },
+};
+
+// Trade Handler
+/**
+A struct to handle the `/trade` command, which swaps pokémons between two trainers.
+*/
+pub struct TradeHandler {
+    ctx: Arc<Context>,
+    command: CommandInteraction,
+}
+
+impl TradeHandler {
+    /**
+    A method to create a new TradeHandler.
+    */
+    pub fn new(ctx: Arc<Context>, command: CommandInteraction) -> Self {
+        Self { ctx, command }
+    }
+
+    /**
+    A method to handle the trade command.
+
+    Both trainers pick their offer in the trade's message and confirm it. Changing an offer
+    resets the confirmations, so nobody confirms a trade they haven't seen. Every trade is logged,
+    whether it's completed, cancelled, expired or failed. An error answering an interaction never
+    ends the trade.
+    */
+    pub async fn handle(&self) -> HandlerResult<()> {
+        info!("Handling the trade command...");
+        let guild_id = self
+            .command
+            .guild_id
+            .ok_or("Guild ID not found.")?
+            .to_string();
+        let options = self.command.data.options();
+        let partner = get_user_option(&options, "user").ok_or("User option not found.")?;
+        if partner.id == self.command.user.id {
+            info!("The user tried to trade with themselves. Ignoring...");
+            return self.send_response(get_msg_trade_with_self()).await;
+        }
+        if partner.bot {
+            info!("The user tried to trade with a bot. Ignoring...");
+            return self.send_response(get_msg_trade_with_bot()).await;
+        }
+
+        let mut initiator = self.get_trade_side(&self.command.user, &guild_id).await?;
+        let mut partner = self.get_trade_side(partner, &guild_id).await?;
+        if initiator.options.is_empty() && partner.options.is_empty() {
+            info!("Neither trainer has pokemons to trade. Ignoring...");
+            return self.send_response(get_msg_trade_no_pokes()).await;
+        }
+
+        let timeout_secs = Config::global()?.bot.trade_timeout_secs;
+        let expires_at =
+            Timestamp::from_unix_timestamp(Timestamp::now().unix_timestamp() + timeout_secs as i64)
+                .map_err(|_| "Invalid trade expiration timestamp.")?;
+        info!(
+            "Starting a trade between {} and {}...",
+            initiator.trainer.id, partner.trainer.id
+        );
+        self.send_response(get_msg_trade(&initiator, &partner, expires_at))
+            .await?;
+
+        let trade_msg = self.command.get_response(&self.ctx.http).await?;
+        let mut interactions = trade_msg
+            .await_component_interaction(&self.ctx.shard)
+            .timeout(Duration::from_secs(timeout_secs))
+            .stream();
+
+        let status = loop {
+            let Some(interaction) = interactions.next().await else {
+                info!("The trade has expired!");
+                break TradeStatus::Expired;
+            };
+
+            let user_id = interaction.user.id;
+            if user_id != initiator.user.id && user_id != partner.user.id {
+                info!("Interaction received from other user. Ignoring...");
+                let message = get_msg_trade_not_participant();
+                self.respond(&interaction, CreateInteractionResponse::Message(message))
+                    .await;
+                continue;
+            }
+
+            let (side, other_side) = match user_id == initiator.user.id {
+                true => (&mut initiator, &mut partner),
+                false => (&mut partner, &mut initiator),
+            };
+            let own_select = match user_id == self.command.user.id {
+                true => "trade_select_initiator",
+                false => "trade_select_partner",
+            };
+            match (interaction.data.custom_id.as_str(), &interaction.data.kind) {
+                (custom_id, ComponentInteractionDataKind::StringSelect { values })
+                    if custom_id == own_select =>
+                {
+                    if !side.select(values) {
+                        info!("The user picked too many pokemons. Ignoring...");
+                        let message = get_msg_trade_too_many_pokes();
+                        self.respond(&interaction, CreateInteractionResponse::Message(message))
+                            .await;
+                        continue;
+                    }
+
+                    info!("The offer of the {} has changed.", side.trainer.id);
+                    side.confirmed = false;
+                    other_side.confirmed = false;
+                }
+                (custom_id, _) if custom_id.starts_with("trade_select") => {
+                    info!("The user tried to change the other offer. Ignoring...");
+                    let message = get_msg_trade_not_your_offer();
+                    self.respond(&interaction, CreateInteractionResponse::Message(message))
+                        .await;
+                    continue;
+                }
+                ("trade_page_previous", _) => {
+                    info!("The {} has moved to the previous page.", side.trainer.id);
+                    side.previous_page();
+                }
+                ("trade_page_next", _) => {
+                    info!("The {} has moved to the next page.", side.trainer.id);
+                    side.next_page();
+                }
+                ("trade_confirm", _)
+                    if side.selected.is_empty() && other_side.selected.is_empty() =>
+                {
+                    info!("The trade has nothing to swap. Ignoring...");
+                    let message = get_msg_trade_empty();
+                    self.respond(&interaction, CreateInteractionResponse::Message(message))
+                        .await;
+                    continue;
+                }
+                ("trade_confirm", _) => {
+                    info!("The {} has confirmed the trade.", side.trainer.id);
+                    side.confirmed = true;
+                    if other_side.confirmed {
+                        self.respond(&interaction, CreateInteractionResponse::Acknowledge)
+                            .await;
+                        break self.swap_pokes(&guild_id, &initiator, &partner).await;
+                    }
+                }
+                ("trade_cancel", _) => {
+                    info!("The {} has cancelled the trade.", side.trainer.id);
+                    self.respond(&interaction, CreateInteractionResponse::Acknowledge)
+                        .await;
+                    break TradeStatus::Cancelled;
+                }
+                _ => {
+                    info!("Invalid interaction. Ignoring...");
+                    continue;
+                }
+            }
+
+            let message = get_msg_trade(&initiator, &partner, expires_at);
+            self.respond(
+                &interaction,
+                CreateInteractionResponse::UpdateMessage(message),
+            )
+            .await;
+        };
+
+        // The swap logs the completed and failed trades itself.
+        if matches!(status, TradeStatus::Cancelled | TradeStatus::Expired) {
+            self.log_trade(&guild_id, &initiator, &partner, status)
+                .await;
+        }
+
+        info!("The trade has ended with the {:?} status.", status);
+        let edit = get_edit_trade_ended(&initiator, &partner, status);
+        self.command.edit_response(&self.ctx.http, edit).await?;
+        Ok(())
+    }
+
+    /**
+    A method to get a trainer's side of the trade, with the pokémons they can offer.
+
+    ## Parameters:
+    - `user`: The Discord user of the trainer.
+    - `guild_id`: The ID of the guild where the trade happens.
+    */
+    async fn get_trade_side(&self, user: &User, guild_id: &str) -> HandlerResult<TradeSide> {
+        let trainer = DBTrainer::find_by_discord_id(&user.id.to_string(), guild_id).await?;
+        let (trainer_pokes, _) = get_trainer_owned_pokes(trainer.clone()).await?;
+        let options = get_trade_options(&trainer_pokes).await?;
+        Ok(TradeSide::new(user.clone(), trainer, options))
+    }
+
+    /**
+    A method to swap the offered pokémons between the trainers.
+
+    The pokémons and the trade's log are saved in a single transaction. If a pokémon changed
+    since the trade started, e.g. it was traded somewhere else, or the transaction fails, nothing
+    is swapped and the trade is logged as failed.
+
+    ## Parameters:
+    - `guild_id`: The ID of the guild where the trade happens.
+    - `initiator`: The side of the trainer who started the trade.
+    - `partner`: The side of the trainer who was invited to the trade.
+
+    ## Returns:
+    - The status of the trade, which is either `Completed` or `Failed`.
+    */
+    async fn swap_pokes(
+        &self,
+        guild_id: &str,
+        initiator: &TradeSide,
+        partner: &TradeSide,
+    ) -> TradeStatus {
+        info!("Swapping the pokemons of the trade...");
+        let committed = match self.get_swap_transaction(guild_id, initiator, partner) {
+            Ok(transaction) => {
+                DATABASE_SERVICE
+                    .clone()
+                    .commit_transaction(transaction)
+                    .await
+            }
+            Err(e) => Err(e),
+        };
+        if let Err(e) = committed {
+            warn!("Error swapping the pokemons of the trade. {}", e);
+            self.log_trade(guild_id, initiator, partner, TradeStatus::Failed)
+                .await;
+            return TradeStatus::Failed;
+        }
+
+        TradeStatus::Completed
+    }
+
+    /**
+    A method to get the transaction that swaps the offered pokémons and logs the completed trade.
+
+    ## Parameters:
+    - `guild_id`: The ID of the guild where the trade happens.
+    - `initiator`: The side of the trainer who started the trade.
+    - `partner`: The side of the trainer who was invited to the trade.
+    */
+    fn get_swap_transaction(
+        &self,
+        guild_id: &str,
+        initiator: &TradeSide,
+        partner: &TradeSide,
+    ) -> DatabaseResult<Transaction> {
+        let mut transaction = Transaction::new();
+        for (side, other_side) in [(initiator, partner), (partner, initiator)] {
+            for option in side.get_offer() {
+                let mut poke = option.poke.clone();
+                poke.trainer_id = other_side.trainer.id.clone();
+                poke.update_in_transaction(&mut transaction)?;
+            }
+        }
+        self.get_trade(guild_id, initiator, partner, TradeStatus::Completed)
+            .create_in_transaction(&mut transaction)?;
+
+        Ok(transaction)
+    }
+
+    /**
+    A method to save a trade that didn't swap any pokémon.
+
+    ## Parameters:
+    - `guild_id`: The ID of the guild where the trade happens.
+    - `initiator`: The side of the trainer who started the trade.
+    - `partner`: The side of the trainer who was invited to the trade.
+    - `status`: How the trade ended.
+    */
+    async fn log_trade(
+        &self,
+        guild_id: &str,
+        initiator: &TradeSide,
+        partner: &TradeSide,
+        status: TradeStatus,
+    ) {
+        let mut trade = self.get_trade(guild_id, initiator, partner, status);
+        if let Err(e) = trade.create().await {
+            warn!("Error logging the trade. {}", e);
+        }
+    }
+
+    /**
+    A method to get the log of a trade.
+
+    ## Parameters:
+    - `guild_id`: The ID of the guild where the trade happens.
+    - `initiator`: The side of the trainer who started the trade.
+    - `partner`: The side of the trainer who was invited to the trade.
+    - `status`: How the trade ended.
+    */
+    fn get_trade(
+        &self,
+        guild_id: &str,
+        initiator: &TradeSide,
+        partner: &TradeSide,
+        status: TradeStatus,
+    ) -> DBTrade {
+        DBTrade::new(
+            guild_id,
+            &initiator.trainer.id,
+            &partner.trainer.id,
+            initiator.get_offered_poke_ids(),
+            partner.get_offered_poke_ids(),
+            status,
+        )
+    }
+
+    /**
+    A method to respond to a component interaction of the trade. An error is only logged, so the
+    trade goes on.
+
+    ## Parameters:
+    - `interaction`: The component interaction.
+    - `response`: The response to the interaction.
+    */
+    async fn respond(
+        &self,
+        interaction: &ComponentInteraction,
+        response: CreateInteractionResponse,
+    ) {
+        if let Err(e) = interaction.create_response(&self.ctx, response).await {
+            warn!("Error responding to a trade interaction. {}", e);
+        }
+    }
+
+    /**
+    A method to respond to the command.
+
+    ## Parameters:
+    - `message`: The response message.
+    */
+    async fn send_response(&self, message: CreateInteractionResponseMessage) -> HandlerResult<()> {
+        let response = CreateInteractionResponse::Message(message);
+        self.command.create_response(&self.ctx, response).await?;
+        Ok(())
+    }
+}
@@ -1,9 +1,10 @@
 // Libs
+use di::LocalHTTPClient;
 use serenity::all::GatewayIntents;
 use services::{DATABASE_SERVICE, POKEFINDER_SERVICE};
-use std::process::exit;
+use std::{path::Path, process::exit};
 use tracing::error;
-use utils::EnvManager;
+use utils::config::{Config, DiscordConfig};
 
 use crate::handlers::event::EventHandler;
 
@@ -11,9 +12,12 @@ mod di;
 mod errors;
 mod handlers;
 mod messages;
+mod migrations;
 mod models;
 mod serializations;
 mod services;
+#[cfg(test)]
+mod testing;
 mod utils;
 
 // Functions
@@ -40,21 +44,89 @@ async fn init_services() {
         error!("Error running the migrations. {}", e);
         exit(1);
     };
+
+    // Build the spawn table in the background, so the first spawn doesn't wait for it.
+    tokio::spawn(async move {
+        if let Err(e) = pokeapi_svc.get_spawn_table().await {
+            error!("Error building the spawn table. {}", e);
+        }
+        if let Err(e) = pokeapi_svc.get_generations().await {
+            error!("Error getting the generations. {}", e);
+        }
+    });
+}
+
+/**
+A method to import a PokeAPI data dump into the `pokeapi.data_dir` directory.
+
+Only the `pokeapi` section of the configuration is required, so the dump can be imported offline
+before the bot is configured.
+
+## Parameters:
+- `source`: The `api/v2` directory of the data dump to import.
+*/
+fn import_pokeapi_dump(source: &str) {
+    let config = match Config::load_pokeapi() {
+        Ok(config) => config,
+        Err(e) => {
+            error!("Error loading the configuration. {}", e);
+            exit(1);
+        }
+    };
+    let Some(data_dir) = config.data_dir.as_deref() else {
+        error!("The PokeAPI data directory is not defined.");
+        exit(1);
+    };
+
+    let local_client = LocalHTTPClient::new(data_dir, &config.url);
+    if let Err(e) = local_client.import_dump(Path::new(source)) {
+        error!("Error importing the PokeAPI data dump. {}", e);
+        exit(1);
+    }
+}
+
+/**
+A method to roll back the database's migrations to a version.
+
+## Parameters:
+- `version`: The schema version to roll back to.
+*/
+async fn rollback_migrations(version: &str) {
+    let version: u32 = match version.parse() {
+        Ok(version) => version,
+        Err(_) => {
+            error!("The version '{}' is not valid.", version);
+            exit(1);
+        }
+    };
+
+    let db_svc = DATABASE_SERVICE.clone();
+    if let Err(e) = db_svc.connect().await {
+        error!("Error connecting to the database. {}", e);
+        exit(1);
+    };
+    if let Err(e) = db_svc.rollback_migrations(version).await {
+        error!("Error rolling back the migrations. {}", e);
+        exit(1);
+    };
 }
 
 /**
 A method to get the discord's bot client.
+
+## Parameters:
+- `config`: The `discord` section of the configuration.
 */
-async fn get_client() -> Result<serenity::Client, serenity::Error> {
+async fn get_client(config: &DiscordConfig) -> Result<serenity::Client, serenity::Error> {
     // Define the permissions for the server.
     let intents = GatewayIntents::MESSAGE_CONTENT
         | GatewayIntents::GUILD_MESSAGES
+        | GatewayIntents::GUILD_MESSAGE_REACTIONS
         | GatewayIntents::GUILDS
         | GatewayIntents::GUILD_MEMBERS
         | GatewayIntents::GUILD_EMOJIS_AND_STICKERS;
 
-    let token = std::env::var("DISCORD_TOKEN").unwrap();
-    serenity::Client::builder(token, intents)
+    serenity::Client::builder(&config.token, intents)
         .event_handler(EventHandler)
         .status(serenity::all::OnlineStatus::DoNotDisturb)
         .await
@@ -62,18 +134,49 @@ async fn get_client() -> Result<serenity::Client, serenity::Error> {
 
 #[tokio::main]
 async fn main() {
-    if !EnvManager::is_env_defined() {
-        exit(1);
+    // Initialize the logger.
+    tracing_subscriber::fmt::init();
+
+    // Check if a PokeAPI data dump should be imported instead of starting the bot.
+    let args: Vec<String> = std::env::args().collect();
+    if args.get(1).map(String::as_str) == Some("import-pokeapi") {
+        match args.get(2) {
+            Some(source) => import_pokeapi_dump(source),
+            None => {
+                error!("Usage: app import-pokeapi <api/v2 directory>");
+                exit(1);
+            }
+        }
+        return;
     }
 
-    // Initialize the services.
-    tracing_subscriber::fmt::init();
+    // Load and validate the configuration.
+    let config = match Config::load() {
+        Ok(config) => config,
+        Err(e) => {
+            error!("Error loading the configuration. {}", e);
+            exit(1);
+        }
+    };
+    Config::init(config.clone());
+
+    // Check if the migrations should be rolled back instead of starting the bot.
+    if args.get(1).map(String::as_str) == Some("rollback-migrations") {
+        match args.get(2) {
+            Some(version) => rollback_migrations(version).await,
+            None => {
+                error!("Usage: app rollback-migrations <version>");
+                exit(1);
+            }
+        }
+        return;
+    }
 
     // Initialize the services.
     init_services().await;
 
     // Get the discord's bot client.
-    let mut client = match get_client().await {
+    let mut client = match get_client(&config.discord).await {
         Ok(client) => client,
         Err(e) => {
             error!("Error creating client: {:?}", e);
@@ -1,33 +1,259 @@
 // Libs
 use serenity::all::{
-    CreateEmbed, CreateEmbedAuthor, CreateInteractionResponseMessage, CreateMessage, Mention, User,
+    ButtonStyle, CreateActionRow, CreateButton, CreateEmbed, CreateEmbedAuthor, CreateEmbedFooter,
+    CreateInteractionResponseMessage, CreateMessage, CreateSelectMenu, CreateSelectMenuKind,
+    CreateSelectMenuOption, EditInteractionResponse, EditMessage, FormattedTimestamp,
+    FormattedTimestampStyle, Mention, Mentionable, Timestamp, User,
 };
 
-use crate::serializations::cache::CachedPokemon;
+use crate::{
+    models::{BallType, DBDailyClaim, DBInventoryItem, TradeStatus},
+    serializations::cache::{CachedGeneration, CachedPokemon, CachedSpawnEntry},
+    utils::{
+        guild_settings::{GuildSettings, SpawnChannelMode},
+        spawn_table::{SpawnTier, SpawnTierStats},
+        trade::{TradeSide, MAX_TRADE_POKES},
+    },
+};
 
 // Functions
+/**
+A method to get the sprite of a pokemon.
+
+## Parameters:
+- `is_shiny`: A boolean to check if the pokemon is shiny.
+- `poke`: The pokemon.
+*/
+fn get_poke_sprite_url(is_shiny: bool, poke: &CachedPokemon) -> &str {
+    match is_shiny {
+        true => &poke.sprites.other.official_artwork.front_shiny,
+        false => &poke.sprites.other.official_artwork.front_default,
+    }
+}
+
+/**
+A method to describe the catch modifier of a ball.
+
+## Parameters:
+- `ball`: The type of the ball.
+*/
+fn describe_catch_modifier(ball: BallType) -> String {
+    match ball.get_catch_modifier() {
+        Some(modifier) => format!("x{} catch chance", modifier),
+        None => String::from("Never fails"),
+    }
+}
+
 /**
 A method to get a message when a wild pokemon has appeared.
 
 ## Parameters:
 - `is_shiny`: A boolean to check if the pokemon is shiny.
 - `poke`: The pokemon that has appeared.
+- `can_run`: If the message should have a button to make the pokemon run.
+- `flees_at`: When the pokemon'll flee. It's shown as a live countdown.
 */
-pub fn get_msg_wild_pokemon_appeared(is_shiny: bool, poke: &CachedPokemon) -> CreateMessage {
+pub fn get_msg_wild_pokemon_appeared(
+    is_shiny: bool,
+    poke: &CachedPokemon,
+    can_run: bool,
+    flees_at: Timestamp,
+) -> CreateMessage {
     let title = match is_shiny {
         true => format!("A wild shiny {} ✨ has appeared!", poke.name.to_uppercase()),
         false => format!("A wild {} has appeared!", poke.name.to_uppercase()),
     };
-    let sprite_url: &str = match is_shiny {
-        true => &poke.sprites.other.official_artwork.front_shiny,
-        false => &poke.sprites.other.official_artwork.front_default,
+    let countdown = FormattedTimestamp::new(flees_at, Some(FormattedTimestampStyle::RelativeTime));
+
+    let embed = CreateEmbed::new()
+        .title(title)
+        .image(get_poke_sprite_url(is_shiny, poke))
+        .description(format!(
+            "*Be quick to catch it or it will run away {}!*",
+            countdown
+        ));
+    let message =
+        BallType::ALL
+            .into_iter()
+            .fold(CreateMessage::new().embed(embed), |message, ball| {
+                message.button(
+                    CreateButton::new(format!("poke_spawn_catch_{}", ball.as_str()))
+                        .label(ball.get_name())
+                        .style(ButtonStyle::Success),
+                )
+            });
+
+    match can_run {
+        true => message.button(
+            CreateButton::new("poke_spawn_run")
+                .label("Run")
+                .style(ButtonStyle::Secondary),
+        ),
+        false => message,
+    }
+}
+
+/**
+A method to get the edit of a wild pokemon's message when it has been caught. The buttons are removed.
+
+## Parameters:
+- `is_shiny`: A boolean to check if the pokemon is shiny.
+- `poke`: The pokemon that was caught.
+- `mention`: The user that caught the pokemon.
+- `reward`: The coins earned by the user.
+*/
+pub fn get_edit_wild_pokemon_caught(
+    is_shiny: bool,
+    poke: &CachedPokemon,
+    mention: Mention,
+    reward: u64,
+) -> EditMessage {
+    let title = match is_shiny {
+        true => format!("The wild shiny {} ✨ was caught!", poke.name.to_uppercase()),
+        false => format!("The wild {} was caught!", poke.name.to_uppercase()),
     };
+    let embed = CreateEmbed::new()
+        .title(title)
+        .image(get_poke_sprite_url(is_shiny, poke))
+        .description(match reward {
+            0 => format!("{} caught it!", mention),
+            reward => format!("{} caught it! **+{} 🪙**", mention, reward),
+        });
+    EditMessage::new().embed(embed).components(vec![])
+}
 
+/**
+A method to get the edit of a wild pokemon's message when it has fled. The buttons are removed.
+
+## Parameters:
+- `is_shiny`: A boolean to check if the pokemon is shiny.
+- `poke`: The pokemon that has fled.
+*/
+pub fn get_edit_wild_pokemon_fled(is_shiny: bool, poke: &CachedPokemon) -> EditMessage {
+    let title = match is_shiny {
+        true => format!("The wild shiny {} ✨ has fled!", poke.name.to_uppercase()),
+        false => format!("The wild {} has fled!", poke.name.to_uppercase()),
+    };
     let embed = CreateEmbed::new()
         .title(title)
-        .image(sprite_url)
-        .description("*Be quick to catch it or it will run away!*");
-    CreateMessage::new().embed(embed)
+        .image(get_poke_sprite_url(is_shiny, poke))
+        .description("*Nobody caught it in time.*");
+    EditMessage::new().embed(embed).components(vec![])
+}
+
+/**
+A method to get the message for the users who tried to catch a pokemon after the capture event ended.
+
+## Parameters:
+- `is_captured`: If the pokemon was caught by someone else.
+*/
+pub fn get_msg_poke_spawn_too_late(is_captured: bool) -> CreateInteractionResponseMessage {
+    let content = match is_captured {
+        true => "Someone else caught it! Be quicker next time.",
+        false => "The pokémon is gone! Be quicker next time.",
+    };
+    CreateInteractionResponseMessage::new()
+        .content(content)
+        .ephemeral(true)
+}
+
+/**
+A method to get the message for the users who tried to make a pokemon they didn't find run.
+*/
+pub fn get_msg_poke_spawn_run_denied() -> CreateInteractionResponseMessage {
+    CreateInteractionResponseMessage::new()
+        .content("Only the trainer who found this pokémon can make it run.")
+        .ephemeral(true)
+}
+
+/**
+A method to get the message for a user whose catch attempt failed with a ball button.
+
+## Parameters:
+- `poke_name`: The name of the pokemon. The function'll uppercase it.
+- `ball`: The ball thrown at the pokemon.
+- `balls_left`: The amount of the ball the user still has.
+*/
+pub fn get_msg_poke_broke_free(
+    poke_name: &str,
+    ball: BallType,
+    balls_left: u64,
+) -> EditInteractionResponse {
+    EditInteractionResponse::new().content(format!(
+        "💨 Oh no! The {} broke free from the {}! Try again before it flees. ({} left)",
+        poke_name.to_uppercase(),
+        ball.get_name(),
+        balls_left
+    ))
+}
+
+/**
+A method to get the message when a user's catch attempt failed with a reaction.
+
+## Parameters:
+- `poke_name`: The name of the pokemon. The function'll uppercase it.
+- `mention`: The user who tried to catch the pokemon.
+*/
+pub fn get_msg_poke_broke_free_reaction(poke_name: &str, mention: Mention) -> CreateMessage {
+    CreateMessage::new().content(format!(
+        "💨 Oh no! The {} broke free from {}'s {}! React again to retry.",
+        poke_name.to_uppercase(),
+        mention,
+        BallType::Poke.get_name()
+    ))
+}
+
+/**
+A method to get the message for a user who threw a ball they don't have.
+
+## Parameters:
+- `ball`: The ball the user tried to throw.
+*/
+pub fn get_msg_poke_out_of_balls(ball: BallType) -> EditInteractionResponse {
+    EditInteractionResponse::new().content(format!(
+        "🎒 You don't have any {}! Check your balls with `/inventory`.",
+        ball.get_name()
+    ))
+}
+
+/**
+A method to get the message for a user whose ball couldn't be thrown because of an error.
+
+## Parameters:
+- `ball`: The ball the user tried to throw.
+*/
+pub fn get_msg_poke_catch_failed(ball: BallType) -> EditInteractionResponse {
+    EditInteractionResponse::new().content(format!(
+        "⚠️ Your {} couldn't be thrown! Try again before the pokémon flees.",
+        ball.get_name()
+    ))
+}
+
+/**
+A method to get the message when a user reacted to a pokemon without any Poké Ball.
+
+## Parameters:
+- `mention`: The user who tried to catch the pokemon.
+*/
+pub fn get_msg_poke_out_of_balls_reaction(mention: Mention) -> CreateMessage {
+    CreateMessage::new().content(format!(
+        "🎒 {} doesn't have any {}! Try another ball with the buttons.",
+        mention,
+        BallType::Poke.get_name()
+    ))
+}
+
+/**
+A method to get the message for a user who tried to catch a pokemon again too soon.
+
+## Parameters:
+- `remaining_secs`: The time in seconds until the user can try again.
+*/
+pub fn get_msg_poke_catch_cooldown(remaining_secs: u64) -> EditInteractionResponse {
+    EditInteractionResponse::new().content(format!(
+        "You're still getting a pokéball ready! Try again in {}s.",
+        remaining_secs.max(1)
+    ))
 }
 
 /**
@@ -72,6 +298,215 @@ pub fn get_msg_wild_pokemon_fled(is_shiny: bool, poke_name: &str) -> CreateMessa
     CreateMessage::new().embed(embed)
 }
 
+/**
+A method to get the message with a trainer's inventory.
+
+## Parameters:
+- `user`: The user that called the command.
+- `balance`: The coins owned by the user.
+- `inventory`: The amount of each ball owned by the user.
+*/
+pub fn get_msg_inventory(
+    user: &User,
+    balance: u64,
+    inventory: &[DBInventoryItem],
+) -> CreateInteractionResponseMessage {
+    let embed = inventory.iter().fold(
+        CreateEmbed::new()
+            .title(format!("{}'s Inventory 🎒", user.name))
+            .author(CreateEmbedAuthor::from(user.clone()))
+            .description(format!("🪙 **Balance**: {} coins", balance)),
+        |embed, item| {
+            embed.field(
+                item.ball.get_name(),
+                format!(
+                    "**{}**\n*{}*",
+                    item.quantity,
+                    describe_catch_modifier(item.ball)
+                ),
+                true,
+            )
+        },
+    );
+    CreateInteractionResponseMessage::new()
+        .embed(embed)
+        .ephemeral(true)
+}
+
+/**
+A method to get the message with the items sold in the shop.
+
+## Parameters:
+- `balance`: The coins owned by the user.
+- `prices`: The price of each ball, or `None` if it isn't sold.
+*/
+pub fn get_msg_shop(
+    balance: u64,
+    prices: &[(BallType, Option<u64>)],
+) -> CreateInteractionResponseMessage {
+    let embed = prices.iter().fold(
+        CreateEmbed::new().title("🛒 Shop").description(format!(
+            "Buy an item with `/shop buy <item> [quantity]`.\n🪙 **Balance**: {} coins",
+            balance
+        )),
+        |embed, (ball, price)| {
+            let price = match price {
+                Some(price) => format!("**{} 🪙**", price),
+                None => String::from("*Not for sale*"),
+            };
+            embed.field(
+                ball.get_name(),
+                format!("{}\n*{}*", price, describe_catch_modifier(*ball)),
+                true,
+            )
+        },
+    );
+    CreateInteractionResponseMessage::new()
+        .embed(embed)
+        .ephemeral(true)
+}
+
+/**
+A method to get the message when a user bought an item in the shop.
+
+## Parameters:
+- `ball`: The ball bought.
+- `quantity`: The amount of balls bought.
+- `cost`: The coins spent.
+- `balance`: The coins the user has left.
+*/
+pub fn get_msg_shop_purchased(
+    ball: BallType,
+    quantity: u64,
+    cost: u64,
+    balance: u64,
+) -> CreateInteractionResponseMessage {
+    CreateInteractionResponseMessage::new()
+        .content(format!(
+            "🛍️ You bought {}x {} for {} 🪙! You have {} coins left.",
+            quantity,
+            ball.get_name(),
+            cost,
+            balance
+        ))
+        .ephemeral(true)
+}
+
+/**
+A method to get the message when a user can't afford an item in the shop.
+
+## Parameters:
+- `ball`: The ball the user tried to buy.
+- `quantity`: The amount of balls the user tried to buy.
+- `cost`: The coins needed.
+- `balance`: The coins owned by the user.
+*/
+pub fn get_msg_shop_insufficient_balance(
+    ball: BallType,
+    quantity: u64,
+    cost: u64,
+    balance: u64,
+) -> CreateInteractionResponseMessage {
+    CreateInteractionResponseMessage::new()
+        .content(format!(
+            "💸 {}x {} costs {} 🪙, but you only have {} coins. Go catch some pokémons!",
+            quantity,
+            ball.get_name(),
+            cost,
+            balance
+        ))
+        .ephemeral(true)
+}
+
+/**
+A method to get the message when the cost of a purchase is too high to be paid.
+
+## Parameters:
+- `ball`: The ball the user tried to buy.
+- `quantity`: The amount of balls the user tried to buy.
+*/
+pub fn get_msg_shop_cost_too_high(
+    ball: BallType,
+    quantity: u64,
+) -> CreateInteractionResponseMessage {
+    CreateInteractionResponseMessage::new()
+        .content(format!(
+            "💸 {}x {} costs more coins than anyone could ever have.",
+            quantity,
+            ball.get_name()
+        ))
+        .ephemeral(true)
+}
+
+/**
+A method to get the message when a user tried to buy an item that isn't sold.
+
+## Parameters:
+- `ball`: The ball the user tried to buy.
+*/
+pub fn get_msg_shop_not_for_sale(ball: BallType) -> CreateInteractionResponseMessage {
+    CreateInteractionResponseMessage::new()
+        .content(format!("The {} isn't for sale.", ball.get_name()))
+        .ephemeral(true)
+}
+
+/**
+A method to get the message when a user claimed their daily reward.
+
+## Parameters:
+- `claim`: The daily reward claimed by the user.
+- `broken_streak`: The streak the user lost by missing a day, if any.
+- `balance`: The coins owned by the user after the claim.
+*/
+pub fn get_msg_daily_claimed(
+    claim: &DBDailyClaim,
+    broken_streak: Option<u32>,
+    balance: u64,
+) -> CreateInteractionResponseMessage {
+    let mut embed = CreateEmbed::new()
+        .title("🎁 Daily reward claimed!")
+        .field("Coins", format!("+{} 🪙", claim.reward), true)
+        .field(
+            BallType::Poke.get_name(),
+            format!("+{}", claim.poke_balls),
+            true,
+        )
+        .field("Streak", format!("🔥 {} days", claim.streak), true)
+        .footer(CreateEmbedFooter::new(format!(
+            "Balance: {} coins",
+            balance
+        )));
+    if let Some(broken_streak) = broken_streak {
+        embed = embed.description(format!(
+            "💔 You missed a day and lost your {} day streak. Come back tomorrow to build it again!",
+            broken_streak
+        ));
+    }
+
+    CreateInteractionResponseMessage::new().embed(embed)
+}
+
+/**
+A method to get the message when a user already claimed their daily reward today.
+
+## Parameters:
+- `streak`: The current streak of the user.
+- `next_claim_at`: When the user can claim the next reward. It's shown as a live countdown.
+*/
+pub fn get_msg_daily_already_claimed(
+    streak: u32,
+    next_claim_at: Timestamp,
+) -> CreateInteractionResponseMessage {
+    let countdown =
+        FormattedTimestamp::new(next_claim_at, Some(FormattedTimestampStyle::RelativeTime));
+    CreateInteractionResponseMessage::new()
+        .content(format!(
+            "You already claimed today's reward! 🔥 {} day streak. The next one is ready {}.",
+            streak, countdown
+        ))
+        .ephemeral(true)
+}
+
 /**
 A method to get a message to ack the /pokedex command.
 */
@@ -122,6 +557,512 @@ pub fn get_embed_pokedex_content(
         .description(pokedex_description)
 }
 
+/**
+A method to get the message with the settings of a guild.
+
+## Parameters:
+- `settings`: A list of (key, effective value, is overridden) tuples.
+*/
+pub fn get_msg_config_settings(
+    settings: &[(&str, String, bool)],
+) -> CreateInteractionResponseMessage {
+    let mut embed = CreateEmbed::new()
+        .title("⚙️ Server settings")
+        .description("Settings that weren't changed in this server use the bot's default.");
+    for (key, value, is_overridden) in settings {
+        let source = match is_overridden {
+            true => "server",
+            false => "default",
+        };
+        embed = embed.field(*key, format!("{} *({})*", value, source), true);
+    }
+
+    CreateInteractionResponseMessage::new()
+        .embed(embed)
+        .ephemeral(true)
+}
+
+/**
+A method to get the message when a setting of a guild has been updated.
+
+## Parameters:
+- `key`: The setting's key.
+- `value`: The setting's new value.
+*/
+pub fn get_msg_config_updated(key: &str, value: &str) -> CreateInteractionResponseMessage {
+    let embed = CreateEmbed::new()
+        .title("✅ Setting updated!")
+        .description(format!("`{}` is now {} in this server.", key, value));
+    CreateInteractionResponseMessage::new()
+        .embed(embed)
+        .ephemeral(true)
+}
+
+/**
+A method to get the message when the settings of a guild have been reset.
+
+## Parameters:
+- `key`: The setting's key. If `None`, all the settings were reset.
+*/
+pub fn get_msg_config_reset(key: Option<&str>) -> CreateInteractionResponseMessage {
+    let description = match key {
+        Some(key) => format!("`{}` now uses the bot's default in this server.", key),
+        None => String::from("All the settings now use the bot's default in this server."),
+    };
+    let embed = CreateEmbed::new()
+        .title("✅ Settings reset!")
+        .description(description);
+    CreateInteractionResponseMessage::new()
+        .embed(embed)
+        .ephemeral(true)
+}
+
+/**
+A method to get the message when a setting's value is invalid.
+
+## Parameters:
+- `key`: The setting's key.
+- `value`: The invalid value.
+- `reason`: The reason why the value is invalid.
+*/
+pub fn get_msg_config_invalid_value(
+    key: &str,
+    value: &str,
+    reason: &str,
+) -> CreateInteractionResponseMessage {
+    let embed = CreateEmbed::new()
+        .title("❌ Invalid value!")
+        .description(format!("`{}` {}. Got `{}`.", key, reason, value));
+    CreateInteractionResponseMessage::new()
+        .embed(embed)
+        .ephemeral(true)
+}
+
+/**
+A method to get the message when the settings of a guild were changed by another command first.
+*/
+pub fn get_msg_config_conflict() -> CreateInteractionResponseMessage {
+    let embed = CreateEmbed::new()
+        .title("❌ Settings changed!")
+        .description("The settings were changed by someone else at the same time. Try again.");
+    CreateInteractionResponseMessage::new()
+        .embed(embed)
+        .ephemeral(true)
+}
+
+/**
+A method to get the message when a user without the Manage Server permission tries to change the settings.
+*/
+pub fn get_msg_config_missing_permission() -> CreateInteractionResponseMessage {
+    let embed = CreateEmbed::new()
+        .title("❌ Missing permission!")
+        .description("You need the **Manage Server** permission to manage the bot's settings.");
+    CreateInteractionResponseMessage::new()
+        .embed(embed)
+        .ephemeral(true)
+}
+
+/**
+A method to get the message with the spawn channels of a guild.
+
+## Parameters:
+- `settings`: The effective settings of the guild.
+*/
+pub fn get_msg_spawn_channels(settings: &GuildSettings) -> CreateInteractionResponseMessage {
+    let mode = match settings.spawn_channel_mode {
+        SpawnChannelMode::All => "Every channel can spawn pokémons.",
+        SpawnChannelMode::Allow => "Only the listed channels can spawn pokémons.",
+        SpawnChannelMode::Deny => "Every channel but the listed ones can spawn pokémons.",
+    };
+    let channels = match settings.spawn_channel_ids.is_empty() {
+        true => String::from("*None*"),
+        false => settings
+            .spawn_channel_ids
+            .iter()
+            .map(|channel_id| channel_id.mention().to_string())
+            .collect::<Vec<String>>()
+            .join(", "),
+    };
+    let wild_area = match settings.wild_area_channel_id {
+        Some(channel_id) => channel_id.mention().to_string(),
+        None => String::from("*None*"),
+    };
+
+    let embed = CreateEmbed::new()
+        .title("🌿 Spawn channels")
+        .description(mode)
+        .field("Listed channels", channels, false)
+        .field("Wild area", wild_area, false);
+    CreateInteractionResponseMessage::new()
+        .embed(embed)
+        .ephemeral(true)
+}
+
+/**
+A method to get the message with the generations that can spawn in a guild.
+
+## Parameters:
+- `generations`: All the generations.
+- `enabled_ids`: The IDs of the generations that can spawn.
+*/
+pub fn get_msg_spawn_generations(
+    generations: &[CachedGeneration],
+    enabled_ids: &[u8],
+) -> CreateInteractionResponseMessage {
+    let description = generations
+        .iter()
+        .map(|generation| {
+            let status = match enabled_ids.contains(&generation.id) {
+                true => "✅",
+                false => "❌",
+            };
+            let number = generation.name.trim_start_matches("generation-");
+            let mut region = generation.region.chars();
+            let region = match region.next() {
+                Some(first) => first.to_uppercase().chain(region).collect(),
+                None => String::new(),
+            };
+            format!(
+                "{} **Generation {}** ({}) - {} species",
+                status,
+                number.to_uppercase(),
+                region,
+                generation.species_ids.len()
+            )
+        })
+        .collect::<Vec<String>>()
+        .join("\n");
+
+    let embed = CreateEmbed::new()
+        .title("🗺️ Spawn generations")
+        .description(description);
+    CreateInteractionResponseMessage::new()
+        .embed(embed)
+        .ephemeral(true)
+}
+
+/**
+A method to get the message with the recent spawns of a guild.
+
+## Parameters:
+- `descriptions`: The description of each spawn, from the newest to the oldest.
+*/
+pub fn get_msg_spawns_recent(descriptions: &[String]) -> CreateInteractionResponseMessage {
+    let description = match descriptions.is_empty() {
+        true => String::from("No pokémon has spawned in this server yet."),
+        false => descriptions.join("\n"),
+    };
+
+    let embed = CreateEmbed::new()
+        .title("📜 Recent spawns")
+        .description(description);
+    CreateInteractionResponseMessage::new()
+        .embed(embed)
+        .ephemeral(true)
+}
+
+/**
+A method to get the message with the effective spawn distribution.
+
+## Parameters:
+- `tier_stats`: The distribution of each rarity tier.
+- `poke`: A pokémon requested by the user and its chance to spawn, if any.
+*/
+pub fn get_msg_spawn_rates(
+    tier_stats: &[SpawnTierStats],
+    poke: Option<(&CachedSpawnEntry, f64)>,
+) -> CreateInteractionResponseMessage {
+    let mut embed = CreateEmbed::new()
+        .title("🎲 Spawn rates")
+        .description("The chance of each rarity tier when a pokémon spawns.");
+    for stats in tier_stats {
+        let average = match stats.species {
+            0 => 0.0,
+            species => stats.chance / species as f64,
+        };
+        embed = embed.field(
+            stats.tier.as_str(),
+            format!(
+                "**{:.3}%** of the spawns\n{} species, {:.4}% each on average",
+                stats.chance * 100.0,
+                stats.species,
+                average * 100.0
+            ),
+            true,
+        );
+    }
+
+    if let Some((entry, chance)) = poke {
+        embed = embed.field(
+            format!("[#{}] {}", entry.id, entry.name.to_uppercase()),
+            format!(
+                "{} tier, capture rate {}\n**{:.4}%** of the spawns",
+                SpawnTier::from_entry(entry).as_str(),
+                entry.capture_rate,
+                chance * 100.0
+            ),
+            false,
+        );
+    }
+
+    CreateInteractionResponseMessage::new()
+        .embed(embed)
+        .ephemeral(true)
+}
+
+/**
+A method to get the message when a pokémon requested by the user isn't in the spawn table.
+
+## Parameters:
+- `identifier`: The ID or the name requested by the user.
+*/
+pub fn get_msg_spawn_rates_not_found(identifier: &str) -> CreateInteractionResponseMessage {
+    let embed = CreateEmbed::new()
+        .title("❌ Pokémon not found!")
+        .description(format!("No pokémon named `{}` can spawn.", identifier));
+    CreateInteractionResponseMessage::new()
+        .embed(embed)
+        .ephemeral(true)
+}
+
+/**
+A method to get the message when a user without the Manage Messages permission tries to see the spawns.
+*/
+pub fn get_msg_spawns_missing_permission() -> CreateInteractionResponseMessage {
+    let embed = CreateEmbed::new()
+        .title("❌ Missing permission!")
+        .description("You need the **Manage Messages** permission to see the server's spawns.");
+    CreateInteractionResponseMessage::new()
+        .embed(embed)
+        .ephemeral(true)
+}
+
+/**
+A method to describe the pokémons offered by a trainer in a trade.
+
+## Parameters:
+- `side`: The trainer's side of the trade.
+*/
+fn describe_trade_offer(side: &TradeSide) -> String {
+    let offer = side.get_offer();
+    match offer.is_empty() {
+        true => String::from("*Nothing yet*"),
+        false => offer
+            .iter()
+            .map(|option| format!("- {}", option.get_label()))
+            .collect::<Vec<String>>()
+            .join("\n"),
+    }
+}
+
+/**
+A method to get the embed fields with the offers of a trade.
+
+## Parameters:
+- `embed`: The embed to add the fields to.
+- `initiator`: The side of the trainer who started the trade.
+- `partner`: The side of the trainer who was invited to the trade.
+*/
+fn add_trade_offer_fields(
+    embed: CreateEmbed,
+    initiator: &TradeSide,
+    partner: &TradeSide,
+) -> CreateEmbed {
+    [initiator, partner].into_iter().fold(embed, |embed, side| {
+        let confirmed = if side.confirmed { " ✅" } else { "" };
+        embed.field(
+            format!("{} offers{}", side.user.name, confirmed),
+            describe_trade_offer(side),
+            true,
+        )
+    })
+}
+
+/**
+A method to get the message of an ongoing trade, with a menu for each trainer to pick their offer.
+
+## Parameters:
+- `initiator`: The side of the trainer who started the trade.
+- `partner`: The side of the trainer who was invited to the trade.
+- `expires_at`: When the trade expires. It's shown as a live countdown.
+*/
+pub fn get_msg_trade(
+    initiator: &TradeSide,
+    partner: &TradeSide,
+    expires_at: Timestamp,
+) -> CreateInteractionResponseMessage {
+    let countdown =
+        FormattedTimestamp::new(expires_at, Some(FormattedTimestampStyle::RelativeTime));
+    let embed = CreateEmbed::new()
+        .title(format!(
+            "🔄 Trade between {} and {}",
+            initiator.user.name, partner.user.name
+        ))
+        .description(format!(
+            "Pick the pokémons you want to trade, then both press **Confirm**. Changing an offer resets the confirmations.\n\n*The trade expires {}.*",
+            countdown
+        ));
+    let embed = add_trade_offer_fields(embed, initiator, partner);
+
+    let mut components: Vec<CreateActionRow> = [
+        ("trade_select_initiator", initiator),
+        ("trade_select_partner", partner),
+    ]
+    .into_iter()
+    .filter(|(_, side)| !side.options.is_empty())
+    .map(|(custom_id, side)| {
+        let page_options = side.get_page_options();
+        let options = page_options
+            .iter()
+            .map(|option| {
+                CreateSelectMenuOption::new(option.get_label(), option.get_value())
+                    .default_selection(side.selected.contains(&option.get_value()))
+            })
+            .collect();
+        let page = match side.get_page_count() {
+            1 => String::new(),
+            page_count => format!(" (page {}/{})", side.page + 1, page_count),
+        };
+        let menu = CreateSelectMenu::new(custom_id, CreateSelectMenuKind::String { options })
+            .placeholder(format!("Pokémons offered by {}{}", side.user.name, page))
+            .min_values(0)
+            .max_values(page_options.len().min(side.get_page_slots()).max(1) as u8);
+        CreateActionRow::SelectMenu(menu)
+    })
+    .collect();
+
+    // The page buttons move the menu of the trainer who presses them.
+    let mut buttons = Vec::new();
+    if initiator.get_page_count() > 1 || partner.get_page_count() > 1 {
+        buttons.push(CreateButton::new("trade_page_previous").label("◀ Previous page"));
+        buttons.push(CreateButton::new("trade_page_next").label("Next page ▶"));
+    }
+    buttons.push(
+        CreateButton::new("trade_confirm")
+            .label("Confirm")
+            .style(ButtonStyle::Success),
+    );
+    buttons.push(
+        CreateButton::new("trade_cancel")
+            .label("Cancel")
+            .style(ButtonStyle::Danger),
+    );
+    components.push(CreateActionRow::Buttons(buttons));
+
+    CreateInteractionResponseMessage::new()
+        .content(format!(
+            "{}, {} wants to trade with you!",
+            partner.user.mention(),
+            initiator.user.mention()
+        ))
+        .embed(embed)
+        .components(components)
+}
+
+/**
+A method to get the edit of a trade's message when the trade ends.
+
+## Parameters:
+- `initiator`: The side of the trainer who started the trade.
+- `partner`: The side of the trainer who was invited to the trade.
+- `status`: How the trade ended.
+*/
+pub fn get_edit_trade_ended(
+    initiator: &TradeSide,
+    partner: &TradeSide,
+    status: TradeStatus,
+) -> EditInteractionResponse {
+    let (title, description) = match status {
+        TradeStatus::Completed => (
+            "✅ Trade completed!",
+            "The pokémons have changed hands. Take good care of them!",
+        ),
+        TradeStatus::Cancelled => (
+            "❌ Trade cancelled!",
+            "One of the trainers cancelled the trade.",
+        ),
+        TradeStatus::Expired => (
+            "⌛ Trade expired!",
+            "The trainers didn't confirm the trade in time.",
+        ),
+        TradeStatus::Failed => (
+            "⚠️ Trade failed!",
+            "Some pokémons changed hands before the swap, so nothing was traded.",
+        ),
+    };
+    let embed = CreateEmbed::new().title(title).description(description);
+    let embed = add_trade_offer_fields(embed, initiator, partner);
+    EditInteractionResponse::new()
+        .embed(embed)
+        .components(vec![])
+}
+
+/**
+A method to get the message when a user tries to trade with themselves.
+*/
+pub fn get_msg_trade_with_self() -> CreateInteractionResponseMessage {
+    CreateInteractionResponseMessage::new()
+        .content("You can't trade with yourself!")
+        .ephemeral(true)
+}
+
+/**
+A method to get the message when a user tries to trade with a bot.
+*/
+pub fn get_msg_trade_with_bot() -> CreateInteractionResponseMessage {
+    CreateInteractionResponseMessage::new()
+        .content("Bots don't have any pokémons to trade!")
+        .ephemeral(true)
+}
+
+/**
+A method to get the message when neither trainer of a trade has a pokémon.
+*/
+pub fn get_msg_trade_no_pokes() -> CreateInteractionResponseMessage {
+    CreateInteractionResponseMessage::new()
+        .content("Neither of you has pokémons to trade. Go catch some first!")
+        .ephemeral(true)
+}
+
+/**
+A method to get the message when a user who isn't part of a trade interacts with it.
+*/
+pub fn get_msg_trade_not_participant() -> CreateInteractionResponseMessage {
+    CreateInteractionResponseMessage::new()
+        .content("This trade isn't yours. Start your own with `/trade`!")
+        .ephemeral(true)
+}
+
+/**
+A method to get the message when a trainer tries to change the other trainer's offer.
+*/
+pub fn get_msg_trade_not_your_offer() -> CreateInteractionResponseMessage {
+    CreateInteractionResponseMessage::new()
+        .content("You can only pick the pokémons you offer.")
+        .ephemeral(true)
+}
+
+/**
+A method to get the message when a trainer offers more pokémons than a trade allows.
+*/
+pub fn get_msg_trade_too_many_pokes() -> CreateInteractionResponseMessage {
+    CreateInteractionResponseMessage::new()
+        .content(format!(
+            "You can offer up to {} pokémons in a trade. Remove some before picking others.",
+            MAX_TRADE_POKES
+        ))
+        .ephemeral(true)
+}
+
+/**
+A method to get the message when a trainer confirms a trade without any pokémon offered.
+*/
+pub fn get_msg_trade_empty() -> CreateInteractionResponseMessage {
+    CreateInteractionResponseMessage::new()
+        .content("Pick at least one pokémon before confirming the trade.")
+        .ephemeral(true)
+}
+
 /**
 A method to get a message when a dev command has been called.
 */
@@ -0,0 +1,26 @@
+// Libs
+use super::Migration;
+
+// Migration
+pub const MIGRATION: Migration = Migration {
+    version: 1,
+    name: "create_trainer_and_pokemon",
+    up: "
+        DEFINE TABLE trainer SCHEMAFULL;
+        DEFINE FIELD discord_id ON TABLE trainer TYPE string;
+        DEFINE FIELD discord_guild_id ON TABLE trainer TYPE string;
+        DEFINE FIELD created_at ON TABLE trainer TYPE datetime;
+        DEFINE FIELD updated_at ON TABLE trainer TYPE datetime;
+
+        DEFINE TABLE pokemon SCHEMAFULL;
+        DEFINE FIELD trainer_id ON TABLE pokemon TYPE record;
+        DEFINE FIELD poke_id ON TABLE pokemon TYPE number;
+        DEFINE FIELD is_shiny ON TABLE pokemon TYPE bool;
+        DEFINE FIELD created_at ON TABLE pokemon TYPE datetime;
+        DEFINE FIELD updated_at ON TABLE pokemon TYPE datetime;
+    ",
+    down: "
+        REMOVE TABLE pokemon;
+        REMOVE TABLE trainer;
+    ",
+};
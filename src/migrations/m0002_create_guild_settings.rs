@@ -0,0 +1,22 @@
+// Libs
+use super::Migration;
+
+// Migration
+pub const MIGRATION: Migration = Migration {
+    version: 2,
+    name: "create_guild_settings",
+    up: "
+        DEFINE TABLE guild_settings SCHEMAFULL;
+        DEFINE FIELD discord_guild_id ON TABLE guild_settings TYPE string;
+        DEFINE FIELD poke_spawn_rate ON TABLE guild_settings TYPE option<number>;
+        DEFINE FIELD poke_shiny_rate ON TABLE guild_settings TYPE option<number>;
+        DEFINE FIELD poke_flee_time_secs ON TABLE guild_settings TYPE option<number>;
+        DEFINE FIELD pokeball_emoji ON TABLE guild_settings TYPE option<string>;
+        DEFINE FIELD created_at ON TABLE guild_settings TYPE datetime;
+        DEFINE FIELD updated_at ON TABLE guild_settings TYPE datetime;
+        DEFINE INDEX guild_settings_discord_guild_id ON TABLE guild_settings COLUMNS discord_guild_id UNIQUE;
+    ",
+    down: "
+        REMOVE TABLE guild_settings;
+    ",
+};
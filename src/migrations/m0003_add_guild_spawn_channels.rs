@@ -0,0 +1,21 @@
+// Libs
+use super::Migration;
+
+// Migration
+pub const MIGRATION: Migration = Migration {
+    version: 3,
+    name: "add_guild_spawn_channels",
+    up: "
+        DEFINE FIELD spawn_channel_mode ON TABLE guild_settings TYPE option<string>
+            ASSERT $value = NONE OR $value IN ['allow', 'deny'];
+        DEFINE FIELD spawn_channel_ids ON TABLE guild_settings TYPE array<string> DEFAULT [];
+        DEFINE FIELD wild_area_channel_id ON TABLE guild_settings TYPE option<string>;
+        UPDATE guild_settings SET spawn_channel_ids = [] WHERE spawn_channel_ids = NONE;
+    ",
+    down: "
+        REMOVE FIELD spawn_channel_mode ON TABLE guild_settings;
+        REMOVE FIELD spawn_channel_ids ON TABLE guild_settings;
+        REMOVE FIELD wild_area_channel_id ON TABLE guild_settings;
+        UPDATE guild_settings UNSET spawn_channel_mode, spawn_channel_ids, wild_area_channel_id;
+    ",
+};
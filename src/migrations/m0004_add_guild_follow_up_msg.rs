@@ -0,0 +1,15 @@
+// Libs
+use super::Migration;
+
+// Migration
+pub const MIGRATION: Migration = Migration {
+    version: 4,
+    name: "add_guild_follow_up_msg",
+    up: "
+        DEFINE FIELD poke_follow_up_msg ON TABLE guild_settings TYPE option<bool>;
+    ",
+    down: "
+        REMOVE FIELD poke_follow_up_msg ON TABLE guild_settings;
+        UPDATE guild_settings UNSET poke_follow_up_msg;
+    ",
+};
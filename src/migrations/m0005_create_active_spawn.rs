@@ -0,0 +1,23 @@
+// Libs
+use super::Migration;
+
+// Migration
+pub const MIGRATION: Migration = Migration {
+    version: 5,
+    name: "create_active_spawn",
+    up: "
+        DEFINE TABLE active_spawn SCHEMAFULL;
+        DEFINE FIELD discord_guild_id ON TABLE active_spawn TYPE string;
+        DEFINE FIELD discord_channel_id ON TABLE active_spawn TYPE string;
+        DEFINE FIELD discord_message_id ON TABLE active_spawn TYPE string;
+        DEFINE FIELD spawner_id ON TABLE active_spawn TYPE option<string>;
+        DEFINE FIELD poke_id ON TABLE active_spawn TYPE number;
+        DEFINE FIELD is_shiny ON TABLE active_spawn TYPE bool;
+        DEFINE FIELD expires_at ON TABLE active_spawn TYPE datetime;
+        DEFINE FIELD created_at ON TABLE active_spawn TYPE datetime;
+        DEFINE FIELD updated_at ON TABLE active_spawn TYPE datetime;
+    ",
+    down: "
+        REMOVE TABLE active_spawn;
+    ",
+};
@@ -0,0 +1,28 @@
+// Libs
+use super::Migration;
+
+// Migration
+pub const MIGRATION: Migration = Migration {
+    version: 6,
+    name: "create_spawn_event",
+    up: "
+        DEFINE TABLE spawn_event SCHEMAFULL;
+        DEFINE FIELD discord_guild_id ON TABLE spawn_event TYPE string;
+        DEFINE FIELD discord_channel_id ON TABLE spawn_event TYPE string;
+        DEFINE FIELD discord_message_id ON TABLE spawn_event TYPE string;
+        DEFINE FIELD spawner_id ON TABLE spawn_event TYPE option<string>;
+        DEFINE FIELD poke_id ON TABLE spawn_event TYPE number;
+        DEFINE FIELD is_shiny ON TABLE spawn_event TYPE bool;
+        DEFINE FIELD outcome ON TABLE spawn_event TYPE string
+            ASSERT $value IN ['caught', 'fled', 'ran'];
+        DEFINE FIELD catcher_id ON TABLE spawn_event TYPE option<string>;
+        DEFINE FIELD catch_latency_ms ON TABLE spawn_event TYPE option<number>;
+        DEFINE FIELD spawned_at ON TABLE spawn_event TYPE datetime;
+        DEFINE FIELD created_at ON TABLE spawn_event TYPE datetime;
+        DEFINE FIELD updated_at ON TABLE spawn_event TYPE datetime;
+        DEFINE INDEX spawn_event_discord_guild_id ON TABLE spawn_event COLUMNS discord_guild_id;
+    ",
+    down: "
+        REMOVE TABLE spawn_event;
+    ",
+};
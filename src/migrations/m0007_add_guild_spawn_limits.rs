@@ -0,0 +1,19 @@
+// Libs
+use super::Migration;
+
+// Migration
+pub const MIGRATION: Migration = Migration {
+    version: 7,
+    name: "add_guild_spawn_limits",
+    up: "
+        DEFINE FIELD poke_spawn_cooldown_secs ON TABLE guild_settings TYPE option<number>;
+        DEFINE FIELD poke_max_spawns_per_channel ON TABLE guild_settings TYPE option<number>;
+        DEFINE FIELD poke_max_spawns_per_guild ON TABLE guild_settings TYPE option<number>;
+    ",
+    down: "
+        REMOVE FIELD poke_spawn_cooldown_secs ON TABLE guild_settings;
+        REMOVE FIELD poke_max_spawns_per_channel ON TABLE guild_settings;
+        REMOVE FIELD poke_max_spawns_per_guild ON TABLE guild_settings;
+        UPDATE guild_settings UNSET poke_spawn_cooldown_secs, poke_max_spawns_per_channel, poke_max_spawns_per_guild;
+    ",
+};
@@ -0,0 +1,23 @@
+// Libs
+use super::Migration;
+
+// Migration
+pub const MIGRATION: Migration = Migration {
+    version: 8,
+    name: "add_guild_msg_filters",
+    up: "
+        DEFINE FIELD msg_min_length ON TABLE guild_settings TYPE option<number>;
+        DEFINE FIELD msg_duplicate_window_secs ON TABLE guild_settings TYPE option<number>;
+        DEFINE FIELD msg_user_cooldown_secs ON TABLE guild_settings TYPE option<number>;
+        DEFINE FIELD msg_min_distinct_authors ON TABLE guild_settings TYPE option<number>;
+        DEFINE FIELD msg_distinct_authors_window_secs ON TABLE guild_settings TYPE option<number>;
+    ",
+    down: "
+        REMOVE FIELD msg_min_length ON TABLE guild_settings;
+        REMOVE FIELD msg_duplicate_window_secs ON TABLE guild_settings;
+        REMOVE FIELD msg_user_cooldown_secs ON TABLE guild_settings;
+        REMOVE FIELD msg_min_distinct_authors ON TABLE guild_settings;
+        REMOVE FIELD msg_distinct_authors_window_secs ON TABLE guild_settings;
+        UPDATE guild_settings UNSET msg_min_length, msg_duplicate_window_secs, msg_user_cooldown_secs, msg_min_distinct_authors, msg_distinct_authors_window_secs;
+    ",
+};
@@ -0,0 +1,18 @@
+// Libs
+use super::Migration;
+
+// Migration
+pub const MIGRATION: Migration = Migration {
+    version: 9,
+    name: "add_guild_spawn_model",
+    up: "
+        DEFINE FIELD poke_spawn_model ON TABLE guild_settings TYPE option<string>
+            ASSERT $value = NONE OR $value IN ['message', 'activity'];
+        DEFINE FIELD poke_spawns_per_hour ON TABLE guild_settings TYPE option<number>;
+    ",
+    down: "
+        REMOVE FIELD poke_spawn_model ON TABLE guild_settings;
+        REMOVE FIELD poke_spawns_per_hour ON TABLE guild_settings;
+        UPDATE guild_settings UNSET poke_spawn_model, poke_spawns_per_hour;
+    ",
+};
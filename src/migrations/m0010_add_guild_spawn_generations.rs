@@ -0,0 +1,16 @@
+// Libs
+use super::Migration;
+
+// Migration
+pub const MIGRATION: Migration = Migration {
+    version: 10,
+    name: "add_guild_spawn_generations",
+    up: "
+        DEFINE FIELD spawn_generations ON TABLE guild_settings TYPE array<number> DEFAULT [];
+        UPDATE guild_settings SET spawn_generations = [] WHERE spawn_generations = NONE;
+    ",
+    down: "
+        REMOVE FIELD spawn_generations ON TABLE guild_settings;
+        UPDATE guild_settings UNSET spawn_generations;
+    ",
+};
@@ -0,0 +1,21 @@
+// Libs
+use super::Migration;
+
+// Migration
+pub const MIGRATION: Migration = Migration {
+    version: 11,
+    name: "create_inventory",
+    up: "
+        DEFINE TABLE inventory SCHEMAFULL;
+        DEFINE FIELD trainer_id ON TABLE inventory TYPE record;
+        DEFINE FIELD ball ON TABLE inventory TYPE string
+            ASSERT $value IN ['poke_ball', 'great_ball', 'ultra_ball', 'master_ball'];
+        DEFINE FIELD quantity ON TABLE inventory TYPE number ASSERT $value >= 0;
+        DEFINE FIELD created_at ON TABLE inventory TYPE datetime;
+        DEFINE FIELD updated_at ON TABLE inventory TYPE datetime;
+        DEFINE INDEX inventory_trainer_id_ball ON TABLE inventory COLUMNS trainer_id, ball UNIQUE;
+    ",
+    down: "
+        REMOVE TABLE inventory;
+    ",
+};
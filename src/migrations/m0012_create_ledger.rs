@@ -0,0 +1,28 @@
+// Libs
+use super::Migration;
+
+// Migration
+pub const MIGRATION: Migration = Migration {
+    version: 12,
+    name: "create_ledger",
+    up: "
+        DEFINE FIELD balance ON TABLE trainer TYPE number DEFAULT 0 ASSERT $value >= 0;
+        UPDATE trainer SET balance = 0 WHERE balance = NONE;
+
+        DEFINE TABLE ledger SCHEMAFULL;
+        DEFINE FIELD trainer_id ON TABLE ledger TYPE record;
+        DEFINE FIELD amount ON TABLE ledger TYPE number;
+        DEFINE FIELD reason ON TABLE ledger TYPE string
+            ASSERT $value IN ['catch', 'purchase'];
+        DEFINE FIELD details ON TABLE ledger TYPE string;
+        DEFINE FIELD created_at ON TABLE ledger TYPE datetime;
+        DEFINE FIELD updated_at ON TABLE ledger TYPE datetime;
+        DEFINE INDEX ledger_trainer_id ON TABLE ledger COLUMNS trainer_id;
+    ",
+    down: "
+        REMOVE TABLE ledger;
+
+        REMOVE FIELD balance ON TABLE trainer;
+        UPDATE trainer UNSET balance;
+    ",
+};
@@ -0,0 +1,74 @@
+// Libs
+use super::Migration;
+
+// Migration
+/**
+The ledger keeps accepting the `daily` reason when it's reverted, so the daily rewards already paid
+stay recorded and the balances can still be audited.
+*/
+pub const MIGRATION: Migration = Migration {
+    version: 13,
+    name: "create_daily_claim",
+    up: "
+        DEFINE TABLE daily_claim SCHEMAFULL;
+        DEFINE FIELD trainer_id ON TABLE daily_claim TYPE record;
+        DEFINE FIELD day ON TABLE daily_claim TYPE number;
+        DEFINE FIELD streak ON TABLE daily_claim TYPE number;
+        DEFINE FIELD reward ON TABLE daily_claim TYPE number;
+        DEFINE FIELD poke_balls ON TABLE daily_claim TYPE number;
+        DEFINE FIELD created_at ON TABLE daily_claim TYPE datetime;
+        DEFINE FIELD updated_at ON TABLE daily_claim TYPE datetime;
+        DEFINE INDEX daily_claim_trainer_id_day ON TABLE daily_claim COLUMNS trainer_id, day UNIQUE;
+
+        DEFINE FIELD reason ON TABLE ledger TYPE string
+            ASSERT $value IN ['catch', 'purchase', 'daily'];
+    ",
+    down: "
+        REMOVE TABLE daily_claim;
+    ",
+};
+
+#[cfg(test)]
+mod tests {
+    use surrealdb::sql::Thing;
+
+    use crate::{
+        di::{Database, QueryVars, SurrealDB, SurrealEngine, Transaction},
+        models::{DBLedgerEntry, DBModel, DBTrainer, LedgerReason},
+        testing::unique_id,
+    };
+
+    #[tokio::test]
+    async fn keeps_the_daily_ledger_entries_when_reverted() {
+        let mut database = SurrealDB::new(SurrealEngine::Memory, &unique_id(), &unique_id());
+        database.connect().await.unwrap();
+        database.run_migrations().await.unwrap();
+
+        let trainer = DBTrainer::new("1", "1");
+        let entry = DBLedgerEntry::new(&trainer.id, 50, LedgerReason::Daily, "Daily reward");
+        let mut transaction = Transaction::new();
+        trainer.create_in_transaction(&mut transaction).unwrap();
+        entry.apply_in_transaction(&mut transaction).unwrap();
+        database.commit(transaction).await.unwrap();
+
+        database.rollback_migrations(12).await.unwrap();
+        let entries: Vec<Thing> = database
+            .query(
+                "SELECT VALUE id FROM ledger WHERE reason = 'daily'",
+                QueryVars::new(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(entries, vec![entry.id.clone()]);
+
+        // The entries must still be valid when they're written again.
+        database
+            .query::<Thing>(
+                "UPDATE type::thing('ledger', $id) SET details = 'Daily reward #1' RETURN NONE",
+                QueryVars::from([(String::from("id"), entry.id.id.to_string().into())]),
+            )
+            .await
+            .unwrap();
+        database.run_migrations().await.unwrap();
+    }
+}
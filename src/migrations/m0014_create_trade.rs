@@ -0,0 +1,24 @@
+// Libs
+use super::Migration;
+
+// Migration
+pub const MIGRATION: Migration = Migration {
+    version: 14,
+    name: "create_trade",
+    up: "
+        DEFINE TABLE trade SCHEMAFULL;
+        DEFINE FIELD discord_guild_id ON TABLE trade TYPE string;
+        DEFINE FIELD initiator_id ON TABLE trade TYPE record;
+        DEFINE FIELD partner_id ON TABLE trade TYPE record;
+        DEFINE FIELD initiator_poke_ids ON TABLE trade TYPE array<record>;
+        DEFINE FIELD partner_poke_ids ON TABLE trade TYPE array<record>;
+        DEFINE FIELD status ON TABLE trade TYPE string
+            ASSERT $value IN ['completed', 'cancelled', 'expired', 'failed'];
+        DEFINE FIELD created_at ON TABLE trade TYPE datetime;
+        DEFINE FIELD updated_at ON TABLE trade TYPE datetime;
+        DEFINE INDEX trade_discord_guild_id ON TABLE trade COLUMNS discord_guild_id;
+    ",
+    down: "
+        REMOVE TABLE trade;
+    ",
+};
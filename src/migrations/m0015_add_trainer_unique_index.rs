@@ -0,0 +1,193 @@
+// Libs
+use super::Migration;
+
+// Migration
+/**
+The duplicated trainers, created by concurrent interactions, are merged into the oldest one before
+the index is defined. Their daily claims are re-keyed to the kept trainer, so the merged trainer
+can't claim the same day again and keeps their streak. When both trainers claimed the same day,
+the longest streak is kept.
+*/
+pub const MIGRATION: Migration = Migration {
+    version: 15,
+    name: "add_trainer_unique_index",
+    up: "
+        FOR $trainer IN (SELECT * FROM trainer) {
+            LET $keeper = (
+                SELECT id, created_at FROM trainer
+                WHERE discord_id = $trainer.discord_id AND discord_guild_id = $trainer.discord_guild_id
+                ORDER BY created_at, id LIMIT 1
+            )[0].id;
+            IF $keeper != $trainer.id {
+                UPDATE pokemon SET trainer_id = $keeper WHERE trainer_id = $trainer.id;
+                UPDATE ledger SET trainer_id = $keeper WHERE trainer_id = $trainer.id;
+                UPDATE trade SET initiator_id = $keeper WHERE initiator_id = $trainer.id;
+                UPDATE trade SET partner_id = $keeper WHERE partner_id = $trainer.id;
+                FOR $item IN (SELECT * FROM inventory WHERE trainer_id = $trainer.id) {
+                    LET $kept_item = (
+                        SELECT id FROM inventory WHERE trainer_id = $keeper AND ball = $item.ball
+                    )[0].id;
+                    IF $kept_item != NONE {
+                        UPDATE $kept_item SET quantity += $item.quantity;
+                        DELETE $item.id;
+                    } ELSE {
+                        UPDATE $item.id SET trainer_id = $keeper;
+                    };
+                };
+                FOR $claim IN (SELECT * FROM daily_claim WHERE trainer_id = $trainer.id) {
+                    LET $kept_claim = type::thing('daily_claim', string::concat(
+                        string::slice(<string> $keeper, string::len('trainer:')),
+                        '_',
+                        <string> $claim.day
+                    ));
+                    IF (SELECT id FROM $kept_claim)[0] != NONE {
+                        UPDATE $kept_claim SET streak = math::max([streak, $claim.streak]);
+                    } ELSE {
+                        CREATE $kept_claim CONTENT {
+                            trainer_id: $keeper,
+                            day: $claim.day,
+                            streak: $claim.streak,
+                            reward: $claim.reward,
+                            poke_balls: $claim.poke_balls,
+                            created_at: $claim.created_at,
+                            updated_at: $claim.updated_at
+                        };
+                    };
+                    DELETE $claim.id;
+                };
+                UPDATE $keeper SET balance += $trainer.balance;
+                DELETE $trainer.id;
+            };
+        };
+
+        DEFINE INDEX trainer_discord_id_guild_id ON TABLE trainer
+            COLUMNS discord_id, discord_guild_id UNIQUE;
+    ",
+    down: "
+        REMOVE INDEX trainer_discord_id_guild_id ON TABLE trainer;
+    ",
+};
+
+#[cfg(test)]
+mod tests {
+    use serde::Deserialize;
+    use surrealdb::sql::Thing;
+
+    use crate::{
+        di::{Database, QueryVars, SurrealDB, SurrealEngine, Transaction},
+        models::{BallType, DBDailyClaim, DBInventoryItem, DBModel, DBPoke, DBTrainer},
+        testing::unique_id,
+    };
+
+    #[derive(Deserialize)]
+    struct Item {
+        trainer_id: Thing,
+        ball: BallType,
+        quantity: u64,
+    }
+
+    /**
+    A method to run a query on the test database.
+    */
+    async fn query<T>(database: &SurrealDB, query: &str, trainer_id: &Thing) -> Vec<T>
+    where
+        T: serde::de::DeserializeOwned + Send + Sync + Unpin,
+    {
+        let vars = QueryVars::from([(String::from("trainer_id"), trainer_id.clone().into())]);
+        database.query(query, vars).await.unwrap()
+    }
+
+    #[tokio::test]
+    async fn merges_the_duplicated_trainers() {
+        let mut database = SurrealDB::new(SurrealEngine::Memory, &unique_id(), &unique_id());
+        database.connect().await.unwrap();
+        database.run_migrations().await.unwrap();
+        database.rollback_migrations(14).await.unwrap();
+
+        // Create a trainer twice, as concurrent interactions did before the index.
+        let mut keeper = DBTrainer::new("1", "1");
+        keeper.balance = 10;
+        let mut duplicate = DBTrainer::new("1", "1");
+        duplicate.balance = 5;
+        let poke = DBPoke::new(&duplicate.id, &25, false);
+        let mut transaction = Transaction::new();
+        keeper.create_in_transaction(&mut transaction).unwrap();
+        duplicate.create_in_transaction(&mut transaction).unwrap();
+        poke.create_in_transaction(&mut transaction).unwrap();
+        DBInventoryItem::new(&keeper.id, BallType::Poke, 3)
+            .create_in_transaction(&mut transaction)
+            .unwrap();
+        DBInventoryItem::new(&duplicate.id, BallType::Poke, 2)
+            .create_in_transaction(&mut transaction)
+            .unwrap();
+        DBInventoryItem::new(&duplicate.id, BallType::Great, 1)
+            .create_in_transaction(&mut transaction)
+            .unwrap();
+        DBDailyClaim::new(&duplicate.id, 1, 1, 50, 5)
+            .create_in_transaction(&mut transaction)
+            .unwrap();
+        DBDailyClaim::new(&keeper.id, 2, 3, 50, 5)
+            .create_in_transaction(&mut transaction)
+            .unwrap();
+        DBDailyClaim::new(&duplicate.id, 2, 4, 50, 5)
+            .create_in_transaction(&mut transaction)
+            .unwrap();
+        database.commit(transaction).await.unwrap();
+
+        database.run_migrations().await.unwrap();
+
+        let trainers: Vec<DBTrainer> = query(
+            &database,
+            "SELECT * FROM trainer WHERE discord_id = '1'",
+            &keeper.id,
+        )
+        .await;
+        assert_eq!(trainers.len(), 1);
+        assert_eq!(trainers[0].id, keeper.id);
+        assert_eq!(trainers[0].balance, 15);
+
+        let pokes: Vec<DBPoke> = query(
+            &database,
+            "SELECT * FROM pokemon WHERE trainer_id = $trainer_id",
+            &keeper.id,
+        )
+        .await;
+        assert_eq!(pokes.len(), 1);
+        assert_eq!(pokes[0].id, poke.id);
+
+        let mut items: Vec<Item> = query(&database, "SELECT * FROM inventory", &keeper.id).await;
+        items.sort_by_key(|item| item.quantity);
+        assert!(items.iter().all(|item| item.trainer_id == keeper.id));
+        let quantities: Vec<(BallType, u64)> = items
+            .iter()
+            .map(|item| (item.ball, item.quantity))
+            .collect();
+        assert_eq!(quantities, vec![(BallType::Great, 1), (BallType::Poke, 5)]);
+
+        let claims: Vec<DBDailyClaim> = query(
+            &database,
+            "SELECT * FROM daily_claim ORDER BY day",
+            &keeper.id,
+        )
+        .await;
+        assert!(claims.iter().all(|claim| claim.trainer_id == keeper.id));
+        let claims: Vec<(Thing, u32)> = claims
+            .into_iter()
+            .map(|claim| (claim.id, claim.streak))
+            .collect();
+        assert_eq!(
+            claims,
+            vec![
+                (DBDailyClaim::new(&keeper.id, 1, 1, 50, 5).id, 1),
+                (DBDailyClaim::new(&keeper.id, 2, 4, 50, 5).id, 4),
+            ]
+        );
+
+        // The trainer can't be duplicated anymore.
+        let mut transaction = Transaction::new();
+        DBTrainer::new("1", "1")
+            .create_in_transaction(&mut transaction)
+            .unwrap();
+        assert!(database.commit(transaction).await.is_err());
+    }
+}
@@ -0,0 +1,113 @@
+// Libs
+mod m0001_create_trainer_and_pokemon;
+mod m0002_create_guild_settings;
+mod m0003_add_guild_spawn_channels;
+mod m0004_add_guild_follow_up_msg;
+mod m0005_create_active_spawn;
+mod m0006_create_spawn_event;
+mod m0007_add_guild_spawn_limits;
+mod m0008_add_guild_msg_filters;
+mod m0009_add_guild_spawn_model;
+mod m0010_add_guild_spawn_generations;
+mod m0011_create_inventory;
+mod m0012_create_ledger;
+mod m0013_create_daily_claim;
+mod m0014_create_trade;
+mod m0015_add_trainer_unique_index;
+
+// Migration
+/**
+A struct to represent a versioned migration of the database's schema.
+
+The `up` statements apply the migration and the `down` statements revert it.
+Both are written in SurrealQL.
+*/
+pub struct Migration {
+    pub version: u32,
+    pub name: &'static str,
+    pub up: &'static str,
+    pub down: &'static str,
+}
+
+// Data
+/**
+All the migrations known by the bot. It must be sorted by the migration's version.
+*/
+pub const MIGRATIONS: &[Migration] = &[
+    m0001_create_trainer_and_pokemon::MIGRATION,
+    m0002_create_guild_settings::MIGRATION,
+    m0003_add_guild_spawn_channels::MIGRATION,
+    m0004_add_guild_follow_up_msg::MIGRATION,
+    m0005_create_active_spawn::MIGRATION,
+    m0006_create_spawn_event::MIGRATION,
+    m0007_add_guild_spawn_limits::MIGRATION,
+    m0008_add_guild_msg_filters::MIGRATION,
+    m0009_add_guild_spawn_model::MIGRATION,
+    m0010_add_guild_spawn_generations::MIGRATION,
+    m0011_create_inventory::MIGRATION,
+    m0012_create_ledger::MIGRATION,
+    m0013_create_daily_claim::MIGRATION,
+    m0014_create_trade::MIGRATION,
+    m0015_add_trainer_unique_index::MIGRATION,
+];
+
+// Functions
+/**
+A method to get the latest schema version known by the bot.
+*/
+pub fn latest_version() -> u32 {
+    MIGRATIONS.last().map_or(0, |migration| migration.version)
+}
+
+/**
+A method to get the migrations to revert to go back to a version, in the order they must be reverted.
+
+The newest migration is reverted first, so each migration is reverted on the schema it was applied to.
+
+## Parameters:
+- `applied_versions`: The versions of the applied migrations.
+- `version`: The version to go back to.
+*/
+pub fn get_reverted_migrations(applied_versions: &[u32], version: u32) -> Vec<&'static Migration> {
+    MIGRATIONS
+        .iter()
+        .rev()
+        .filter(|migration| {
+            migration.version > version && applied_versions.contains(&migration.version)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn migrations_are_sorted_by_version() {
+        assert!(MIGRATIONS
+            .windows(2)
+            .all(|migrations| migrations[0].version < migrations[1].version));
+    }
+
+    #[test]
+    fn get_reverted_migrations_reverts_the_newest_first() {
+        let applied_versions: Vec<u32> = MIGRATIONS.iter().map(|m| m.version).collect();
+        let versions: Vec<u32> = get_reverted_migrations(&applied_versions, 12)
+            .iter()
+            .map(|migration| migration.version)
+            .collect();
+        let expected: Vec<u32> = (13..=latest_version()).rev().collect();
+
+        assert_eq!(versions, expected);
+    }
+
+    #[test]
+    fn get_reverted_migrations_skips_the_migrations_not_applied() {
+        let versions: Vec<u32> = get_reverted_migrations(&[1, 2, 5], 1)
+            .iter()
+            .map(|migration| migration.version)
+            .collect();
+
+        assert_eq!(versions, vec![5, 2]);
+    }
+}
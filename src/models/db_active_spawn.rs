@@ -0,0 +1,118 @@
+// Libs
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+use surrealdb::sql::{Datetime, Id, Thing};
+use tracing::info;
+
+use super::{DBModel, DBMutableModel};
+use crate::{di::QueryVars, errors::DatabaseResult, services::DATABASE_SERVICE};
+
+// DBActiveSpawn
+/**
+A struct to represent a spawned pokemon that wasn't caught and didn't flee yet.
+
+The record's ID is the Discord ID of the spawn's message. It's used to recover the spawn if the bot restarts.
+*/
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct DBActiveSpawn {
+    pub id: Thing,
+    pub discord_guild_id: String,
+    pub discord_channel_id: String,
+    pub discord_message_id: String,
+    pub spawner_id: Option<String>,
+    pub poke_id: u16,
+    pub is_shiny: bool,
+    pub expires_at: Datetime,
+    pub created_at: Datetime,
+    pub updated_at: Datetime,
+}
+
+impl DBActiveSpawn {
+    /**
+    A method to create a new instance of DBActiveSpawn.
+
+    ## Parameters:
+    - `discord_guild_id`: The Discord ID of the spawn's guild.
+    - `discord_channel_id`: The Discord ID of the spawn's channel.
+    - `discord_message_id`: The Discord ID of the spawn's message.
+    - `spawner_id`: The Discord ID of the user who triggered the spawn, if any.
+    - `poke_id`: The ID of the pokemon.
+    - `is_shiny`: A boolean to ensure if the pokemon is shiny or not.
+    - `flee_time`: The time until the pokemon flees.
+    */
+    pub fn new(
+        discord_guild_id: String,
+        discord_channel_id: String,
+        discord_message_id: String,
+        spawner_id: Option<String>,
+        poke_id: u16,
+        is_shiny: bool,
+        flee_time: Duration,
+    ) -> Self {
+        let now = Datetime::default();
+        Self {
+            id: Thing {
+                tb: String::from("active_spawn"),
+                id: Id::from(discord_message_id.clone()),
+            },
+            discord_guild_id,
+            discord_channel_id,
+            discord_message_id,
+            spawner_id,
+            poke_id,
+            is_shiny,
+            expires_at: Datetime(now.0 + flee_time),
+            created_at: now.clone(),
+            updated_at: now,
+        }
+    }
+
+    /**
+    A method to get all the active spawns.
+    */
+    pub async fn get_all() -> DatabaseResult<Vec<Self>> {
+        info!("Getting all the active spawns...");
+        let db_svc = DATABASE_SERVICE.clone();
+        let query = "SELECT * FROM active_spawn ORDER BY expires_at";
+        db_svc.run_query(query, QueryVars::new()).await
+    }
+
+    /**
+    A method to get the remaining time until the pokemon flees.
+
+    ## Returns:
+    - `None` if the pokemon already fled.
+    */
+    pub fn get_remaining_time(&self) -> Option<Duration> {
+        (self.expires_at.0 - Datetime::default().0)
+            .to_std()
+            .ok()
+            .filter(|remaining| !remaining.is_zero())
+    }
+}
+
+impl DBModel for DBActiveSpawn {
+    const TABLE: &'static str = "active_spawn";
+
+    fn get_id(&self) -> &Thing {
+        &self.id
+    }
+
+    fn set_id(&mut self, id: Thing) {
+        self.id = id;
+    }
+
+    fn get_key(&self) -> String {
+        self.discord_message_id.clone()
+    }
+}
+
+impl DBMutableModel for DBActiveSpawn {
+    fn get_updated_at(&self) -> &Datetime {
+        &self.updated_at
+    }
+
+    fn set_updated_at(&mut self, updated_at: Datetime) {
+        self.updated_at = updated_at;
+    }
+}
@@ -0,0 +1,108 @@
+// Libs
+use serde::{Deserialize, Serialize};
+use surrealdb::sql::{Datetime, Id, Thing};
+use tracing::info;
+
+use super::DBModel;
+use crate::{di::QueryVars, errors::DatabaseResult, services::DATABASE_SERVICE};
+
+// Constants
+/**
+The amount of seconds in a day.
+*/
+const SECS_PER_DAY: i64 = 24 * 60 * 60;
+
+// DBDailyClaim
+/**
+A struct to represent a daily reward claimed by a trainer in the database.
+
+The ID of a claim is made of the trainer's ID and the day, so a trainer can't claim twice on the
+same day, even with concurrent interactions.
+*/
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct DBDailyClaim {
+    pub id: Thing,
+    pub trainer_id: Thing,
+    pub day: i64,
+    pub streak: u32,
+    pub reward: u64,
+    pub poke_balls: u64,
+    pub created_at: Datetime,
+    pub updated_at: Datetime,
+}
+
+impl DBDailyClaim {
+    /**
+    A method to create a new instance of DBDailyClaim.
+
+    ## Parameters:
+    - `trainer_id`: The ID of the trainer who claimed the reward.
+    - `day`: The UTC day of the claim, as returned by `get_day`.
+    - `streak`: The amount of consecutive days the trainer claimed the reward, including this one.
+    - `reward`: The coins given to the trainer.
+    - `poke_balls`: The Poké Balls given to the trainer.
+    */
+    pub fn new(trainer_id: &Thing, day: i64, streak: u32, reward: u64, poke_balls: u64) -> Self {
+        Self {
+            id: Thing {
+                tb: String::from("daily_claim"),
+                id: Id::String(format!("{}_{}", trainer_id.id, day)),
+            },
+            trainer_id: trainer_id.clone(),
+            day,
+            streak,
+            reward,
+            poke_balls,
+            created_at: Datetime::default(),
+            updated_at: Datetime::default(),
+        }
+    }
+
+    /**
+    A method to get the UTC day of a datetime, as the amount of days since the Unix epoch.
+
+    ## Parameters:
+    - `datetime`: The datetime.
+    */
+    pub fn get_day(datetime: &Datetime) -> i64 {
+        datetime.0.timestamp().div_euclid(SECS_PER_DAY)
+    }
+
+    /**
+    A method to get when a UTC day starts, as a Unix timestamp.
+
+    ## Parameters:
+    - `day`: The UTC day, as returned by `get_day`.
+    */
+    pub fn get_day_start(day: i64) -> i64 {
+        day * SECS_PER_DAY
+    }
+
+    /**
+    A method to get the most recent daily reward claimed by a trainer.
+
+    ## Parameters:
+    - `trainer_id`: The ID of the trainer.
+    */
+    pub async fn get_latest_by_trainer_id(trainer_id: &Thing) -> DatabaseResult<Option<Self>> {
+        info!("Getting the latest daily claim of the {}...", trainer_id);
+        let db_svc = DATABASE_SERVICE.clone();
+        let query =
+            "SELECT * FROM daily_claim WHERE trainer_id = $trainer_id ORDER BY day DESC LIMIT 1";
+        let vars = QueryVars::from([(String::from("trainer_id"), trainer_id.clone().into())]);
+        let mut claims: Vec<Self> = db_svc.run_query(query, vars).await?;
+        Ok(claims.pop())
+    }
+}
+
+impl DBModel for DBDailyClaim {
+    const TABLE: &'static str = "daily_claim";
+
+    fn get_id(&self) -> &Thing {
+        &self.id
+    }
+
+    fn set_id(&mut self, id: Thing) {
+        self.id = id;
+    }
+}
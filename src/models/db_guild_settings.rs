@@ -0,0 +1,421 @@
+// Libs
+use serde::{Deserialize, Serialize};
+use serenity::utils::parse_emoji;
+use surrealdb::sql::{Datetime, Id, Thing};
+use tracing::{info, warn};
+
+use super::{DBModel, DBMutableModel};
+use crate::{
+    di::Transaction,
+    errors::DatabaseResult,
+    services::DATABASE_SERVICE,
+    utils::{
+        config::{parse_positive, parse_value},
+        spawn_model::SpawnModel,
+    },
+};
+
+// Constants
+/**
+The maximum window of the message filters, in seconds. The recent messages of a channel are kept
+for the largest window, so it's limited to a day.
+*/
+const MAX_MSG_WINDOW_SECS: u64 = 24 * 60 * 60;
+
+// Data
+/**
+The settings that can be overridden per guild.
+*/
+pub const GUILD_SETTING_KEYS: &[&str] = &[
+    "poke_spawn_rate",
+    "poke_spawn_model",
+    "poke_spawns_per_hour",
+    "poke_shiny_rate",
+    "poke_flee_time_secs",
+    "pokeball_emoji",
+    "poke_follow_up_msg",
+    "poke_spawn_cooldown_secs",
+    "poke_max_spawns_per_channel",
+    "poke_max_spawns_per_guild",
+    "msg_min_length",
+    "msg_duplicate_window_secs",
+    "msg_user_cooldown_secs",
+    "msg_min_distinct_authors",
+    "msg_distinct_authors_window_secs",
+];
+
+// DBGuildSettings
+/**
+A struct to represent the settings of a guild in the database.
+
+The record's ID is the guild's Discord ID. A `None` setting falls back to the `bot` configuration.
+
+The spawn channels are only used by the `allow` and `deny` spawn channel modes. Without a mode,
+every channel can spawn pokémons. Without spawn generations, the pokémons of every generation can spawn.
+*/
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct DBGuildSettings {
+    pub id: Thing,
+    pub discord_guild_id: String,
+    pub poke_spawn_rate: Option<u64>,
+    pub poke_spawn_model: Option<String>,
+    pub poke_spawns_per_hour: Option<u64>,
+    pub poke_shiny_rate: Option<u64>,
+    pub poke_flee_time_secs: Option<u64>,
+    pub pokeball_emoji: Option<String>,
+    pub poke_follow_up_msg: Option<bool>,
+    pub poke_spawn_cooldown_secs: Option<u64>,
+    pub poke_max_spawns_per_channel: Option<u64>,
+    pub poke_max_spawns_per_guild: Option<u64>,
+    pub msg_min_length: Option<u64>,
+    pub msg_duplicate_window_secs: Option<u64>,
+    pub msg_user_cooldown_secs: Option<u64>,
+    pub msg_min_distinct_authors: Option<u64>,
+    pub msg_distinct_authors_window_secs: Option<u64>,
+    pub spawn_channel_mode: Option<String>,
+    #[serde(default)]
+    pub spawn_channel_ids: Vec<String>,
+    pub wild_area_channel_id: Option<String>,
+    #[serde(default)]
+    pub spawn_generations: Vec<u8>,
+    pub created_at: Datetime,
+    pub updated_at: Datetime,
+}
+
+impl DBGuildSettings {
+    /**
+    A method to create a new instance of DBGuildSettings without any override.
+
+    ## Parameters:
+    - `discord_guild_id`: The Discord ID of the guild.
+    */
+    pub fn new<T>(discord_guild_id: T) -> Self
+    where
+        T: Into<String>,
+    {
+        let discord_guild_id: String = discord_guild_id.into();
+        Self {
+            id: Thing {
+                tb: String::from("guild_settings"),
+                id: Id::from(discord_guild_id.clone()),
+            },
+            discord_guild_id,
+            poke_spawn_rate: None,
+            poke_spawn_model: None,
+            poke_spawns_per_hour: None,
+            poke_shiny_rate: None,
+            poke_flee_time_secs: None,
+            pokeball_emoji: None,
+            poke_follow_up_msg: None,
+            poke_spawn_cooldown_secs: None,
+            poke_max_spawns_per_channel: None,
+            poke_max_spawns_per_guild: None,
+            msg_min_length: None,
+            msg_duplicate_window_secs: None,
+            msg_user_cooldown_secs: None,
+            msg_min_distinct_authors: None,
+            msg_distinct_authors_window_secs: None,
+            spawn_channel_mode: None,
+            spawn_channel_ids: Vec::new(),
+            wild_area_channel_id: None,
+            spawn_generations: Vec::new(),
+            created_at: Datetime::default(),
+            updated_at: Datetime::default(),
+        }
+    }
+
+    /**
+    A method to get the settings of a guild by its Discord ID.
+
+    ## Parameters:
+    - `discord_guild_id`: The Discord ID of the guild.
+    */
+    pub async fn get_by_guild_id(discord_guild_id: &str) -> DatabaseResult<Option<Self>> {
+        info!("Getting the guild settings by Discord ID...");
+        let db_svc = DATABASE_SERVICE.clone();
+        db_svc.get_record("guild_settings", discord_guild_id).await
+    }
+
+    /**
+    A method to save the settings, creating them if they weren't in the database.
+
+    The settings are only saved if they weren't changed since they were read, so a concurrent
+    command's changes are never overwritten.
+
+    ## Parameters:
+    - `is_new`: If the settings weren't in the database when they were read.
+
+    ## Returns:
+    - `false` if the settings were changed concurrently and nothing was saved.
+    */
+    pub async fn save(&mut self, is_new: bool) -> DatabaseResult<bool> {
+        info!("Saving the guild settings#{}...", self.id);
+        let read_updated_at = self.updated_at.clone();
+        let result = match is_new {
+            true => {
+                // The creation fails if the settings were created concurrently.
+                let mut transaction = Transaction::new();
+                self.create_in_transaction(&mut transaction)?;
+                let db_svc = DATABASE_SERVICE.clone();
+                db_svc.commit_transaction(transaction).await
+            }
+            false => self.update().await,
+        };
+
+        if let Err(e) = result {
+            let latest = Self::get_by_guild_id(&self.discord_guild_id).await?;
+            let is_changed = match latest {
+                Some(latest) => is_new || latest.updated_at != read_updated_at,
+                None => !is_new,
+            };
+            if !is_changed {
+                return Err(e);
+            }
+
+            warn!("The guild settings were changed concurrently. {}", e);
+            return Ok(false);
+        }
+
+        info!("Guild settings#{} saved successfully.", self.id);
+        Ok(true)
+    }
+
+    /**
+    A method to get the overridden value of a setting.
+
+    ## Parameters:
+    - `key`: The setting's key. It must be one of `GUILD_SETTING_KEYS`.
+    */
+    pub fn get_value(&self, key: &str) -> Option<String> {
+        match key {
+            "poke_spawn_rate" => self.poke_spawn_rate.map(|value| value.to_string()),
+            "poke_spawn_model" => self.poke_spawn_model.clone(),
+            "poke_spawns_per_hour" => self.poke_spawns_per_hour.map(|value| value.to_string()),
+            "poke_shiny_rate" => self.poke_shiny_rate.map(|value| value.to_string()),
+            "poke_flee_time_secs" => self.poke_flee_time_secs.map(|value| value.to_string()),
+            "pokeball_emoji" => self.pokeball_emoji.clone(),
+            "poke_follow_up_msg" => self.poke_follow_up_msg.map(|value| value.to_string()),
+            "poke_spawn_cooldown_secs" => self.poke_spawn_cooldown_secs.map(|v| v.to_string()),
+            "poke_max_spawns_per_channel" => {
+                self.poke_max_spawns_per_channel.map(|v| v.to_string())
+            }
+            "poke_max_spawns_per_guild" => self.poke_max_spawns_per_guild.map(|v| v.to_string()),
+            "msg_min_length" => self.msg_min_length.map(|v| v.to_string()),
+            "msg_duplicate_window_secs" => self.msg_duplicate_window_secs.map(|v| v.to_string()),
+            "msg_user_cooldown_secs" => self.msg_user_cooldown_secs.map(|v| v.to_string()),
+            "msg_min_distinct_authors" => self.msg_min_distinct_authors.map(|v| v.to_string()),
+            "msg_distinct_authors_window_secs" => {
+                self.msg_distinct_authors_window_secs.map(|v| v.to_string())
+            }
+            _ => None,
+        }
+    }
+
+    /**
+    A method to validate and override the value of a setting.
+
+    ## Parameters:
+    - `key`: The setting's key. It must be one of `GUILD_SETTING_KEYS`.
+    - `value`: The raw value of the setting.
+
+    ## Returns:
+    - An `Err` with the reason if the key or the value is invalid.
+    */
+    pub fn set_value(&mut self, key: &str, value: &str) -> Result<(), String> {
+        match key {
+            "poke_spawn_rate" => self.poke_spawn_rate = Some(parse_positive(value)?),
+            "poke_spawn_model" => {
+                let model = SpawnModel::parse(value)?;
+                self.poke_spawn_model = Some(model.as_str().to_string());
+            }
+            "poke_spawns_per_hour" => self.poke_spawns_per_hour = Some(parse_positive(value)?),
+            "poke_shiny_rate" => self.poke_shiny_rate = Some(parse_positive(value)?),
+            "poke_flee_time_secs" => self.poke_flee_time_secs = Some(parse_positive(value)?),
+            "pokeball_emoji" => {
+                let emoji = parse_emoji(value)
+                    .ok_or_else(|| String::from("must be a valid custom emoji"))?;
+                self.pokeball_emoji = Some(emoji.to_string());
+            }
+            "poke_follow_up_msg" => self.poke_follow_up_msg = Some(parse_value(value)?),
+            "poke_spawn_cooldown_secs" => self.poke_spawn_cooldown_secs = Some(parse_value(value)?),
+            "poke_max_spawns_per_channel" => {
+                self.poke_max_spawns_per_channel = Some(parse_value(value)?)
+            }
+            "poke_max_spawns_per_guild" => {
+                self.poke_max_spawns_per_guild = Some(parse_value(value)?)
+            }
+            "msg_min_length" => self.msg_min_length = Some(parse_value(value)?),
+            "msg_duplicate_window_secs" => {
+                self.msg_duplicate_window_secs = Some(parse_window(value)?)
+            }
+            "msg_user_cooldown_secs" => self.msg_user_cooldown_secs = Some(parse_value(value)?),
+            "msg_min_distinct_authors" => self.msg_min_distinct_authors = Some(parse_value(value)?),
+            "msg_distinct_authors_window_secs" => {
+                self.msg_distinct_authors_window_secs = Some(parse_window(value)?)
+            }
+            _ => return Err(format!("must be one of {:?}", GUILD_SETTING_KEYS)),
+        }
+
+        Ok(())
+    }
+
+    /**
+    A method to remove the override of a setting, falling back to the `bot` configuration.
+
+    ## Parameters:
+    - `key`: The setting's key. It must be one of `GUILD_SETTING_KEYS`.
+    */
+    pub fn reset_value(&mut self, key: &str) {
+        match key {
+            "poke_spawn_rate" => self.poke_spawn_rate = None,
+            "poke_spawn_model" => self.poke_spawn_model = None,
+            "poke_spawns_per_hour" => self.poke_spawns_per_hour = None,
+            "poke_shiny_rate" => self.poke_shiny_rate = None,
+            "poke_flee_time_secs" => self.poke_flee_time_secs = None,
+            "pokeball_emoji" => self.pokeball_emoji = None,
+            "poke_follow_up_msg" => self.poke_follow_up_msg = None,
+            "poke_spawn_cooldown_secs" => self.poke_spawn_cooldown_secs = None,
+            "poke_max_spawns_per_channel" => self.poke_max_spawns_per_channel = None,
+            "poke_max_spawns_per_guild" => self.poke_max_spawns_per_guild = None,
+            "msg_min_length" => self.msg_min_length = None,
+            "msg_duplicate_window_secs" => self.msg_duplicate_window_secs = None,
+            "msg_user_cooldown_secs" => self.msg_user_cooldown_secs = None,
+            "msg_min_distinct_authors" => self.msg_min_distinct_authors = None,
+            "msg_distinct_authors_window_secs" => self.msg_distinct_authors_window_secs = None,
+            _ => {}
+        }
+    }
+
+    /**
+    A method to remove the overrides of every setting in `GUILD_SETTING_KEYS`.
+
+    The spawn channels, the wild area and the spawn generations are kept, as they have their own commands.
+    */
+    pub fn reset_values(&mut self) {
+        for key in GUILD_SETTING_KEYS {
+            self.reset_value(key);
+        }
+    }
+}
+
+impl DBModel for DBGuildSettings {
+    const TABLE: &'static str = "guild_settings";
+
+    fn get_id(&self) -> &Thing {
+        &self.id
+    }
+
+    fn set_id(&mut self, id: Thing) {
+        self.id = id;
+    }
+
+    fn get_key(&self) -> String {
+        self.discord_guild_id.clone()
+    }
+}
+
+impl DBMutableModel for DBGuildSettings {
+    fn get_updated_at(&self) -> &Datetime {
+        &self.updated_at
+    }
+
+    fn set_updated_at(&mut self, updated_at: Datetime) {
+        self.updated_at = updated_at;
+    }
+}
+
+// Functions
+/**
+A method to parse the window of a message filter, in seconds.
+
+## Parameters:
+- `value`: The raw value.
+*/
+fn parse_window(value: &str) -> Result<u64, String> {
+    let window = parse_value(value)?;
+    if window > MAX_MSG_WINDOW_SECS {
+        return Err(format!("must be at most {}", MAX_MSG_WINDOW_SECS));
+    }
+
+    Ok(window)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::{run_test, unique_id};
+
+    #[test]
+    fn save_never_overwrites_a_concurrent_change() {
+        run_test(async {
+            let guild_id = unique_id();
+            let mut settings = DBGuildSettings::new(&guild_id);
+            assert!(settings.save(true).await.unwrap());
+            assert!(!DBGuildSettings::new(&guild_id).save(true).await.unwrap());
+
+            let mut first = DBGuildSettings::get_by_guild_id(&guild_id)
+                .await
+                .unwrap()
+                .unwrap();
+            let mut second = first.clone();
+            first.set_value("poke_spawn_rate", "10").unwrap();
+            assert!(first.save(false).await.unwrap());
+            second.set_value("poke_shiny_rate", "10").unwrap();
+            assert!(!second.save(false).await.unwrap());
+
+            let saved = DBGuildSettings::get_by_guild_id(&guild_id)
+                .await
+                .unwrap()
+                .unwrap();
+            assert_eq!(saved.poke_spawn_rate, Some(10));
+            assert_eq!(saved.poke_shiny_rate, None);
+        })
+    }
+
+    #[test]
+    fn set_value_rejects_the_windows_longer_than_a_day() {
+        let mut settings = DBGuildSettings::new(unique_id());
+        for key in [
+            "msg_duplicate_window_secs",
+            "msg_distinct_authors_window_secs",
+        ] {
+            let max = MAX_MSG_WINDOW_SECS.to_string();
+            settings.set_value(key, &max).unwrap();
+            assert_eq!(settings.get_value(key), Some(max));
+
+            let too_long = (MAX_MSG_WINDOW_SECS + 1).to_string();
+            assert!(settings.set_value(key, &too_long).is_err());
+            assert_eq!(
+                settings.get_value(key),
+                Some(MAX_MSG_WINDOW_SECS.to_string())
+            );
+        }
+    }
+
+    #[test]
+    fn reset_values_keeps_the_spawn_channels_and_generations() {
+        let mut settings = DBGuildSettings::new(unique_id());
+        for key in GUILD_SETTING_KEYS {
+            let value = match *key {
+                "poke_spawn_model" => "activity",
+                "pokeball_emoji" => "<:pokeball:1251387543090626623>",
+                "poke_follow_up_msg" => "true",
+                _ => "10",
+            };
+            settings.set_value(key, value).unwrap();
+        }
+        settings.spawn_channel_mode = Some(String::from("allow"));
+        settings.spawn_channel_ids = vec![String::from("1")];
+        settings.wild_area_channel_id = Some(String::from("2"));
+        settings.spawn_generations = vec![1, 2];
+
+        settings.reset_values();
+        for key in GUILD_SETTING_KEYS {
+            assert_eq!(settings.get_value(key), None, "{} wasn't reset", key);
+        }
+        assert_eq!(settings.spawn_channel_mode.as_deref(), Some("allow"));
+        assert_eq!(settings.spawn_channel_ids, vec![String::from("1")]);
+        assert_eq!(settings.wild_area_channel_id.as_deref(), Some("2"));
+        assert_eq!(settings.spawn_generations, vec![1, 2]);
+    }
+}
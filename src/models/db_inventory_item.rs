@@ -0,0 +1,235 @@
+// Libs
+use serde::{Deserialize, Serialize};
+use surrealdb::sql::{Datetime, Id, Thing};
+use tracing::info;
+
+use super::{DBModel, DBMutableModel};
+use crate::{di::QueryVars, errors::DatabaseResult, services::DATABASE_SERVICE};
+
+// Ball Type
+/**
+An enum to represent the balls a trainer can throw at a wild pokemon.
+*/
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub enum BallType {
+    #[serde(rename = "poke_ball")]
+    Poke,
+    #[serde(rename = "great_ball")]
+    Great,
+    #[serde(rename = "ultra_ball")]
+    Ultra,
+    #[serde(rename = "master_ball")]
+    Master,
+}
+
+impl BallType {
+    /**
+    All the balls, from the weakest to the strongest.
+    */
+    pub const ALL: [Self; 4] = [Self::Poke, Self::Great, Self::Ultra, Self::Master];
+
+    /**
+    A method to get a ball from its ID.
+
+    ## Parameters:
+    - `value`: The ID of the ball, e.g. `great_ball`.
+    */
+    pub fn parse(value: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|ball| ball.as_str() == value)
+    }
+
+    /**
+    A method to get the ID of the ball, as it's stored in the database.
+    */
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Poke => "poke_ball",
+            Self::Great => "great_ball",
+            Self::Ultra => "ultra_ball",
+            Self::Master => "master_ball",
+        }
+    }
+
+    /**
+    A method to get the display name of the ball.
+    */
+    pub fn get_name(self) -> &'static str {
+        match self {
+            Self::Poke => "Poké Ball",
+            Self::Great => "Great Ball",
+            Self::Ultra => "Ultra Ball",
+            Self::Master => "Master Ball",
+        }
+    }
+
+    /**
+    A method to get the multiplier applied to a pokemon's catch chance when the ball is thrown.
+
+    ## Returns:
+    - The multiplier, or `None` if the ball never fails.
+    */
+    pub fn get_catch_modifier(self) -> Option<f64> {
+        match self {
+            Self::Poke => Some(1.0),
+            Self::Great => Some(1.5),
+            Self::Ultra => Some(2.0),
+            Self::Master => None,
+        }
+    }
+}
+
+// DBInventoryItem
+/**
+A struct to represent the amount of a ball owned by a trainer in the database.
+*/
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct DBInventoryItem {
+    pub id: Thing,
+    pub trainer_id: Thing,
+    pub ball: BallType,
+    pub quantity: u64,
+    pub created_at: Datetime,
+    pub updated_at: Datetime,
+}
+
+impl DBInventoryItem {
+    /**
+    A method to create a new instance of DBInventoryItem.
+
+    ## Parameters:
+    - `trainer_id`: The ID of the trainer who owns the balls.
+    - `ball`: The type of the balls.
+    - `quantity`: The amount of balls.
+    */
+    pub fn new(trainer_id: &Thing, ball: BallType, quantity: u64) -> Self {
+        Self {
+            id: Thing {
+                tb: String::from("inventory"),
+                id: Id::ulid(),
+            },
+            trainer_id: trainer_id.clone(),
+            ball,
+            quantity,
+            created_at: Datetime::default(),
+            updated_at: Datetime::default(),
+        }
+    }
+
+    /**
+    A method to get all the items of a trainer's inventory.
+
+    ## Parameters:
+    - `trainer_id`: The ID of the trainer.
+    */
+    pub async fn get_by_trainer_id(trainer_id: &Thing) -> DatabaseResult<Vec<Self>> {
+        info!("Getting the inventory of the {}...", trainer_id);
+        let db_svc = DATABASE_SERVICE.clone();
+        let query = "SELECT * FROM inventory WHERE trainer_id = $trainer_id";
+        let vars = QueryVars::from([(String::from("trainer_id"), trainer_id.clone().into())]);
+        db_svc.run_query(query, vars).await
+    }
+
+    /**
+    A method to take a ball from a trainer's inventory.
+
+    The check and the update are done in a single statement, so concurrent throws can't use the
+    same ball twice.
+
+    ## Parameters:
+    - `trainer_id`: The ID of the trainer.
+    - `ball`: The type of the ball.
+
+    ## Returns:
+    - The amount of balls left, or `None` if the trainer had none.
+    */
+    pub async fn consume(trainer_id: &Thing, ball: BallType) -> DatabaseResult<Option<u64>> {
+        info!("Taking a {} from the {}...", ball.as_str(), trainer_id);
+        let db_svc = DATABASE_SERVICE.clone();
+        let query = "UPDATE inventory SET quantity -= 1, updated_at = time::now() WHERE trainer_id = $trainer_id AND ball = $ball AND quantity > 0";
+        let vars = QueryVars::from([
+            (String::from("trainer_id"), trainer_id.clone().into()),
+            (String::from("ball"), ball.as_str().into()),
+        ]);
+        let mut items: Vec<Self> = db_svc.run_query(query, vars).await?;
+        Ok(items.pop().map(|item| item.quantity))
+    }
+
+    /**
+    A method to give a ball taken with `consume` back to a trainer.
+
+    ## Parameters:
+    - `trainer_id`: The ID of the trainer.
+    - `ball`: The type of the ball.
+    */
+    pub async fn refund(trainer_id: &Thing, ball: BallType) -> DatabaseResult<()> {
+        info!("Giving a {} back to the {}...", ball.as_str(), trainer_id);
+        let db_svc = DATABASE_SERVICE.clone();
+        let query = "UPDATE inventory SET quantity += 1, updated_at = time::now() WHERE trainer_id = $trainer_id AND ball = $ball";
+        let vars = QueryVars::from([
+            (String::from("trainer_id"), trainer_id.clone().into()),
+            (String::from("ball"), ball.as_str().into()),
+        ]);
+        let items: Vec<Self> = db_svc.run_query(query, vars).await?;
+        match items.is_empty() {
+            true => {
+                Err(format!("The {} has no {} to give back.", trainer_id, ball.as_str()).into())
+            }
+            false => Ok(()),
+        }
+    }
+}
+
+impl DBModel for DBInventoryItem {
+    const TABLE: &'static str = "inventory";
+
+    fn get_id(&self) -> &Thing {
+        &self.id
+    }
+
+    fn set_id(&mut self, id: Thing) {
+        self.id = id;
+    }
+}
+
+impl DBMutableModel for DBInventoryItem {
+    fn get_updated_at(&self) -> &Datetime {
+        &self.updated_at
+    }
+
+    fn set_updated_at(&mut self, updated_at: Datetime) {
+        self.updated_at = updated_at;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::{run_test, unique_id};
+
+    #[test]
+    fn refund_gives_back_a_consumed_ball() {
+        run_test(async {
+            let trainer_id = Thing::from(("trainer", unique_id().as_str()));
+            DBInventoryItem::new(&trainer_id, BallType::Great, 1)
+                .create()
+                .await
+                .unwrap();
+
+            let consumed = DBInventoryItem::consume(&trainer_id, BallType::Great).await;
+            assert_eq!(consumed.unwrap(), Some(0));
+            let consumed = DBInventoryItem::consume(&trainer_id, BallType::Great).await;
+            assert_eq!(consumed.unwrap(), None);
+
+            DBInventoryItem::refund(&trainer_id, BallType::Great)
+                .await
+                .unwrap();
+            let items = DBInventoryItem::get_by_trainer_id(&trainer_id)
+                .await
+                .unwrap();
+            assert_eq!(items[0].quantity, 1);
+            assert!(DBInventoryItem::refund(&trainer_id, BallType::Ultra)
+                .await
+                .is_err());
+        });
+    }
+}
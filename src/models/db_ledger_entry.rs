@@ -0,0 +1,211 @@
+// Libs
+use serde::{Deserialize, Serialize};
+use surrealdb::sql::{Datetime, Id, Thing};
+
+use super::DBModel;
+use crate::{di::Transaction, errors::DatabaseResult};
+
+// Ledger Reason
+/**
+An enum to represent why a trainer's balance changed.
+*/
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum LedgerReason {
+    /// The trainer caught a pokemon.
+    Catch,
+    /// The trainer bought something in the shop.
+    Purchase,
+    /// The trainer claimed their daily reward.
+    Daily,
+}
+
+// DBLedgerEntry
+/**
+A struct to represent a change of a trainer's balance in the database.
+
+Every change of a balance is recorded, so the balances can be audited and the changes reverted.
+*/
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct DBLedgerEntry {
+    pub id: Thing,
+    pub trainer_id: Thing,
+    pub amount: i64,
+    pub reason: LedgerReason,
+    pub details: String,
+    pub created_at: Datetime,
+    pub updated_at: Datetime,
+}
+
+impl DBLedgerEntry {
+    /**
+    A method to create a new instance of DBLedgerEntry.
+
+    ## Parameters:
+    - `trainer_id`: The ID of the trainer whose balance changed.
+    - `amount`: The amount added to the balance. It's negative when the trainer spends.
+    - `reason`: Why the balance changed.
+    - `details`: A description of the change, e.g. the pokemon caught or the item bought.
+    */
+    pub fn new(
+        trainer_id: &Thing,
+        amount: i64,
+        reason: LedgerReason,
+        details: impl Into<String>,
+    ) -> Self {
+        Self {
+            id: Thing {
+                tb: String::from("ledger"),
+                id: Id::ulid(),
+            },
+            trainer_id: trainer_id.clone(),
+            amount,
+            reason,
+            details: details.into(),
+            created_at: Datetime::default(),
+            updated_at: Datetime::default(),
+        }
+    }
+
+    /**
+    A method to add the entry and the change of the trainer's balance to a transaction.
+
+    The balance can't go below 0, so the transaction fails if the trainer can't afford it.
+
+    ## Parameters:
+    - `transaction`: The transaction to add the change to.
+    */
+    pub fn apply_in_transaction(&self, transaction: &mut Transaction) -> DatabaseResult<()> {
+        let trainer_id = self.trainer_id.id.to_string();
+        transaction.increment("trainer", &trainer_id, "balance", self.amount);
+        self.create_in_transaction(transaction)
+    }
+}
+
+impl DBModel for DBLedgerEntry {
+    const TABLE: &'static str = "ledger";
+
+    fn get_id(&self) -> &Thing {
+        &self.id
+    }
+
+    fn set_id(&mut self, id: Thing) {
+        self.id = id;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        di::QueryVars,
+        models::{BallType, DBInventoryItem, DBMutableModel, DBTrainer},
+        services::DATABASE_SERVICE,
+        testing::{run_test, unique_id},
+    };
+
+    /**
+    A method to get the ledger entries of a trainer.
+
+    ## Parameters:
+    - `trainer_id`: The ID of the trainer.
+    */
+    async fn get_trainer_entries(trainer_id: &Thing) -> Vec<DBLedgerEntry> {
+        let query = "SELECT * FROM ledger WHERE trainer_id = $trainer_id";
+        let vars = QueryVars::from([(String::from("trainer_id"), trainer_id.clone().into())]);
+        DATABASE_SERVICE
+            .clone()
+            .run_query(query, vars)
+            .await
+            .unwrap()
+    }
+
+    /**
+    A method to get a new trainer with a balance, and an empty inventory item of the trainer.
+
+    ## Parameters:
+    - `balance`: The balance of the trainer.
+    */
+    async fn get_trainer_with_item(balance: u64) -> (DBTrainer, DBInventoryItem) {
+        let mut trainer = DBTrainer::find_by_discord_id(&unique_id(), &unique_id())
+            .await
+            .unwrap();
+        trainer.balance = balance;
+        trainer.update().await.unwrap();
+        let mut item = DBInventoryItem::new(&trainer.id, BallType::Poke, 0);
+        item.create().await.unwrap();
+        (trainer, item)
+    }
+
+    /**
+    A method to buy some balls, like the shop does.
+
+    ## Parameters:
+    - `trainer`: The trainer who buys the balls.
+    - `item`: The inventory item of the balls.
+    - `cost`: The cost of the balls.
+    */
+    async fn buy(trainer: &DBTrainer, item: &DBInventoryItem, cost: i64) -> DatabaseResult<()> {
+        let mut transaction = Transaction::new();
+        DBLedgerEntry::new(
+            &trainer.id,
+            -cost,
+            LedgerReason::Purchase,
+            "Bought 1x Poké Ball",
+        )
+        .apply_in_transaction(&mut transaction)?;
+        transaction.increment("inventory", &item.id.id.to_string(), "quantity", 1);
+        DATABASE_SERVICE
+            .clone()
+            .commit_transaction(transaction)
+            .await
+    }
+
+    /**
+    A method to get the balance of a trainer and the quantity of their item.
+
+    ## Parameters:
+    - `trainer`: The trainer.
+    */
+    async fn get_balance_and_quantity(trainer: &DBTrainer) -> (u64, u64) {
+        let balance = DBTrainer::get_by_discord_id(&trainer.discord_id, &trainer.discord_guild_id)
+            .await
+            .unwrap()
+            .unwrap()
+            .balance;
+        let items = DBInventoryItem::get_by_trainer_id(&trainer.id)
+            .await
+            .unwrap();
+        (balance, items[0].quantity)
+    }
+
+    #[test]
+    fn apply_in_transaction_writes_the_balance_and_the_entry() {
+        run_test(async {
+            let (trainer, item) = get_trainer_with_item(100).await;
+            buy(&trainer, &item, 40).await.unwrap();
+
+            assert_eq!(get_balance_and_quantity(&trainer).await, (60, 1));
+            let entries = get_trainer_entries(&trainer.id).await;
+            assert_eq!(entries.len(), 1);
+            assert_eq!(entries[0].amount, -40);
+            assert_eq!(entries[0].reason, LedgerReason::Purchase);
+        });
+    }
+
+    #[test]
+    fn apply_in_transaction_rejects_an_overspend() {
+        run_test(async {
+            let (trainer, item) = get_trainer_with_item(30).await;
+            assert!(buy(&trainer, &item, 40).await.is_err());
+
+            // Neither the balance, the item nor the ledger are changed.
+            assert_eq!(get_balance_and_quantity(&trainer).await, (30, 0));
+            assert!(get_trainer_entries(&trainer.id).await.is_empty());
+
+            buy(&trainer, &item, 30).await.unwrap();
+            assert_eq!(get_balance_and_quantity(&trainer).await, (0, 1));
+            assert_eq!(get_trainer_entries(&trainer.id).await.len(), 1);
+        });
+    }
+}
@@ -1,27 +1,152 @@
 // Libs
 use async_trait::async_trait;
 use serde::{de::DeserializeOwned, Serialize};
+use std::fmt::Debug;
+use surrealdb::sql::{Datetime, Thing};
+use tracing::info;
 
-use crate::errors::DatabaseResult;
+use crate::{di::Transaction, errors::DatabaseResult, services::DATABASE_SERVICE};
 
 // Traits
 /**
 A trait to define a database model. It'll be used to define the common methods for the database models.
+
+The models that only implement this trait are never changed once they're created.
 */
 #[async_trait]
-pub trait DBModel: DeserializeOwned + Serialize + Send + Sync + Unpin {
+pub trait DBModel: Clone + Debug + DeserializeOwned + Serialize + Send + Sync + Unpin {
+    /**
+    The table where the model is stored.
+    */
+    const TABLE: &'static str;
+
+    /**
+    A method to get the ID of the model.
+    */
+    fn get_id(&self) -> &Thing;
+
+    /**
+    A method to set the ID of the model.
+
+    ## Parameters:
+    - `id`: The ID of the model.
+    */
+    fn set_id(&mut self, id: Thing);
+
+    /**
+    A method to get the key of the model's record in its table.
+
+    It's the ID's key by default. The models keyed by a Discord ID use it as is, so it's not escaped.
+    */
+    fn get_key(&self) -> String {
+        self.get_id().id.to_string()
+    }
+
     /**
     A method to create a new model in the database.
     */
-    async fn create(&mut self) -> DatabaseResult<()>;
+    async fn create(&mut self) -> DatabaseResult<()> {
+        info!("Inserting a new record to the {} table...", Self::TABLE);
+        let db_svc = DATABASE_SERVICE.clone();
+        let record = db_svc
+            .insert_record(Self::TABLE, &self.get_key(), self.clone())
+            .await?;
+
+        let Some(record) = record else {
+            return Err(
+                format!("Failed to insert the record to the {} table.", Self::TABLE).into(),
+            );
+        };
+
+        self.set_id(record.get_id().clone());
+        info!("#{} inserted successfully.", self.get_id());
+        Ok(())
+    }
+
+    /**
+    A method to add the creation of the model to a transaction.
+
+    ## Parameters:
+    - `transaction`: The transaction to add the creation to.
+    */
+    fn create_in_transaction(&self, transaction: &mut Transaction) -> DatabaseResult<()> {
+        transaction.insert(Self::TABLE, &self.get_key(), self.clone())
+    }
+}
 
-    // /**
-    // A method to update a model in the database.
-    // */
-    // async fn update(&mut self) -> DatabaseResult<()>;
+/**
+A trait to define a database model that can be updated and deleted once it's created.
+*/
+#[async_trait]
+pub trait DBMutableModel: DBModel {
+    /**
+    A method to get the date the model was last updated.
+    */
+    fn get_updated_at(&self) -> &Datetime;
+
+    /**
+    A method to set the date the model was last updated.
+
+    ## Parameters:
+    - `updated_at`: The date the model was last updated.
+    */
+    fn set_updated_at(&mut self, updated_at: Datetime);
+
+    /**
+    A method to add the update of the model to a transaction. The `updated_at` field is bumped.
+
+    The transaction fails if the model was updated since it was read.
+
+    ## Parameters:
+    - `transaction`: The transaction to add the update to.
+    */
+    fn update_in_transaction(&mut self, transaction: &mut Transaction) -> DatabaseResult<()> {
+        let read_updated_at = self.get_updated_at().clone();
+        self.set_updated_at(Datetime::default());
+        transaction.update(Self::TABLE, &self.get_key(), self.clone(), &read_updated_at)
+    }
+
+    /**
+    A method to add the deletion of the model to a transaction.
+
+    ## Parameters:
+    - `transaction`: The transaction to add the deletion to.
+    */
+    fn delete_in_transaction(&self, transaction: &mut Transaction) {
+        transaction.delete(Self::TABLE, &self.get_key())
+    }
+
+    /**
+    A method to update a model in the database. The `updated_at` field is bumped.
+
+    It fails if the model was updated or deleted since it was read.
+    */
+    async fn update(&mut self) -> DatabaseResult<()> {
+        info!("Updating the #{} in the database...", self.get_id());
+        let db_svc = DATABASE_SERVICE.clone();
+        let read_updated_at = self.get_updated_at().clone();
+        self.set_updated_at(Datetime::default());
+        let record = db_svc
+            .update_record(Self::TABLE, &self.get_key(), self.clone(), &read_updated_at)
+            .await?;
+
+        if record.is_none() {
+            return Err(format!("Failed to update the #{}.", self.get_id()).into());
+        }
+
+        info!("#{} updated successfully.", self.get_id());
+        Ok(())
+    }
+
+    /**
+    A method to delete a model in the database.
+    */
+    async fn delete(&self) -> DatabaseResult<()> {
+        info!("Deleting the #{} from the database...", self.get_id());
+        let db_svc = DATABASE_SERVICE.clone();
+        db_svc.delete_record(Self::TABLE, &self.get_key()).await?;
 
-    // /**
-    // A method to delete a model in the database.
-    // */
-    // async fn delete(&self) -> DatabaseResult<()>;
+        info!("#{} deleted successfully.", self.get_id());
+        Ok(())
+    }
 }
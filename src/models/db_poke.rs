@@ -1,10 +1,8 @@
 // Libs
 use serde::{Deserialize, Serialize};
 use surrealdb::sql::{Datetime, Id, Thing};
-use tracing::info;
 
-use super::DBModel;
-use crate::{errors::DatabaseResult, services::DATABASE_SERVICE};
+use super::{DBModel, DBMutableModel};
 
 // DBPoke
 /**
@@ -44,47 +42,130 @@ impl DBPoke {
     }
 }
 
-#[async_trait::async_trait]
 impl DBModel for DBPoke {
-    async fn create(&mut self) -> DatabaseResult<()> {
-        info!("Inserting a new pokemon to the database...");
-        let db_svc = DATABASE_SERVICE.clone();
-        let poke_db = db_svc
-            .insert_record("pokemon", &self.id.id.to_string(), self.clone())
-            .await?;
-
-        if poke_db.is_none() {
-            return Err("Failed to insert the pokemon.".into());
-        }
+    const TABLE: &'static str = "pokemon";
+
+    fn get_id(&self) -> &Thing {
+        &self.id
+    }
+
+    fn set_id(&mut self, id: Thing) {
+        self.id = id;
+    }
+}
+
+impl DBMutableModel for DBPoke {
+    fn get_updated_at(&self) -> &Datetime {
+        &self.updated_at
+    }
+
+    fn set_updated_at(&mut self, updated_at: Datetime) {
+        self.updated_at = updated_at;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        di::Transaction,
+        models::DBTrainer,
+        services::DATABASE_SERVICE,
+        testing::{run_test, unique_id},
+    };
+
+    /**
+    A method to get a pokemon from the database.
+    */
+    async fn get_poke(poke: &DBPoke) -> Option<DBPoke> {
+        DATABASE_SERVICE
+            .clone()
+            .get_record("pokemon", &poke.id.id.to_string())
+            .await
+            .unwrap()
+    }
+
+    #[test]
+    fn poke_round_trip() {
+        run_test(async {
+            let mut trainer = DBTrainer::new(unique_id(), unique_id());
+            trainer.create().await.unwrap();
+            let mut poke = DBPoke::new(&trainer.id, &25, false);
+            poke.create().await.unwrap();
+
+            let found = get_poke(&poke).await.unwrap();
+            assert_eq!(found.trainer_id, trainer.id);
+            assert_eq!(found.poke_id, 25);
+            assert!(!found.is_shiny);
+
+            poke.is_shiny = true;
+            poke.update().await.unwrap();
+            let found = get_poke(&poke).await.unwrap();
+            assert!(found.is_shiny);
+            assert_ne!(found.updated_at, found.created_at);
 
-        self.id = poke_db.unwrap().id;
-        info!("Pokemon#{} inserted successfully.", self.id);
-        Ok(())
+            poke.delete().await.unwrap();
+            assert!(get_poke(&poke).await.is_none());
+        });
     }
 
-    // async fn update(&mut self) -> DatabaseResult<()> {
-    //     info!("Updating the pokemon#{} in the database...", self.id);
-    //     let db_svc = DATABASE_SERVICE.clone();
-    //     self.updated_at = Utc::now();
-    //     db_svc
-    //         .update_record("pokemon", &self.id, self.clone())
-    //
-    //         .await?;
-
-    //     info!("Pokemon#{} updated successfully.", self.id);
-    //     Ok(())
-    // }
-
-    // async fn delete(&self) -> DatabaseResult<()> {
-    //     info!("Deleting the pokemon#{} from the database...", self.id);
-
-    //     let db_svc = DATABASE_SERVICE.clone();
-    //     db_svc
-    //         .delete_record("pokemon", &self.id)
-    //
-    //         .await?;
-
-    //     info!("Pokemon#{} deleted successfully.", self.id);
-    //     Ok(())
-    // }
+    #[test]
+    fn update_in_transaction_rejects_a_stale_poke() {
+        run_test(async {
+            let mut trainer = DBTrainer::new(unique_id(), unique_id());
+            trainer.create().await.unwrap();
+            let mut poke = DBPoke::new(&trainer.id, &25, false);
+            poke.create().await.unwrap();
+            let mut stale_poke = poke.clone();
+
+            let mut transaction = Transaction::new();
+            poke.is_shiny = true;
+            poke.update_in_transaction(&mut transaction).unwrap();
+            let db_svc = DATABASE_SERVICE.clone();
+            db_svc.commit_transaction(transaction).await.unwrap();
+
+            let mut transaction = Transaction::new();
+            stale_poke.poke_id = 26;
+            stale_poke.update_in_transaction(&mut transaction).unwrap();
+            assert!(db_svc.commit_transaction(transaction).await.is_err());
+
+            let found = get_poke(&poke).await.unwrap();
+            assert_eq!(found.poke_id, 25);
+            assert!(found.is_shiny);
+        });
+    }
+
+    #[test]
+    fn update_rejects_a_stale_poke() {
+        run_test(async {
+            let mut trainer = DBTrainer::new(unique_id(), unique_id());
+            trainer.create().await.unwrap();
+            let mut poke = DBPoke::new(&trainer.id, &25, false);
+            poke.create().await.unwrap();
+            let mut stale_poke = poke.clone();
+
+            poke.is_shiny = true;
+            poke.update().await.unwrap();
+            stale_poke.poke_id = 26;
+            assert!(stale_poke.update().await.is_err());
+
+            let found = get_poke(&poke).await.unwrap();
+            assert_eq!(found.poke_id, 25);
+            assert!(found.is_shiny);
+        });
+    }
+
+    #[test]
+    fn update_never_recreates_a_deleted_poke() {
+        run_test(async {
+            let mut trainer = DBTrainer::new(unique_id(), unique_id());
+            trainer.create().await.unwrap();
+            let mut poke = DBPoke::new(&trainer.id, &25, false);
+            poke.create().await.unwrap();
+            poke.delete().await.unwrap();
+
+            assert!(poke.update().await.is_err());
+            assert!(get_poke(&poke).await.is_none());
+        });
+    }
 }
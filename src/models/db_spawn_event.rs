@@ -0,0 +1,119 @@
+// Libs
+use serde::{Deserialize, Serialize};
+use surrealdb::sql::{Datetime, Id, Thing};
+use tracing::info;
+
+use super::{DBActiveSpawn, DBModel};
+use crate::{di::QueryVars, errors::DatabaseResult, services::DATABASE_SERVICE};
+
+// Spawn Outcome
+/**
+An enum to represent how a spawn ended.
+*/
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SpawnOutcome {
+    /// A user caught the pokemon.
+    Caught,
+    /// Nobody caught the pokemon in time.
+    Fled,
+    /// The spawner made the pokemon run.
+    Ran,
+}
+
+// DBSpawnEvent
+/**
+A struct to represent a finished spawn in the database.
+*/
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct DBSpawnEvent {
+    pub id: Thing,
+    pub discord_guild_id: String,
+    pub discord_channel_id: String,
+    pub discord_message_id: String,
+    pub spawner_id: Option<String>,
+    pub poke_id: u16,
+    pub is_shiny: bool,
+    pub outcome: SpawnOutcome,
+    pub catcher_id: Option<String>,
+    pub catch_latency_ms: Option<u64>,
+    pub spawned_at: Datetime,
+    pub created_at: Datetime,
+    pub updated_at: Datetime,
+}
+
+impl DBSpawnEvent {
+    /**
+    A method to create a new instance of DBSpawnEvent from a spawn that just ended.
+
+    The catch latency is the time between the spawn and now.
+
+    ## Parameters:
+    - `active_spawn`: The spawn that ended.
+    - `outcome`: How the spawn ended.
+    - `catcher_id`: The Discord ID of the user who caught the pokemon, if any.
+    */
+    pub fn new(
+        active_spawn: &DBActiveSpawn,
+        outcome: SpawnOutcome,
+        catcher_id: Option<String>,
+    ) -> Self {
+        let now = Datetime::default();
+        let catch_latency_ms = catcher_id.as_ref().and_then(|_| {
+            let latency = now.0 - active_spawn.created_at.0;
+            u64::try_from(latency.num_milliseconds()).ok()
+        });
+
+        Self {
+            id: Thing {
+                tb: String::from("spawn_event"),
+                id: Id::ulid(),
+            },
+            discord_guild_id: active_spawn.discord_guild_id.clone(),
+            discord_channel_id: active_spawn.discord_channel_id.clone(),
+            discord_message_id: active_spawn.discord_message_id.clone(),
+            spawner_id: active_spawn.spawner_id.clone(),
+            poke_id: active_spawn.poke_id,
+            is_shiny: active_spawn.is_shiny,
+            outcome,
+            catcher_id,
+            catch_latency_ms,
+            spawned_at: active_spawn.created_at.clone(),
+            created_at: now.clone(),
+            updated_at: now,
+        }
+    }
+
+    /**
+    A method to get the most recent spawns of a guild, from the newest to the oldest.
+
+    ## Parameters:
+    - `discord_guild_id`: The Discord ID of the guild.
+    - `limit`: The maximum amount of spawns to get.
+    */
+    pub async fn get_recent_by_guild_id(
+        discord_guild_id: &str,
+        limit: u32,
+    ) -> DatabaseResult<Vec<Self>> {
+        info!("Getting the recent spawns of the guild...");
+        let db_svc = DATABASE_SERVICE.clone();
+        let query = "SELECT * FROM spawn_event WHERE discord_guild_id = $discord_guild_id ORDER BY created_at DESC LIMIT $limit";
+        let vars = QueryVars::from([
+            (String::from("discord_guild_id"), discord_guild_id.into()),
+            (String::from("limit"), limit.into()),
+        ]);
+        db_svc.run_query(query, vars).await
+    }
+}
+
+impl DBModel for DBSpawnEvent {
+    const TABLE: &'static str = "spawn_event";
+
+    fn get_id(&self) -> &Thing {
+        &self.id
+    }
+
+    fn set_id(&mut self, id: Thing) {
+        self.id = id;
+    }
+}
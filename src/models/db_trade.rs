@@ -0,0 +1,88 @@
+// Libs
+use serde::{Deserialize, Serialize};
+use surrealdb::sql::{Datetime, Id, Thing};
+
+use super::DBModel;
+
+// Trade Status
+/**
+An enum to represent how a trade ended.
+*/
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum TradeStatus {
+    /// Both trainers confirmed and the pokemons were swapped.
+    Completed,
+    /// One of the trainers cancelled the trade.
+    Cancelled,
+    /// The trainers didn't confirm the trade in time.
+    Expired,
+    /// A pokemon changed hands before the swap, so nothing was swapped.
+    Failed,
+}
+
+// DBTrade
+/**
+A struct to represent a finished trade between two trainers in the database.
+*/
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct DBTrade {
+    pub id: Thing,
+    pub discord_guild_id: String,
+    pub initiator_id: Thing,
+    pub partner_id: Thing,
+    pub initiator_poke_ids: Vec<Thing>,
+    pub partner_poke_ids: Vec<Thing>,
+    pub status: TradeStatus,
+    pub created_at: Datetime,
+    pub updated_at: Datetime,
+}
+
+impl DBTrade {
+    /**
+    A method to create a new instance of DBTrade.
+
+    ## Parameters:
+    - `discord_guild_id`: The Discord ID of the guild where the trade happened.
+    - `initiator_id`: The ID of the trainer who started the trade.
+    - `partner_id`: The ID of the trainer who was invited to the trade.
+    - `initiator_poke_ids`: The IDs of the pokemons offered by the initiator.
+    - `partner_poke_ids`: The IDs of the pokemons offered by the partner.
+    - `status`: How the trade ended.
+    */
+    pub fn new(
+        discord_guild_id: impl Into<String>,
+        initiator_id: &Thing,
+        partner_id: &Thing,
+        initiator_poke_ids: Vec<Thing>,
+        partner_poke_ids: Vec<Thing>,
+        status: TradeStatus,
+    ) -> Self {
+        Self {
+            id: Thing {
+                tb: String::from("trade"),
+                id: Id::ulid(),
+            },
+            discord_guild_id: discord_guild_id.into(),
+            initiator_id: initiator_id.clone(),
+            partner_id: partner_id.clone(),
+            initiator_poke_ids,
+            partner_poke_ids,
+            status,
+            created_at: Datetime::default(),
+            updated_at: Datetime::default(),
+        }
+    }
+}
+
+impl DBModel for DBTrade {
+    const TABLE: &'static str = "trade";
+
+    fn get_id(&self) -> &Thing {
+        &self.id
+    }
+
+    fn set_id(&mut self, id: Thing) {
+        self.id = id;
+    }
+}
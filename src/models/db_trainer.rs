@@ -1,11 +1,11 @@
 // Libs
 use serde::{Deserialize, Serialize};
 use surrealdb::sql::{Datetime, Id, Thing};
-use tracing::info;
+use tracing::{info, warn};
 
-use crate::{errors::DatabaseResult, services::DATABASE_SERVICE};
+use crate::{di::QueryVars, errors::DatabaseResult, services::DATABASE_SERVICE};
 
-use super::DBModel;
+use super::{DBModel, DBMutableModel};
 
 // Database Trainer
 /**
@@ -16,6 +16,8 @@ pub struct DBTrainer {
     pub id: Thing,
     pub discord_id: String,
     pub discord_guild_id: String,
+    #[serde(default)]
+    pub balance: u64,
     pub created_at: Datetime,
     pub updated_at: Datetime,
 }
@@ -29,7 +31,7 @@ impl DBTrainer {
     - `discord_id`: The Discord ID of the trainer.
     - `discord_guild_id`: The Discord Guild ID of the trainer.
     */
-    fn new<T>(discord_id: T, discord_guild_id: T) -> Self
+    pub fn new<T>(discord_id: T, discord_guild_id: T) -> Self
     where
         T: Into<String>,
     {
@@ -40,15 +42,39 @@ impl DBTrainer {
             },
             discord_id: discord_id.into(),
             discord_guild_id: discord_guild_id.into(),
+            balance: 0,
             created_at: Datetime::default(),
             updated_at: Datetime::default(),
         }
     }
 
+    /**
+    A method to get a trainer by their Discord ID.
+
+    ## Parameters:
+    - `discord_id`: The Discord ID of the trainer.
+    - `discord_guild_id`: The Discord Guild ID of the trainer.
+    */
+    pub async fn get_by_discord_id(
+        discord_id: &str,
+        discord_guild_id: &str,
+    ) -> DatabaseResult<Option<Self>> {
+        info!("Getting the trainer by Discord ID...");
+        let db_svc = DATABASE_SERVICE.clone();
+        let query = "SELECT * FROM trainer WHERE discord_id = $discord_id AND discord_guild_id = $discord_guild_id LIMIT 1";
+        let vars = QueryVars::from([
+            (String::from("discord_id"), discord_id.into()),
+            (String::from("discord_guild_id"), discord_guild_id.into()),
+        ]);
+        let mut trainer_db: Vec<Self> = db_svc.run_query(query, vars).await?;
+        Ok(trainer_db.pop())
+    }
+
     /**
     A method to find a trainer by their Discord ID.
 
-    If the trainer is not found, a new trainer will be created.
+    If the trainer is not found, a new trainer will be created. The trainers are unique per guild,
+    so if a concurrent interaction creates the trainer first, that trainer is returned instead.
 
     ## Parameters:
     - `discord_id`: The Discord ID of the trainer.
@@ -59,64 +85,179 @@ impl DBTrainer {
         discord_guild_id: &str,
     ) -> DatabaseResult<Self> {
         info!("Finding the trainer by Discord ID...");
-        let db_svc = DATABASE_SERVICE.clone();
-        let query = format!(
-            "SELECT * FROM trainer WHERE discord_id = '{}' AND discord_guild_id = '{}' LIMIT 1",
-            discord_id, discord_guild_id
-        );
-        let mut trainer_db: Vec<Self> = db_svc.run_query(&query).await?;
-        if trainer_db.is_empty() {
-            info!("Trainer not found. Creating a new trainer...");
-            let mut new_trainer = Self::new(discord_id, discord_guild_id);
-            new_trainer.create().await?;
-            return Ok(new_trainer);
+        if let Some(trainer) = Self::get_by_discord_id(discord_id, discord_guild_id).await? {
+            info!("Trainer found successfully.");
+            return Ok(trainer);
         }
 
-        info!("Trainer found successfully.");
-        Ok(trainer_db.pop().unwrap())
+        info!("Trainer not found. Creating a new trainer...");
+        let mut new_trainer = Self::new(discord_id, discord_guild_id);
+        if let Err(e) = new_trainer.create().await {
+            let Some(trainer) = Self::get_by_discord_id(discord_id, discord_guild_id).await? else {
+                return Err(e);
+            };
+
+            warn!("The trainer was created concurrently. {}", e);
+            return Ok(trainer);
+        }
+
+        Ok(new_trainer)
     }
 }
 
-#[async_trait::async_trait]
 impl DBModel for DBTrainer {
-    async fn create(&mut self) -> DatabaseResult<()> {
-        info!("Inserting a new trainer to the database...");
-        let db_svc = DATABASE_SERVICE.clone();
-        let trainer_db = db_svc
-            .insert_record("trainer", &self.id.id.to_string(), self.clone())
-            .await?;
+    const TABLE: &'static str = "trainer";
 
-        if trainer_db.is_none() {
-            return Err("Failed to insert the trainer.".into());
-        }
+    fn get_id(&self) -> &Thing {
+        &self.id
+    }
 
-        self.id = trainer_db.unwrap().id;
-        info!("#{} inserted successfully.", self.id);
-        Ok(())
+    fn set_id(&mut self, id: Thing) {
+        self.id = id;
     }
+}
+
+impl DBMutableModel for DBTrainer {
+    fn get_updated_at(&self) -> &Datetime {
+        &self.updated_at
+    }
+
+    fn set_updated_at(&mut self, updated_at: Datetime) {
+        self.updated_at = updated_at;
+    }
+}
 
-    // async fn update(&mut self) -> DatabaseResult<()> {
-    //     info!("Updating the trainer in the database...");
-    //     let db_svc = DATABASE_SERVICE.clone();
-    //     self.updated_at = Utc::now();
-    //     db_svc
-    //         .update_record("trainer", &self.id, self.clone())
-    //
-    //         .await?;
-
-    //     info!("#{} updated successfully.", self.id);
-    //     Ok(())
-    // }
-
-    // async fn delete(&self) -> DatabaseResult<()> {
-    //     info!("Deleting the trainer#{} from the database...", self.id);
-    //     let db_svc = DATABASE_SERVICE.clone();
-    //     db_svc
-    //         .delete_record("trainer", &self.id)
-    //
-    //         .await?;
-
-    //     info!("#{} deleted successfully.", self.id);
-    //     Ok(())
-    // }
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::{run_test, unique_id, HOSTILE_IDS};
+
+    /**
+    A method to get all the trainers of a guild.
+    */
+    async fn get_guild_trainers(discord_guild_id: &str) -> Vec<DBTrainer> {
+        let query = "SELECT * FROM trainer WHERE discord_guild_id = $discord_guild_id ORDER BY id";
+        let vars = QueryVars::from([(String::from("discord_guild_id"), discord_guild_id.into())]);
+        DATABASE_SERVICE
+            .clone()
+            .run_query(query, vars)
+            .await
+            .unwrap()
+    }
+
+    #[test]
+    fn trainer_round_trip() {
+        run_test(async {
+            let (discord_id, guild_id) = (unique_id(), unique_id());
+            let mut trainer = DBTrainer::new(discord_id.as_str(), guild_id.as_str());
+            trainer.create().await.unwrap();
+
+            let found = DBTrainer::get_by_discord_id(&discord_id, &guild_id)
+                .await
+                .unwrap()
+                .unwrap();
+            assert_eq!(found.id, trainer.id);
+            assert_eq!(found.balance, 0);
+
+            trainer.balance = 100;
+            trainer.update().await.unwrap();
+            let found = DBTrainer::get_by_discord_id(&discord_id, &guild_id)
+                .await
+                .unwrap()
+                .unwrap();
+            assert_eq!(found.balance, 100);
+
+            trainer.delete().await.unwrap();
+            let found = DBTrainer::get_by_discord_id(&discord_id, &guild_id)
+                .await
+                .unwrap();
+            assert!(found.is_none());
+        });
+    }
+
+    #[test]
+    fn find_by_discord_id_creates_the_trainer_once() {
+        run_test(async {
+            let (discord_id, guild_id) = (unique_id(), unique_id());
+            let created = DBTrainer::find_by_discord_id(&discord_id, &guild_id)
+                .await
+                .unwrap();
+            let found = DBTrainer::find_by_discord_id(&discord_id, &guild_id)
+                .await
+                .unwrap();
+            assert_eq!(found.id, created.id);
+
+            // The trainers are apart in each guild.
+            let other_guild = DBTrainer::find_by_discord_id(&discord_id, &unique_id())
+                .await
+                .unwrap();
+            assert_ne!(other_guild.id, created.id);
+        });
+    }
+
+    #[test]
+    fn get_by_discord_id_binds_hostile_ids() {
+        run_test(async {
+            let guild_id = unique_id();
+            let victim = DBTrainer::find_by_discord_id(&unique_id(), &guild_id)
+                .await
+                .unwrap();
+
+            for hostile_id in HOSTILE_IDS {
+                let found = DBTrainer::get_by_discord_id(hostile_id, &guild_id)
+                    .await
+                    .unwrap();
+                assert!(found.is_none(), "{} found a trainer", hostile_id);
+                let found = DBTrainer::get_by_discord_id(&victim.discord_id, hostile_id)
+                    .await
+                    .unwrap();
+                assert!(found.is_none(), "{} found a trainer", hostile_id);
+            }
+
+            let trainers = get_guild_trainers(&guild_id).await;
+            assert_eq!(trainers.len(), 1);
+            assert_eq!(trainers[0].updated_at, victim.updated_at);
+        });
+    }
+
+    #[test]
+    fn find_by_discord_id_binds_hostile_ids() {
+        run_test(async {
+            let guild_id = unique_id();
+            let victim = DBTrainer::find_by_discord_id(&unique_id(), &guild_id)
+                .await
+                .unwrap();
+
+            // Each hostile ID is only stored as the ID of a new trainer.
+            for hostile_id in HOSTILE_IDS {
+                let trainer = DBTrainer::find_by_discord_id(hostile_id, &guild_id)
+                    .await
+                    .unwrap();
+                assert_ne!(trainer.id, victim.id);
+                assert_eq!(trainer.discord_id, hostile_id);
+            }
+
+            let trainers = get_guild_trainers(&guild_id).await;
+            assert_eq!(trainers.len(), HOSTILE_IDS.len() + 1);
+            let found = trainers
+                .iter()
+                .find(|trainer| trainer.id == victim.id)
+                .unwrap();
+            assert_eq!(found.discord_id, victim.discord_id);
+            assert_eq!(found.updated_at, victim.updated_at);
+        });
+    }
+
+    #[test]
+    fn find_by_discord_id_creates_a_single_trainer_concurrently() {
+        run_test(async {
+            let (discord_id, guild_id) = (unique_id(), unique_id());
+            let find = || DBTrainer::find_by_discord_id(&discord_id, &guild_id);
+            let (first, second, third) = tokio::join!(find(), find(), find());
+
+            let ids = [first.unwrap().id, second.unwrap().id, third.unwrap().id];
+            assert!(ids.iter().all(|id| *id == ids[0]));
+            assert_eq!(get_guild_trainers(&guild_id).await.len(), 1);
+        });
+    }
 }
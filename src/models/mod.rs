@@ -1,8 +1,22 @@
 // Libs
-pub use db_model::DBModel;
+pub use db_active_spawn::DBActiveSpawn;
+pub use db_daily_claim::DBDailyClaim;
+pub use db_guild_settings::{DBGuildSettings, GUILD_SETTING_KEYS};
+pub use db_inventory_item::{BallType, DBInventoryItem};
+pub use db_ledger_entry::{DBLedgerEntry, LedgerReason};
+pub use db_model::{DBModel, DBMutableModel};
 pub use db_poke::DBPoke;
+pub use db_spawn_event::{DBSpawnEvent, SpawnOutcome};
+pub use db_trade::{DBTrade, TradeStatus};
 pub use db_trainer::DBTrainer;
 
+mod db_active_spawn;
+mod db_daily_claim;
+mod db_guild_settings;
+mod db_inventory_item;
+mod db_ledger_entry;
 mod db_model;
 mod db_poke;
+mod db_spawn_event;
+mod db_trade;
 mod db_trainer;
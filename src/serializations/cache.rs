@@ -1,11 +1,19 @@
 // Libs
 use serde::{Deserialize, Serialize};
 
+use crate::models::BallType;
+
 use super::pokeapi::{
-    PokeAPIChain, PokeAPIPokemon, PokeAPIPokemonEvolutionChain, PokeAPIPokemonSpecies,
-    PokeAPIResource, PokeAPISprites, PokeAPIStat, PokeAPIType,
+    PokeAPIChain, PokeAPIGeneration, PokeAPIPokemon, PokeAPIPokemonEvolutionChain,
+    PokeAPIPokemonSpecies, PokeAPIResource, PokeAPISprites, PokeAPIStat, PokeAPIType,
 };
 
+// Constants
+/**
+The minimum chance of a catch attempt to succeed.
+*/
+pub const MIN_CATCH_CHANCE: f64 = 0.05;
+
 // Cached Pokemon
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct CachedPokemon {
@@ -23,6 +31,7 @@ pub struct CachedPokemon {
     pub is_legendary: bool,
     pub is_mythical: bool,
     pub evolves_to: Vec<PokeAPIChain>,
+    pub capture_rate: u8,
 }
 
 impl CachedPokemon {
@@ -54,6 +63,95 @@ impl CachedPokemon {
             is_legendary: species.is_legendary,
             is_mythical: species.is_mythical,
             evolves_to: evolution_chain.chain.evolves_to,
+            capture_rate: species.capture_rate,
+        }
+    }
+
+    /**
+    A method to get the chance, from 0 to 1, of a catch attempt to succeed.
+
+    The chance is the species' capture rate out of 255, multiplied by the ball's catch modifier,
+    so the species that are hard to catch in the games are also hard to catch here. It never goes
+    below `MIN_CATCH_CHANCE`.
+
+    ## Parameters:
+    - `ball`: The ball thrown at the pokemon.
+    */
+    pub fn get_catch_chance(&self, ball: BallType) -> f64 {
+        let Some(modifier) = ball.get_catch_modifier() else {
+            return 1.0;
+        };
+        (f64::from(self.capture_rate) / 255.0 * modifier).clamp(MIN_CATCH_CHANCE, 1.0)
+    }
+}
+
+// Cached Spawn Entry
+/**
+A struct to represent a species in the spawn table, with only the attributes used to weight the spawns.
+*/
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct CachedSpawnEntry {
+    pub id: u16,
+    pub name: String,
+    pub capture_rate: u8,
+    pub is_baby: bool,
+    pub is_legendary: bool,
+    pub is_mythical: bool,
+}
+
+impl From<PokeAPIPokemonSpecies> for CachedSpawnEntry {
+    fn from(species: PokeAPIPokemonSpecies) -> Self {
+        CachedSpawnEntry {
+            id: species.id,
+            name: species.name,
+            capture_rate: species.capture_rate,
+            is_baby: species.is_baby,
+            is_legendary: species.is_legendary,
+            is_mythical: species.is_mythical,
+        }
+    }
+}
+
+// Cached Generation
+/**
+A struct to represent a generation and the species introduced in it.
+*/
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct CachedGeneration {
+    pub id: u8,
+    pub name: String,
+    pub region: String,
+    pub species_ids: Vec<u16>,
+}
+
+impl CachedGeneration {
+    /**
+    A method to check if the generation matches an identifier given by a user.
+
+    ## Parameters:
+    - `identifier`: The ID, the name (e.g. `generation-i`) or the region (e.g. `kanto`) of the generation.
+    */
+    pub fn matches(&self, identifier: &str) -> bool {
+        let identifier = identifier.trim().to_lowercase();
+        self.id.to_string() == identifier || self.name == identifier || self.region == identifier
+    }
+}
+
+impl From<PokeAPIGeneration> for CachedGeneration {
+    fn from(generation: PokeAPIGeneration) -> Self {
+        // The species' IDs are only available in their URLs.
+        let species_ids = generation
+            .pokemon_species
+            .iter()
+            .filter_map(|species| species.url.trim_end_matches('/').rsplit('/').next())
+            .filter_map(|id| id.parse().ok())
+            .collect();
+
+        CachedGeneration {
+            id: generation.id,
+            name: generation.name,
+            region: generation.main_region.name,
+            species_ids,
         }
     }
 }
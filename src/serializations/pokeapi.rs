@@ -58,6 +58,7 @@ pub struct PokeAPIResource {
 // Pokemon Species
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct PokeAPIPokemonSpecies {
+    pub capture_rate: u8,
     pub evolution_chain: PokeAPIEvolutionchain,
     pub evolves_from_species: Option<PokeAPIResource>,
     pub has_gender_differences: bool,
@@ -89,3 +90,17 @@ pub struct PokeAPIChain {
     pub evolves_to: Vec<PokeAPIChain>,
     pub species: PokeAPIResource,
 }
+
+// Generation
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct PokeAPIGeneration {
+    pub id: u8,
+    pub main_region: PokeAPIResource,
+    pub name: String,
+    pub pokemon_species: Vec<PokeAPIResource>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct PokeAPIGenerationCount {
+    pub count: u8,
+}
@@ -1,16 +1,34 @@
 // Libs
 use once_cell::sync::Lazy;
 use serde::{de::DeserializeOwned, Serialize};
-use std::sync::Arc;
+use std::{process::exit, sync::Arc};
+use surrealdb::sql::Datetime;
 use tokio::sync::RwLock;
-use tracing::{debug, info};
+use tracing::{debug, error, info};
 
-use crate::di::{Database, SurrealDB};
+use crate::di::{Database, QueryVars, SurrealDB, SurrealEngine, Transaction};
 use crate::errors::DatabaseResult;
+use crate::utils::config::Config;
 
 // Data
 pub static DATABASE_SERVICE: Lazy<Arc<DatabaseService<SurrealDB>>> = Lazy::new(|| {
-    let db_src = DatabaseService::new(RwLock::new(SurrealDB::default()));
+    let config = match Config::global() {
+        Ok(config) => &config.database,
+        Err(e) => {
+            error!("Error getting the database configuration: {}", e);
+            exit(1);
+        }
+    };
+    let engine = match SurrealEngine::from_config(config) {
+        Ok(engine) => engine,
+        Err(e) => {
+            error!("Error getting the database engine: {}", e);
+            exit(1);
+        }
+    };
+
+    let database = SurrealDB::new(engine, &config.namespace, &config.name);
+    let db_src = DatabaseService::new(RwLock::new(database));
     Arc::new(db_src)
 });
 
@@ -58,40 +76,73 @@ where
         Ok(())
     }
 
+    /**
+    A method to roll back the database's migrations to a version.
+
+    ## Parameters:
+    - `version`: The schema version to roll back to.
+    */
+    pub async fn rollback_migrations(&self, version: u32) -> DatabaseResult<()> {
+        // Revert the database migrations.
+        info!("Rolling back the database migrations to v{}...", version);
+        self.database
+            .read()
+            .await
+            .rollback_migrations(version)
+            .await?;
+        info!("Database migrations rolled back.");
+        Ok(())
+    }
+
     /**
     A method to run a database query.
 
     ## Parameters:
     - `query`: The query to run.
+    - `vars`: The variables to bind to the query.
     */
-    pub async fn run_query<T>(&self, query: &str) -> DatabaseResult<Vec<T>>
+    pub async fn run_query<T>(&self, query: &str, vars: QueryVars) -> DatabaseResult<Vec<T>>
     where
         T: DeserializeOwned + Send + Sync + Unpin,
     {
         // Run the query on the database.
-        debug!("Running query: '{}'", query);
-        let result = self.database.read().await.query(query).await?;
+        debug!("Running query: '{}' with {:?}", query, vars);
+        let result = self.database.read().await.query(query, vars).await?;
         info!("Query ran successfully.");
         Ok(result)
     }
 
-    // /**
-    // A method to get a record from the database.
-
-    // ## Parameters:
-    // - `tb`: The table to get the record from.
-    // - `id`: The ID of the record to get.
-    // */
-    // pub async fn get_record<T>(&self, tb: &str, id: &Ulid) -> DatabaseResult<Option<T>>
-    // where
-    //     T: DeserializeOwned + Send + Sync + Unpin,
-    // {
-    //     // Get the record from the database.
-    //     info!("Getting record from table '{}' with ID '{}'", tb, id);
-    //     let record = self.database.read().await.get(tb, id).await?;
-    //     info!("Got record from table '{}' with ID '{}'", tb, id);
-    //     Ok(record)
-    // }
+    /**
+    A method to atomically apply a transaction.
+
+    ## Parameters:
+    - `transaction`: The transaction to commit.
+    */
+    pub async fn commit_transaction(&self, transaction: Transaction) -> DatabaseResult<()> {
+        // Commit the transaction on the database.
+        debug!("Committing transaction: {:?}", transaction);
+        self.database.read().await.commit(transaction).await?;
+        info!("Transaction committed successfully.");
+        Ok(())
+    }
+
+    /**
+    A method to get a record from the database.
+
+    ## Parameters:
+    - `tb`: The table to get the record from.
+    - `id`: The ID of the record to get.
+    */
+    pub async fn get_record<T>(&self, tb: &str, id: &str) -> DatabaseResult<Option<T>>
+    where
+        T: DeserializeOwned + Send + Sync + Unpin,
+    {
+        // Get the record from the database.
+        info!("Getting record #{}:{}...", tb, id);
+        let record = self.database.read().await.get(tb, id).await?;
+        info!("Got record #{}:{}.", tb, id);
+        Ok(record)
+    }
 
     /**
     A method to insert a new record into the database.
@@ -111,37 +162,49 @@ where
         Ok(result)
     }
 
-    // /**
-    // A method to update a record in the database.
-
-    // ## Parameters:
-    // - `tb`: The table to update the record in.
-    // - `id`: The ID of the record to update.
-    // - `record`: The record to update the record with.
-    // */
-    // pub async fn update_record<T>(&self, tb: &str, id: &Ulid, record: T) -> DatabaseResult<()>
-    // where
-    //     T: Serialize + Send + Sync + Unpin,
-    // {
-    //     // Update the record in the database.
-    //     info!("Updating record in table '{}' with ID '{}'", tb, id);
-    //     self.database.read().await.update(tb, id, record).await?;
-    //     info!("Updated record in table '{}' with ID '{}'", tb, id);
-    //     Ok(())
-    // }
-
-    // /**
-    // A method to delete a record from the database.
-
-    // ## Parameters:
-    // - `tb`: The table to delete the record from.
-    // - `id`: The ID of the record to delete.
-    // */
-    // pub async fn delete_record(&self, tb: &str, id: &Ulid) -> DatabaseResult<()> {
-    //     // Delete the record from the database.
-    //     info!("Deleting record from table '{}' with ID '{}'", tb, id);
-    //     self.database.read().await.delete(tb, id).await?;
-    //     info!("Deleted record from table '{}' with ID '{}'", tb, id);
-    //     Ok(())
-    // }
+    /**
+    A method to update a record in the database.
+
+    ## Parameters:
+    - `tb`: The table to update the record in.
+    - `id`: The ID of the record to update.
+    - `record`: The record to update the record with.
+    - `read_updated_at`: The `updated_at` field of the record when it was read.
+    */
+    pub async fn update_record<T>(
+        &self,
+        tb: &str,
+        id: &str,
+        record: T,
+        read_updated_at: &Datetime,
+    ) -> DatabaseResult<Option<T>>
+    where
+        T: DeserializeOwned + Serialize + Send + Sync + Unpin,
+    {
+        // Update the record in the database.
+        info!("Updating record #{}:{}...", tb, id);
+        let result = self
+            .database
+            .read()
+            .await
+            .update(tb, id, record, read_updated_at)
+            .await?;
+        info!("Updated record #{}:{}.", tb, id);
+        Ok(result)
+    }
+
+    /**
+    A method to delete a record from the database.
+
+    ## Parameters:
+    - `tb`: The table to delete the record from.
+    - `id`: The ID of the record to delete.
+    */
+    pub async fn delete_record(&self, tb: &str, id: &str) -> DatabaseResult<()> {
+        // Delete the record from the database.
+        info!("Deleting record #{}:{}...", tb, id);
+        self.database.read().await.delete(tb, id).await?;
+        info!("Deleted record #{}:{}.", tb, id);
+        Ok(())
+    }
 }
@@ -1,30 +1,58 @@
 // Libs
 use once_cell::sync::Lazy;
-use std::{process::exit, sync::Arc};
-use tokio::sync::RwLock;
-use tracing::{error, info};
+use serenity::futures::{stream, StreamExt, TryStreamExt};
+use std::{process::exit, sync::Arc, time::Duration};
+use tokio::sync::{Mutex, RwLock};
+use tracing::{error, info, warn};
 
-use crate::di::{Cache, RedisCache};
-use crate::di::{HTTPClient, ReqwestHTTPClient};
+use crate::di::{Cache, CacheBackend};
+use crate::di::{HTTPClient, HTTPClientBackend};
 use crate::errors::PokeFinderResult;
-use crate::serializations::cache::CachedPokemon;
+use crate::serializations::cache::{CachedGeneration, CachedPokemon, CachedSpawnEntry};
 use crate::serializations::pokeapi::{
-    PokeAPIPokemon, PokeAPIPokemonEvolutionChain, PokeAPIPokemonSpecies, PokeAPIPokemonSpeciesCount,
+    PokeAPIGeneration, PokeAPIGenerationCount, PokeAPIPokemon, PokeAPIPokemonEvolutionChain,
+    PokeAPIPokemonSpecies, PokeAPIPokemonSpeciesCount,
 };
-use crate::utils::EnvManager;
+use crate::utils::config::Config;
+
+// Constants
+/**
+The maximum amount of species requested at the same time while building the spawn table.
+*/
+const SPAWN_TABLE_CONCURRENCY: usize = 16;
+
+/**
+The time-to-live of the spawn table and the counts it's built from, so the new species are added
+and the skipped ones are retried.
+*/
+const SPAWN_TABLE_TTL: Duration = Duration::from_secs(24 * 60 * 60);
 
 // Data
-pub static POKEFINDER_SERVICE: Lazy<Arc<PokeFinder<RedisCache, ReqwestHTTPClient>>> =
+pub static POKEFINDER_SERVICE: Lazy<Arc<PokeFinder<CacheBackend, HTTPClientBackend>>> =
     Lazy::new(|| {
-        let redis_instance = RedisCache::new();
-        if let Err(e) = redis_instance {
-            error!("Error creating Redis instance: {:?}", e);
+        let config = match Config::global() {
+            Ok(config) => config,
+            Err(e) => {
+                error!("Error getting the PokeFinder configuration: {}", e);
+                exit(1);
+            }
+        };
+        let cache_instance = CacheBackend::new(&config.cache);
+        if let Err(e) = cache_instance {
+            error!("Error creating the cache instance: {:?}", e);
+            exit(1);
+        };
+
+        let http_client_instance = HTTPClientBackend::new(&config.pokeapi);
+        if let Err(e) = http_client_instance {
+            error!("Error creating the HTTP client instance: {:?}", e);
             exit(1);
         };
 
         Arc::new(PokeFinder::new(
-            RwLock::new(redis_instance.unwrap()),
-            ReqwestHTTPClient::new(),
+            RwLock::new(cache_instance.unwrap()),
+            http_client_instance.unwrap(),
+            &config.pokeapi.url,
         ))
     });
 
@@ -47,6 +75,8 @@ where
 {
     cache: RwLock<T>,
     http_client: U,
+    pokeapi_url: String,
+    spawn_table_lock: Mutex<()>,
 }
 
 impl<T, U> PokeFinder<T, U>
@@ -60,9 +90,15 @@ where
     ## Parameters:
     - `cache`: The cache to use for the service. It should implement the Cache trait.
     - `http_client`: The HTTPClient to use for the service. It should implement the HTTPClient trait.
+    - `pokeapi_url`: The PokeAPI's URL.
     */
-    pub fn new(cache: RwLock<T>, http_client: U) -> Self {
-        Self { cache, http_client }
+    pub fn new(cache: RwLock<T>, http_client: U, pokeapi_url: &str) -> Self {
+        Self {
+            cache,
+            http_client,
+            pokeapi_url: pokeapi_url.to_string(),
+            spawn_table_lock: Mutex::new(()),
+        }
     }
 
     /**
@@ -86,10 +122,17 @@ where
         info!("Checking if the pokemon#{} is in the cache...", identifier);
         let cache = self.cache.read().await;
 
+        // The pokemons cached by an older version may lack a field, e.g. the capture rate, so they're
+        // retrieved again.
         let poke = cache.get_key(identifier).await?;
         if let Some(poke) = poke {
-            info!("The pokemon#{} is in the cache.", identifier);
-            return Ok(serde_json::from_str(&poke).unwrap());
+            match serde_json::from_str(&poke) {
+                Ok(poke) => {
+                    info!("The pokemon#{} is in the cache.", identifier);
+                    return Ok(poke);
+                }
+                Err(e) => warn!("The pokemon#{} in the cache is outdated. {}", identifier, e),
+            }
         }
 
         info!(
@@ -124,10 +167,7 @@ where
         }
 
         info!("The amount of pokemons is not in the cache. Retrieving from the PokeAPI...");
-        let url = format!(
-            "{}/pokemon-species/",
-            EnvManager::get_var::<String>("POKEAPI_URL")
-        );
+        let url = format!("{}/pokemon-species/", self.pokeapi_url);
         let poke_count = self
             .http_client
             .access::<PokeAPIPokemonSpeciesCount>("GET", &url)
@@ -136,13 +176,120 @@ where
 
         info!("Inserting the amount of pokemons in the cache...");
         cache
-            .insert_key("pokemons_count", &poke_count.to_string())
+            .insert_persistent_key("pokemons_count", &poke_count.to_string(), SPAWN_TABLE_TTL)
             .await?;
 
         info!("The amount of pokemons has been inserted in the cache.");
         Ok(poke_count)
     }
 
+    /**
+    A method to get the spawn table, with the rarity attributes of every species.
+
+    The table is built from all the species in the PokeAPI the first time and then read from the cache
+    until it expires. The species that can't be retrieved are skipped.
+    */
+    pub async fn get_spawn_table(&self) -> PokeFinderResult<Vec<CachedSpawnEntry>> {
+        // Only one task builds the table, the others wait for it to be cached.
+        let _lock = self.spawn_table_lock.lock().await;
+
+        info!("Checking if the spawn table is in the cache...");
+        let cache = self.cache.read().await;
+        let spawn_table = cache.get_key("spawn_table").await?;
+        if let Some(spawn_table) = spawn_table {
+            match serde_json::from_str(&spawn_table) {
+                Ok(spawn_table) => {
+                    info!("The spawn table is in the cache.");
+                    return Ok(spawn_table);
+                }
+                Err(e) => warn!("The spawn table in the cache is outdated. {}", e),
+            }
+        }
+        drop(cache);
+
+        let poke_count = self.get_poke_count().await?;
+        info!(
+            "The spawn table is not in the cache. Retrieving {} species from the PokeAPI...",
+            poke_count
+        );
+        let mut spawn_table: Vec<CachedSpawnEntry> = stream::iter(1..=poke_count)
+            .map(|id| async move {
+                match self.get_poke_species(&id.to_string()).await {
+                    Ok(species) => Some(CachedSpawnEntry::from(species)),
+                    Err(e) => {
+                        warn!("Skipping the species#{} of the spawn table. {}", id, e);
+                        None
+                    }
+                }
+            })
+            .buffer_unordered(SPAWN_TABLE_CONCURRENCY)
+            .filter_map(|entry| async move { entry })
+            .collect()
+            .await;
+        spawn_table.sort_by_key(|entry| entry.id);
+
+        info!("Inserting the spawn table in the cache...");
+        let cache = self.cache.read().await;
+        cache
+            .insert_persistent_key(
+                "spawn_table",
+                &serde_json::to_string(&spawn_table).unwrap(),
+                SPAWN_TABLE_TTL,
+            )
+            .await?;
+
+        info!("The spawn table has been inserted in the cache.");
+        Ok(spawn_table)
+    }
+
+    /**
+    A method to get all the generations and the species introduced in each one, ordered by ID.
+    */
+    pub async fn get_generations(&self) -> PokeFinderResult<Vec<CachedGeneration>> {
+        info!("Checking if the generations are in the cache...");
+        let cache = self.cache.read().await;
+
+        let generations = cache.get_key("generations").await?;
+        if let Some(generations) = generations {
+            info!("The generations are in the cache.");
+            return serde_json::from_str(&generations)
+                .map_err(|e| format!("Invalid generations in the cache. {}", e).into());
+        }
+
+        info!("The generations are not in the cache. Retrieving from the PokeAPI...");
+        let url = format!("{}/generation/", self.pokeapi_url);
+        let generation_count = self
+            .http_client
+            .access::<PokeAPIGenerationCount>("GET", &url)
+            .await?
+            .count;
+        let mut generations: Vec<CachedGeneration> = stream::iter(1..=generation_count)
+            .map(|id| async move {
+                let url = format!("{}/generation/{}", self.pokeapi_url, id);
+                let generation = self
+                    .http_client
+                    .access::<PokeAPIGeneration>("GET", &url)
+                    .await?;
+                PokeFinderResult::Ok(CachedGeneration::from(generation))
+            })
+            .buffer_unordered(SPAWN_TABLE_CONCURRENCY)
+            .try_collect()
+            .await?;
+        generations.sort_by_key(|generation| generation.id);
+
+        info!("Inserting the generations in the cache...");
+        cache
+            .insert_persistent_key(
+                "generations",
+                &serde_json::to_string(&generations).unwrap(),
+                SPAWN_TABLE_TTL,
+            )
+            .await?;
+
+        info!("The generations have been inserted in the cache.");
+        Ok(generations)
+    }
+
     /**
     A method to create a cached pokemon from a pokemon, a species, and an evolution chain.
 
@@ -173,11 +320,7 @@ where
     - `identifier`: The identifier of the pokemon to get.
     */
     async fn get_poke(&self, identifier: &str) -> PokeFinderResult<PokeAPIPokemon> {
-        let url = format!(
-            "{}/pokemon/{}",
-            EnvManager::get_var::<String>("POKEAPI_URL"),
-            identifier
-        );
+        let url = format!("{}/pokemon/{}", self.pokeapi_url, identifier);
         Ok(self.http_client.access("GET", &url).await?)
     }
 
@@ -188,11 +331,7 @@ where
     - `identifier`: The identifier of the species to get.
     */
     async fn get_poke_species(&self, identifier: &str) -> PokeFinderResult<PokeAPIPokemonSpecies> {
-        let url = format!(
-            "{}/pokemon-species/{}",
-            EnvManager::get_var::<String>("POKEAPI_URL"),
-            identifier
-        );
+        let url = format!("{}/pokemon-species/{}", self.pokeapi_url, identifier);
         Ok(self
             .http_client
             .access::<PokeAPIPokemonSpecies>("GET", &url)
@@ -206,14 +345,245 @@ where
         &self,
         identifier: &str,
     ) -> PokeFinderResult<PokeAPIPokemonEvolutionChain> {
-        let url = format!(
-            "{}/evolution-chain/{}",
-            EnvManager::get_var::<String>("POKEAPI_URL"),
-            identifier
-        );
+        let url = format!("{}/evolution-chain/{}", self.pokeapi_url, identifier);
         Ok(self
             .http_client
             .access::<PokeAPIPokemonEvolutionChain>("GET", &url)
             .await?)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use serde::Serialize;
+    use std::{
+        fs,
+        path::{Path, PathBuf},
+        sync::atomic::{AtomicUsize, Ordering},
+    };
+
+    use super::*;
+    use crate::{
+        di::LocalHTTPClient,
+        serializations::pokeapi::{
+            PokeAPIChain, PokeAPIEvolutionchain, PokeAPIResource, PokeAPISprites,
+        },
+        utils::config::CacheConfig,
+    };
+
+    const POKEAPI_URL: &str = "https://pokeapi.co/api/v2";
+    const SPECIES: [(u16, &str, u8); 3] = [
+        (1, "bulbasaur", 45),
+        (2, "ivysaur", 45),
+        (3, "venusaur", 45),
+    ];
+
+    /**
+    A method to write a resource of the fixture's data dump.
+    */
+    fn write_resource(root: &Path, resource_path: &str, resource: impl Serialize) {
+        let directory = root.join(resource_path);
+        fs::create_dir_all(&directory).unwrap();
+        fs::write(
+            directory.join("index.json"),
+            serde_json::to_string(&resource).unwrap(),
+        )
+        .unwrap();
+    }
+
+    /**
+    A method to write a PokeAPI data dump with the first evolution line, in a new directory.
+    */
+    fn write_fixtures() -> PathBuf {
+        static FIXTURES: AtomicUsize = AtomicUsize::new(0);
+        let root = std::env::temp_dir().join(format!(
+            "discmon_pokeapi_{}_{}",
+            std::process::id(),
+            FIXTURES.fetch_add(1, Ordering::Relaxed)
+        ));
+        let resource = |name: &str, path: &str| PokeAPIResource {
+            name: name.to_string(),
+            url: format!("{}/{}/", POKEAPI_URL, path),
+        };
+
+        write_resource(
+            &root,
+            "pokemon-species",
+            PokeAPIPokemonSpeciesCount { count: 3 },
+        );
+        write_resource(&root, "generation", PokeAPIGenerationCount { count: 1 });
+        for (id, name, capture_rate) in SPECIES {
+            write_resource(
+                &root,
+                &format!("pokemon/{}", id),
+                PokeAPIPokemon {
+                    id,
+                    name: name.to_string(),
+                    is_default: true,
+                    species: resource(name, &format!("pokemon-species/{}", id)),
+                    sprites: PokeAPISprites::default(),
+                    ..Default::default()
+                },
+            );
+            write_resource(
+                &root,
+                &format!("pokemon-species/{}", id),
+                PokeAPIPokemonSpecies {
+                    id,
+                    name: name.to_string(),
+                    capture_rate,
+                    evolution_chain: PokeAPIEvolutionchain {
+                        url: format!("{}/evolution-chain/1/", POKEAPI_URL),
+                    },
+                    ..Default::default()
+                },
+            );
+        }
+        write_resource(
+            &root,
+            "evolution-chain/1",
+            PokeAPIPokemonEvolutionChain {
+                chain: PokeAPIChain {
+                    species: resource("bulbasaur", "pokemon-species/1"),
+                    evolves_to: vec![PokeAPIChain {
+                        species: resource("ivysaur", "pokemon-species/2"),
+                        evolves_to: Vec::new(),
+                    }],
+                },
+            },
+        );
+        write_resource(
+            &root,
+            "generation/1",
+            PokeAPIGeneration {
+                id: 1,
+                name: String::from("generation-i"),
+                main_region: resource("kanto", "region/1"),
+                pokemon_species: SPECIES
+                    .iter()
+                    .map(|(id, name, _)| resource(name, &format!("pokemon-species/{}", id)))
+                    .collect(),
+            },
+        );
+
+        root
+    }
+
+    /**
+    A method to get a PokeFinder backed by the memory cache and a fixture's data dump.
+    */
+    fn get_poke_finder(
+        root: &Path,
+        max_entries: usize,
+    ) -> PokeFinder<CacheBackend, LocalHTTPClient> {
+        let config = CacheConfig {
+            backend: String::from("memory"),
+            host: None,
+            namespace: String::from("discmon"),
+            memory_ttl_secs: 60,
+            memory_max_entries: max_entries,
+        };
+        let cache = CacheBackend::new(&config).unwrap();
+        let http_client = LocalHTTPClient::new(root, POKEAPI_URL);
+        PokeFinder::new(RwLock::new(cache), http_client, POKEAPI_URL)
+    }
+
+    #[tokio::test]
+    async fn find_poke_merges_and_caches_the_pokemon() {
+        let root = write_fixtures();
+        let poke_finder = get_poke_finder(&root, 0);
+        poke_finder.connect_to_cache().await.unwrap();
+
+        let poke = poke_finder.find_poke("1").await.unwrap();
+        assert_eq!(poke.name, "bulbasaur");
+        assert_eq!(poke.capture_rate, 45);
+        assert_eq!(poke.evolves_to.len(), 1);
+
+        // The pokemon is cached by its ID and its name, so the data dump isn't read again.
+        fs::remove_dir_all(&root).unwrap();
+        assert_eq!(poke_finder.find_poke("1").await.unwrap().id, 1);
+        assert_eq!(poke_finder.find_poke("bulbasaur").await.unwrap().id, 1);
+        assert!(poke_finder.find_poke("2").await.is_err());
+    }
+
+    #[tokio::test]
+    async fn find_poke_retrieves_the_outdated_pokemons_again() {
+        let root = write_fixtures();
+        let poke_finder = get_poke_finder(&root, 0);
+        let mut outdated_poke = serde_json::to_value(CachedPokemon::default()).unwrap();
+        outdated_poke
+            .as_object_mut()
+            .unwrap()
+            .remove("capture_rate");
+        poke_finder
+            .cache
+            .read()
+            .await
+            .insert_key("1", &outdated_poke.to_string())
+            .await
+            .unwrap();
+
+        let poke = poke_finder.find_poke("1").await.unwrap();
+        assert_eq!(poke.name, "bulbasaur");
+        assert_eq!(poke.capture_rate, 45);
+        let cached_poke = poke_finder.cache.read().await.get_key("1").await.unwrap();
+        let cached_poke: CachedPokemon = serde_json::from_str(&cached_poke.unwrap()).unwrap();
+        assert_eq!(cached_poke.capture_rate, 45);
+        fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[tokio::test]
+    async fn get_spawn_table_is_never_evicted() {
+        let root = write_fixtures();
+        let poke_finder = get_poke_finder(&root, 1);
+
+        let spawn_table = poke_finder.get_spawn_table().await.unwrap();
+        let ids: Vec<u16> = spawn_table.iter().map(|entry| entry.id).collect();
+        assert_eq!(ids, vec![1, 2, 3]);
+        let generations = poke_finder.get_generations().await.unwrap();
+        assert_eq!(generations[0].species_ids, vec![1, 2, 3]);
+
+        // Fill the cache, then check the tables are still cached without the data dump.
+        for id in ["1", "2", "3"] {
+            poke_finder.find_poke(id).await.unwrap();
+        }
+        fs::remove_dir_all(&root).unwrap();
+        assert_eq!(poke_finder.get_spawn_table().await.unwrap().len(), 3);
+        assert_eq!(poke_finder.get_generations().await.unwrap().len(), 1);
+        assert_eq!(poke_finder.get_poke_count().await.unwrap(), 3);
+    }
+
+    #[tokio::test]
+    async fn get_spawn_table_skips_the_species_that_cant_be_retrieved() {
+        let root = write_fixtures();
+        fs::remove_dir_all(root.join("pokemon-species/2")).unwrap();
+        let poke_finder = get_poke_finder(&root, 0);
+
+        let spawn_table = poke_finder.get_spawn_table().await.unwrap();
+        let ids: Vec<u16> = spawn_table.iter().map(|entry| entry.id).collect();
+        assert_eq!(ids, vec![1, 3]);
+        fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[tokio::test]
+    async fn get_spawn_table_rebuilds_an_outdated_table() {
+        let root = write_fixtures();
+        let poke_finder = get_poke_finder(&root, 0);
+        poke_finder
+            .cache
+            .read()
+            .await
+            .insert_persistent_key("spawn_table", "[{\"id\":1}]", SPAWN_TABLE_TTL)
+            .await
+            .unwrap();
+
+        let spawn_table = poke_finder.get_spawn_table().await.unwrap();
+        assert_eq!(spawn_table.len(), 3);
+        assert_eq!(spawn_table[0].name, "bulbasaur");
+        let cached_table = poke_finder.cache.read().await.get_key("spawn_table").await;
+        let cached_table: Vec<CachedSpawnEntry> =
+            serde_json::from_str(&cached_table.unwrap().unwrap()).unwrap();
+        assert_eq!(cached_table.len(), 3);
+        fs::remove_dir_all(&root).unwrap();
+    }
+}
@@ -0,0 +1,111 @@
+// Libs
+use once_cell::sync::Lazy;
+use std::future::Future;
+use surrealdb::sql::Id;
+use tokio::{
+    runtime::{Builder, Runtime},
+    sync::OnceCell,
+};
+
+use crate::{services::DATABASE_SERVICE, utils::config::Config};
+
+// Constants
+/**
+The configuration of the tests. The database and the cache run in memory.
+The environment variables don't override it.
+*/
+pub const TEST_CONFIG: &str = r#"
+[bot]
+poke_flee_time_secs = 60
+poke_spawn_rate = 20
+poke_shiny_rate = 4096
+pokeball_emoji_id = "<:pokeball:1251387543090626623>"
+pokedex_pokes_per_page = 6
+pokedex_timeout_secs = 180
+
+[database]
+engine = "memory"
+namespace = "discmon"
+name = "discmon"
+
+[discord]
+token = "DISCORD_TOKEN"
+
+[pokeapi]
+source = "remote"
+url = "https://pokeapi.co/api/v2"
+
+[cache]
+backend = "memory"
+namespace = "discmon"
+"#;
+
+/**
+The values that would alter a query if they were formatted into it instead of bound to it.
+*/
+pub const HOSTILE_IDS: [&str; 4] = [
+    "x' OR 1=1 --",
+    "\"; DELETE trainer;",
+    "'; DELETE pokemon; --",
+    "1\" OR discord_id != \"",
+];
+
+// Data
+/**
+The runtime shared by the tests, so the in-memory database outlives each test.
+*/
+static RUNTIME: Lazy<Runtime> = Lazy::new(|| {
+    Builder::new_multi_thread()
+        .enable_all()
+        .build()
+        .expect("The test runtime couldn't be built.")
+});
+
+/**
+If the test database is connected and migrated.
+*/
+static DATABASE_READY: OnceCell<()> = OnceCell::const_new();
+
+// Functions
+/**
+A method to run an async test against the services, which are initialized the first time.
+
+The tests share the same in-memory database, so each test must use its own records.
+
+## Parameters:
+- `test`: The test to run.
+*/
+pub fn run_test<F: Future>(test: F) -> F::Output {
+    RUNTIME.block_on(async {
+        DATABASE_READY
+            .get_or_init(|| async {
+                let table = TEST_CONFIG
+                    .parse()
+                    .expect("The test configuration is invalid.");
+                Config::init(
+                    Config::from_table_without_env(table)
+                        .expect("The test configuration is invalid."),
+                );
+
+                let db_svc = DATABASE_SERVICE.clone();
+                db_svc
+                    .connect()
+                    .await
+                    .expect("The test database couldn't connect.");
+                db_svc
+                    .run_migrations()
+                    .await
+                    .expect("The test database couldn't be migrated.");
+            })
+            .await;
+
+        test.await
+    })
+}
+
+/**
+A method to get a new unique ID, to keep the records of each test apart.
+*/
+pub fn unique_id() -> String {
+    Id::ulid().to_string()
+}
@@ -0,0 +1,754 @@
+// Libs
+use once_cell::sync::OnceCell;
+use serenity::all::{parse_emoji, EmojiIdentifier};
+use std::{collections::HashMap, path::Path, str::FromStr};
+
+use super::spawn_model::SpawnModel;
+use crate::errors::{ConfigError, ConfigResult};
+
+// Data
+static CONFIG: OnceCell<Config> = OnceCell::new();
+
+// Config
+/**
+A struct to represent the bot's configuration.
+
+It's loaded from a TOML file and each key can be overridden by an environment variable named
+`<SECTION>_<KEY>` in uppercase. e.g. `bot.poke_spawn_rate` can be overridden by `BOT_POKE_SPAWN_RATE`.
+*/
+#[derive(Clone, Debug)]
+pub struct Config {
+    pub bot: BotConfig,
+    pub database: DatabaseConfig,
+    pub discord: DiscordConfig,
+    pub pokeapi: PokeAPIConfig,
+    pub cache: CacheConfig,
+}
+
+/**
+A struct to represent the `bot` section of the configuration.
+*/
+#[derive(Clone, Debug)]
+pub struct BotConfig {
+    pub poke_flee_time_secs: u64,
+    pub poke_spawn_rate: u64,
+    pub poke_spawn_model: SpawnModel,
+    pub poke_spawns_per_hour: u64,
+    pub poke_activity_window_secs: u64,
+    pub poke_shiny_rate: u64,
+    pub pokeball_emoji: EmojiIdentifier,
+    pub pokedex_pokes_per_page: u16,
+    pub pokedex_timeout_secs: u64,
+    pub poke_follow_up_msg: bool,
+    pub poke_spawn_cooldown_secs: u64,
+    pub poke_max_spawns_per_channel: u64,
+    pub poke_max_spawns_per_guild: u64,
+    pub msg_min_length: u64,
+    pub msg_duplicate_window_secs: u64,
+    pub msg_user_cooldown_secs: u64,
+    pub msg_min_distinct_authors: u64,
+    pub msg_distinct_authors_window_secs: u64,
+    pub spawn_weight_common: u64,
+    pub spawn_weight_baby: u64,
+    pub spawn_weight_legendary: u64,
+    pub spawn_weight_mythical: u64,
+    pub spawn_weight_by_capture_rate: bool,
+    pub catch_attempt_cooldown_secs: u64,
+    pub starting_poke_balls: u64,
+    pub starting_great_balls: u64,
+    pub starting_ultra_balls: u64,
+    pub starting_master_balls: u64,
+    pub catch_reward: u64,
+    pub new_species_bonus: u64,
+    pub shiny_catch_bonus: u64,
+    pub poke_ball_price: u64,
+    pub great_ball_price: u64,
+    pub ultra_ball_price: u64,
+    pub master_ball_price: u64,
+    pub daily_reward: u64,
+    pub daily_streak_bonus: u64,
+    pub daily_max_streak_days: u64,
+    pub daily_poke_balls: u64,
+    pub trade_timeout_secs: u64,
+}
+
+/**
+A struct to represent the `database` section of the configuration.
+*/
+#[derive(Clone, Debug)]
+pub struct DatabaseConfig {
+    pub engine: String,
+    pub host: Option<String>,
+    pub path: Option<String>,
+    pub namespace: String,
+    pub name: String,
+    pub user: Option<String>,
+    pub pass: Option<String>,
+}
+
+/**
+A struct to represent the `discord` section of the configuration.
+*/
+#[derive(Clone, Debug)]
+pub struct DiscordConfig {
+    pub token: String,
+}
+
+/**
+A struct to represent the `pokeapi` section of the configuration.
+*/
+#[derive(Clone, Debug)]
+pub struct PokeAPIConfig {
+    pub source: String,
+    pub url: String,
+    pub data_dir: Option<String>,
+}
+
+/**
+A struct to represent the `cache` section of the configuration.
+*/
+#[derive(Clone, Debug)]
+pub struct CacheConfig {
+    pub backend: String,
+    pub host: Option<String>,
+    pub namespace: String,
+    pub memory_ttl_secs: u64,
+    pub memory_max_entries: usize,
+}
+
+impl Config {
+    /**
+    A method to load the configuration.
+
+    The file is read from the path in the `DISCMON_CONFIG` environment variable, or `config.toml`
+    if it's not defined. The file is optional when `DISCMON_CONFIG` is not defined.
+    All the keys are validated and every invalid key is reported.
+    */
+    pub fn load() -> ConfigResult<Self> {
+        Self::from_table(Self::read_table()?)
+    }
+
+    /**
+    A method to load only the `pokeapi` section of the configuration.
+
+    It's used by the commands that don't start the bot, so they don't require the other sections.
+    */
+    pub fn load_pokeapi() -> ConfigResult<PokeAPIConfig> {
+        let mut source = ConfigSource::new(Self::read_table()?);
+        match PokeAPIConfig::load(&mut source) {
+            Some(pokeapi) if source.errors.is_empty() => Ok(pokeapi),
+            _ => Err(ConfigError::InvalidKeys(source.errors)),
+        }
+    }
+
+    /**
+    A method to read the configuration's file.
+
+    The file is read from the path in the `DISCMON_CONFIG` environment variable, or `config.toml`
+    if it's not defined. The file is optional when `DISCMON_CONFIG` is not defined.
+    */
+    fn read_table() -> ConfigResult<toml::Table> {
+        let table = match std::env::var("DISCMON_CONFIG") {
+            Ok(path) => std::fs::read_to_string(path)?.parse::<toml::Table>()?,
+            Err(_) if Path::new("config.toml").exists() => {
+                std::fs::read_to_string("config.toml")?.parse::<toml::Table>()?
+            }
+            Err(_) => toml::Table::new(),
+        };
+
+        Ok(table)
+    }
+
+    /**
+    A method to load the configuration from a TOML table.
+
+    The environment variables still override the table's keys.
+    All the keys are validated and every invalid key is reported.
+
+    ## Parameters:
+    - `table`: The TOML table with a table for each section.
+    */
+    pub fn from_table(table: toml::Table) -> ConfigResult<Self> {
+        Self::from_source(ConfigSource::new(table))
+    }
+
+    /**
+    A method to load the configuration from a TOML table, ignoring the environment variables.
+
+    It's used by the tests, so the environment can't change their configuration.
+
+    ## Parameters:
+    - `table`: The TOML table with a table for each section.
+    */
+    #[cfg(test)]
+    pub fn from_table_without_env(table: toml::Table) -> ConfigResult<Self> {
+        Self::from_source(ConfigSource::without_env(table))
+    }
+
+    /**
+    A method to load the configuration from a source.
+    All the keys are validated and every invalid key is reported.
+
+    ## Parameters:
+    - `source`: The source of the configuration values.
+    */
+    fn from_source(mut source: ConfigSource) -> ConfigResult<Self> {
+        let bot = BotConfig::load(&mut source);
+        let database = DatabaseConfig::load(&mut source);
+        let discord = DiscordConfig::load(&mut source);
+        let pokeapi = PokeAPIConfig::load(&mut source);
+        let cache = CacheConfig::load(&mut source);
+
+        match (bot, database, discord, pokeapi, cache) {
+            (Some(bot), Some(database), Some(discord), Some(pokeapi), Some(cache))
+                if source.errors.is_empty() =>
+            {
+                Ok(Self {
+                    bot,
+                    database,
+                    discord,
+                    pokeapi,
+                    cache,
+                })
+            }
+            _ => Err(ConfigError::InvalidKeys(source.errors)),
+        }
+    }
+
+    /**
+    A method to set the global configuration. It can only be set once.
+
+    ## Parameters:
+    - `config`: The configuration to set.
+    */
+    pub fn init(config: Self) {
+        let _ = CONFIG.set(config);
+    }
+
+    /**
+    A method to get the global configuration.
+
+    It fails if the configuration wasn't initialized with `Config::init`.
+    */
+    pub fn global() -> ConfigResult<&'static Self> {
+        CONFIG.get().ok_or(ConfigError::NotInitialized)
+    }
+}
+
+impl BotConfig {
+    /**
+    A method to load the `bot` section.
+
+    ## Parameters:
+    - `source`: The source of the configuration values.
+    */
+    fn load(source: &mut ConfigSource) -> Option<Self> {
+        let poke_flee_time_secs = source.get_required("bot.poke_flee_time_secs", parse_positive);
+        let poke_spawn_rate = source.get_required("bot.poke_spawn_rate", parse_positive);
+        let poke_spawn_model = source.get_optional("bot.poke_spawn_model", SpawnModel::parse);
+        let poke_spawns_per_hour = source.get_optional("bot.poke_spawns_per_hour", parse_positive);
+        let poke_activity_window_secs =
+            source.get_optional("bot.poke_activity_window_secs", parse_positive);
+        let poke_shiny_rate = source.get_required("bot.poke_shiny_rate", parse_positive);
+        let pokeball_emoji = source.get_required("bot.pokeball_emoji_id", |value| {
+            parse_emoji(value).ok_or_else(|| String::from("must be a valid custom emoji"))
+        });
+        let pokedex_pokes_per_page =
+            source.get_required("bot.pokedex_pokes_per_page", parse_positive);
+        let pokedex_timeout_secs = source.get_required("bot.pokedex_timeout_secs", parse_positive);
+        let poke_follow_up_msg = source.get_optional("bot.poke_follow_up_msg", parse_value);
+        let poke_spawn_cooldown_secs =
+            source.get_optional("bot.poke_spawn_cooldown_secs", parse_value);
+        let poke_max_spawns_per_channel =
+            source.get_optional("bot.poke_max_spawns_per_channel", parse_value);
+        let poke_max_spawns_per_guild =
+            source.get_optional("bot.poke_max_spawns_per_guild", parse_value);
+        let msg_min_length = source.get_optional("bot.msg_min_length", parse_value);
+        let msg_duplicate_window_secs =
+            source.get_optional("bot.msg_duplicate_window_secs", parse_value);
+        let msg_user_cooldown_secs = source.get_optional("bot.msg_user_cooldown_secs", parse_value);
+        let msg_min_distinct_authors =
+            source.get_optional("bot.msg_min_distinct_authors", parse_value);
+        let msg_distinct_authors_window_secs =
+            source.get_optional("bot.msg_distinct_authors_window_secs", parse_value);
+        let spawn_weight_common = source.get_optional("bot.spawn_weight_common", parse_value);
+        let spawn_weight_baby = source.get_optional("bot.spawn_weight_baby", parse_value);
+        let spawn_weight_legendary = source.get_optional("bot.spawn_weight_legendary", parse_value);
+        let spawn_weight_mythical = source.get_optional("bot.spawn_weight_mythical", parse_value);
+        let spawn_weight_by_capture_rate =
+            source.get_optional("bot.spawn_weight_by_capture_rate", parse_value);
+        let catch_attempt_cooldown_secs =
+            source.get_optional("bot.catch_attempt_cooldown_secs", parse_value);
+        let starting_poke_balls = source.get_optional("bot.starting_poke_balls", parse_value);
+        let starting_great_balls = source.get_optional("bot.starting_great_balls", parse_value);
+        let starting_ultra_balls = source.get_optional("bot.starting_ultra_balls", parse_value);
+        let starting_master_balls = source.get_optional("bot.starting_master_balls", parse_value);
+        let catch_reward = source.get_optional("bot.catch_reward", parse_value);
+        let new_species_bonus = source.get_optional("bot.new_species_bonus", parse_value);
+        let shiny_catch_bonus = source.get_optional("bot.shiny_catch_bonus", parse_value);
+        let poke_ball_price = source.get_optional("bot.poke_ball_price", parse_value);
+        let great_ball_price = source.get_optional("bot.great_ball_price", parse_value);
+        let ultra_ball_price = source.get_optional("bot.ultra_ball_price", parse_value);
+        let master_ball_price = source.get_optional("bot.master_ball_price", parse_value);
+        let daily_reward = source.get_optional("bot.daily_reward", parse_value);
+        let daily_streak_bonus = source.get_optional("bot.daily_streak_bonus", parse_value);
+        let daily_max_streak_days = source.get_optional("bot.daily_max_streak_days", parse_value);
+        let daily_poke_balls = source.get_optional("bot.daily_poke_balls", parse_value);
+        let trade_timeout_secs = source.get_optional("bot.trade_timeout_secs", parse_positive);
+
+        Some(Self {
+            poke_flee_time_secs: poke_flee_time_secs?,
+            poke_spawn_rate: poke_spawn_rate?,
+            poke_spawn_model: poke_spawn_model.unwrap_or(SpawnModel::Message),
+            poke_spawns_per_hour: poke_spawns_per_hour.unwrap_or(4),
+            poke_activity_window_secs: poke_activity_window_secs.unwrap_or(900),
+            poke_shiny_rate: poke_shiny_rate?,
+            pokeball_emoji: pokeball_emoji?,
+            pokedex_pokes_per_page: pokedex_pokes_per_page?,
+            pokedex_timeout_secs: pokedex_timeout_secs?,
+            poke_follow_up_msg: poke_follow_up_msg.unwrap_or(false),
+            poke_spawn_cooldown_secs: poke_spawn_cooldown_secs.unwrap_or(0),
+            poke_max_spawns_per_channel: poke_max_spawns_per_channel.unwrap_or(1),
+            poke_max_spawns_per_guild: poke_max_spawns_per_guild.unwrap_or(0),
+            msg_min_length: msg_min_length.unwrap_or(0),
+            msg_duplicate_window_secs: msg_duplicate_window_secs.unwrap_or(0),
+            msg_user_cooldown_secs: msg_user_cooldown_secs.unwrap_or(0),
+            msg_min_distinct_authors: msg_min_distinct_authors.unwrap_or(0),
+            msg_distinct_authors_window_secs: msg_distinct_authors_window_secs.unwrap_or(0),
+            spawn_weight_common: spawn_weight_common.unwrap_or(100),
+            spawn_weight_baby: spawn_weight_baby.unwrap_or(50),
+            spawn_weight_legendary: spawn_weight_legendary.unwrap_or(10),
+            spawn_weight_mythical: spawn_weight_mythical.unwrap_or(5),
+            spawn_weight_by_capture_rate: spawn_weight_by_capture_rate.unwrap_or(true),
+            catch_attempt_cooldown_secs: catch_attempt_cooldown_secs.unwrap_or(3),
+            starting_poke_balls: starting_poke_balls.unwrap_or(20),
+            starting_great_balls: starting_great_balls.unwrap_or(5),
+            starting_ultra_balls: starting_ultra_balls.unwrap_or(1),
+            starting_master_balls: starting_master_balls.unwrap_or(0),
+            catch_reward: catch_reward.unwrap_or(25),
+            new_species_bonus: new_species_bonus.unwrap_or(50),
+            shiny_catch_bonus: shiny_catch_bonus.unwrap_or(250),
+            poke_ball_price: poke_ball_price.unwrap_or(5),
+            great_ball_price: great_ball_price.unwrap_or(15),
+            ultra_ball_price: ultra_ball_price.unwrap_or(30),
+            master_ball_price: master_ball_price.unwrap_or(1000),
+            daily_reward: daily_reward.unwrap_or(50),
+            daily_streak_bonus: daily_streak_bonus.unwrap_or(10),
+            daily_max_streak_days: daily_max_streak_days.unwrap_or(7),
+            daily_poke_balls: daily_poke_balls.unwrap_or(5),
+            trade_timeout_secs: trade_timeout_secs.unwrap_or(120),
+        })
+    }
+}
+
+impl DatabaseConfig {
+    /**
+    A method to load the `database` section.
+
+    ## Parameters:
+    - `source`: The source of the configuration values.
+    */
+    fn load(source: &mut ConfigSource) -> Option<Self> {
+        let engine = source.get_required("database.engine", |value| {
+            parse_choice(value, &["remote", "memory", "file"])
+        });
+        let host = source.get_optional("database.host", parse_value);
+        let path = source.get_optional("database.path", parse_value);
+        let namespace = source.get_required("database.namespace", parse_value);
+        let name = source.get_required("database.name", parse_value);
+        let user = source.get_optional("database.user", parse_value);
+        let pass = source.get_optional("database.pass", parse_value);
+
+        let is_remote = engine.as_deref() == Some("remote");
+        source.require_if(is_remote, "database.host", host.is_some(), "remote engine");
+        source.require_if(is_remote, "database.user", user.is_some(), "remote engine");
+        source.require_if(is_remote, "database.pass", pass.is_some(), "remote engine");
+        let is_file = engine.as_deref() == Some("file");
+        source.require_if(is_file, "database.path", path.is_some(), "file engine");
+
+        Some(Self {
+            engine: engine?,
+            host,
+            path,
+            namespace: namespace?,
+            name: name?,
+            user,
+            pass,
+        })
+    }
+}
+
+impl DiscordConfig {
+    /**
+    A method to load the `discord` section.
+
+    ## Parameters:
+    - `source`: The source of the configuration values.
+    */
+    fn load(source: &mut ConfigSource) -> Option<Self> {
+        let token = source.get_required("discord.token", parse_value);
+        Some(Self { token: token? })
+    }
+}
+
+impl PokeAPIConfig {
+    /**
+    A method to load the `pokeapi` section.
+
+    ## Parameters:
+    - `source`: The source of the configuration values.
+    */
+    fn load(source: &mut ConfigSource) -> Option<Self> {
+        let pokeapi_source = source.get_required("pokeapi.source", |value| {
+            parse_choice(value, &["remote", "local"])
+        });
+        let url = source.get_required("pokeapi.url", parse_value);
+        let data_dir = source.get_optional("pokeapi.data_dir", parse_value);
+
+        let is_local = pokeapi_source.as_deref() == Some("local");
+        source.require_if(
+            is_local,
+            "pokeapi.data_dir",
+            data_dir.is_some(),
+            "local source",
+        );
+
+        Some(Self {
+            source: pokeapi_source?,
+            url: url?,
+            data_dir,
+        })
+    }
+}
+
+impl CacheConfig {
+    /**
+    A method to load the `cache` section.
+
+    ## Parameters:
+    - `source`: The source of the configuration values.
+    */
+    fn load(source: &mut ConfigSource) -> Option<Self> {
+        let backend = source.get_required("cache.backend", |value| {
+            parse_choice(value, &["redis", "memory"])
+        });
+        let host = source.get_optional("cache.host", parse_value);
+        let namespace = source.get_required("cache.namespace", parse_value);
+        let memory_ttl_secs = source.get_optional("cache.memory_ttl_secs", parse_value);
+        let memory_max_entries = source.get_optional("cache.memory_max_entries", parse_value);
+
+        let is_redis = backend.as_deref() == Some("redis");
+        source.require_if(is_redis, "cache.host", host.is_some(), "redis backend");
+
+        Some(Self {
+            backend: backend?,
+            host,
+            namespace: namespace?,
+            memory_ttl_secs: memory_ttl_secs.unwrap_or(0),
+            memory_max_entries: memory_max_entries.unwrap_or(0),
+        })
+    }
+}
+
+// Config Source
+/**
+A struct to read the raw configuration values and collect the errors found while parsing them.
+*/
+struct ConfigSource {
+    values: HashMap<String, String>,
+    errors: Vec<String>,
+    read_env: bool,
+}
+
+impl ConfigSource {
+    /**
+    A method to create a new ConfigSource from a TOML table.
+
+    ## Parameters:
+    - `table`: The TOML table. Each section is flattened into `<section>.<key>` keys.
+    */
+    fn new(table: toml::Table) -> Self {
+        let mut values = HashMap::new();
+        for (section, section_values) in table {
+            let toml::Value::Table(section_values) = section_values else {
+                continue;
+            };
+
+            for (key, value) in section_values {
+                let value = match value {
+                    toml::Value::String(value) => value,
+                    value => value.to_string(),
+                };
+                values.insert(format!("{}.{}", section, key), value);
+            }
+        }
+
+        Self {
+            values,
+            errors: Vec::new(),
+            read_env: true,
+        }
+    }
+
+    /**
+    A method to create a new ConfigSource from a TOML table that ignores the environment variables.
+
+    ## Parameters:
+    - `table`: The TOML table. Each section is flattened into `<section>.<key>` keys.
+    */
+    #[cfg(test)]
+    fn without_env(table: toml::Table) -> Self {
+        Self {
+            read_env: false,
+            ..Self::new(table)
+        }
+    }
+
+    /**
+    A method to get the name of the environment variable that overrides a key.
+
+    ## Parameters:
+    - `key`: The key in the `<section>.<key>` format.
+    */
+    fn env_key(key: &str) -> String {
+        key.replace('.', "_").to_uppercase()
+    }
+
+    /**
+    A method to get and parse an optional value. If the value can't be parsed, an error is collected.
+
+    ## Parameters:
+    - `key`: The key in the `<section>.<key>` format.
+    - `parser`: The function to parse the raw value.
+    */
+    fn get_optional<T>(
+        &mut self,
+        key: &str,
+        parser: impl Fn(&str) -> Result<T, String>,
+    ) -> Option<T> {
+        let env_key = Self::env_key(key);
+        let value = std::env::var(&env_key)
+            .ok()
+            .filter(|_| self.read_env)
+            .or_else(|| self.values.get(key).cloned())?;
+
+        match parser(&value) {
+            Ok(value) => Some(value),
+            Err(e) => {
+                self.errors
+                    .push(format!("'{}' ({}) {}. Got '{}'.", key, env_key, e, value));
+                None
+            }
+        }
+    }
+
+    /**
+    A method to get and parse a required value. If the value is missing or can't be parsed, an error is collected.
+
+    ## Parameters:
+    - `key`: The key in the `<section>.<key>` format.
+    - `parser`: The function to parse the raw value.
+    */
+    fn get_required<T>(
+        &mut self,
+        key: &str,
+        parser: impl Fn(&str) -> Result<T, String>,
+    ) -> Option<T> {
+        let errors_len = self.errors.len();
+        let value = self.get_optional(key, parser);
+        if value.is_none() && errors_len == self.errors.len() {
+            self.errors.push(format!(
+                "'{}' ({}) is not defined.",
+                key,
+                Self::env_key(key)
+            ));
+        }
+
+        value
+    }
+
+    /**
+    A method to collect an error if a conditionally required value is missing.
+
+    ## Parameters:
+    - `condition`: If the value is required.
+    - `key`: The key in the `<section>.<key>` format.
+    - `is_defined`: If the value is defined.
+    - `reason`: What requires the value.
+    */
+    fn require_if(&mut self, condition: bool, key: &str, is_defined: bool, reason: &str) {
+        if condition && !is_defined {
+            self.errors.push(format!(
+                "'{}' ({}) is required by the {}.",
+                key,
+                Self::env_key(key),
+                reason
+            ));
+        }
+    }
+}
+
+// Functions
+/**
+A method to parse a raw configuration value.
+
+## Parameters:
+- `value`: The raw value.
+*/
+pub fn parse_value<T>(value: &str) -> Result<T, String>
+where
+    T: FromStr,
+{
+    value
+        .parse()
+        .map_err(|_| format!("must be a valid {}", std::any::type_name::<T>()))
+}
+
+/**
+A method to parse a raw configuration value that must be greater than zero.
+
+## Parameters:
+- `value`: The raw value.
+*/
+pub fn parse_positive<T>(value: &str) -> Result<T, String>
+where
+    T: FromStr + PartialOrd + Default,
+{
+    let value: T = parse_value(value)?;
+    if value <= T::default() {
+        return Err(String::from("must be greater than 0"));
+    }
+
+    Ok(value)
+}
+
+/**
+A method to parse a raw configuration value that must be one of the available choices.
+
+## Parameters:
+- `value`: The raw value.
+- `choices`: The available choices.
+*/
+pub fn parse_choice(value: &str, choices: &[&str]) -> Result<String, String> {
+    let value = value.to_lowercase();
+    if !choices.contains(&value.as_str()) {
+        return Err(format!("must be one of {:?}", choices));
+    }
+
+    Ok(value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::TEST_CONFIG;
+
+    /**
+    A method to get the test configuration's table with some values replaced.
+
+    ## Parameters:
+    - `values`: The `<section>.<key>` keys and their TOML values.
+    */
+    fn get_table(values: &[(&str, toml::Value)]) -> toml::Table {
+        let mut table: toml::Table = TEST_CONFIG.parse().unwrap();
+        for (key, value) in values {
+            let (section, key) = key.split_once('.').unwrap();
+            let section = table
+                .entry(section)
+                .or_insert_with(|| toml::Value::Table(toml::Table::new()));
+            section
+                .as_table_mut()
+                .unwrap()
+                .insert(key.to_string(), value.clone());
+        }
+
+        table
+    }
+
+    /**
+    A method to get the errors found while loading a configuration.
+
+    ## Parameters:
+    - `table`: The TOML table with a table for each section.
+    */
+    fn get_errors(table: toml::Table) -> Vec<String> {
+        match Config::from_table_without_env(table) {
+            Ok(_) => Vec::new(),
+            Err(ConfigError::InvalidKeys(errors)) => errors,
+            Err(e) => panic!("Unexpected error: {}", e),
+        }
+    }
+
+    #[test]
+    fn from_table_loads_the_test_configuration() {
+        let config = Config::from_table_without_env(get_table(&[])).unwrap();
+        assert_eq!(config.bot.poke_spawn_rate, 20);
+        assert_eq!(config.database.engine, "memory");
+        assert_eq!(config.cache.backend, "memory");
+    }
+
+    #[test]
+    fn from_table_reports_every_invalid_key() {
+        let mut table = get_table(&[
+            ("bot.poke_shiny_rate", toml::Value::from("often")),
+            ("database.engine", toml::Value::from("sqlite")),
+        ]);
+        table.remove("discord");
+
+        let errors = get_errors(table);
+        assert_eq!(errors.len(), 3, "{:?}", errors);
+        assert!(errors[0].starts_with("'bot.poke_shiny_rate' (BOT_POKE_SHINY_RATE) must be"));
+        assert!(errors[1].starts_with("'database.engine' (DATABASE_ENGINE) must be one of"));
+        assert_eq!(errors[2], "'discord.token' (DISCORD_TOKEN) is not defined.");
+    }
+
+    #[test]
+    fn from_table_rejects_the_values_that_must_be_positive() {
+        let cases = [(0, "must be greater than 0"), (-1, "must be a valid u64")];
+        for (value, reason) in cases {
+            let table = get_table(&[("bot.poke_spawn_rate", toml::Value::from(value))]);
+            assert_eq!(
+                get_errors(table),
+                vec![format!(
+                    "'bot.poke_spawn_rate' (BOT_POKE_SPAWN_RATE) {}. Got '{}'.",
+                    reason, value
+                )]
+            );
+        }
+    }
+
+    #[test]
+    fn from_table_requires_the_redis_host_only_for_the_redis_backend() {
+        let errors = get_errors(get_table(&[("cache.backend", toml::Value::from("redis"))]));
+        assert_eq!(
+            errors,
+            vec!["'cache.host' (CACHE_HOST) is required by the redis backend."]
+        );
+
+        let errors = get_errors(get_table(&[
+            ("cache.backend", toml::Value::from("redis")),
+            ("cache.host", toml::Value::from("localhost:6379")),
+        ]));
+        assert!(errors.is_empty(), "{:?}", errors);
+    }
+
+    #[test]
+    fn config_source_overrides_the_table_with_the_environment() {
+        // The key is only read by this test, so the variable can't leak into the other tests.
+        std::env::set_var("TEST_ENV_OVERRIDE", "2");
+        let table = get_table(&[("test.env_override", toml::Value::from(1))]);
+
+        let mut source = ConfigSource::new(table.clone());
+        assert_eq!(
+            source.get_optional("test.env_override", parse_value),
+            Some(2)
+        );
+
+        let mut source = ConfigSource::without_env(table);
+        assert_eq!(
+            source.get_optional("test.env_override", parse_value),
+            Some(1)
+        );
+        assert!(source.errors.is_empty());
+    }
+}
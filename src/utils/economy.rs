@@ -0,0 +1,92 @@
+// Libs
+use super::config::BotConfig;
+use crate::models::BallType;
+
+// Functions
+/**
+A method to get the coins earned by a trainer for catching a pokemon.
+
+## Parameters:
+- `config`: The bot's configuration.
+- `is_shiny`: If the pokemon is shiny.
+- `is_new_species`: If it's the first pokemon of its species caught by the trainer.
+*/
+pub fn get_catch_reward(config: &BotConfig, is_shiny: bool, is_new_species: bool) -> u64 {
+    let mut reward = config.catch_reward;
+    if is_shiny {
+        reward += config.shiny_catch_bonus;
+    }
+    if is_new_species {
+        reward += config.new_species_bonus;
+    }
+
+    reward
+}
+
+/**
+A method to get the coins given to a trainer for claiming their daily reward.
+
+Every consecutive day adds the streak bonus, up to `bot.daily_max_streak_days` days.
+
+## Parameters:
+- `config`: The bot's configuration.
+- `streak`: The amount of consecutive days the trainer claimed the reward, including today.
+*/
+pub fn get_daily_reward(config: &BotConfig, streak: u32) -> u64 {
+    let bonus_days = u64::from(streak).clamp(1, config.daily_max_streak_days.max(1)) - 1;
+    config.daily_reward + config.daily_streak_bonus * bonus_days
+}
+
+/**
+A method to get the price of a ball in the shop.
+
+## Parameters:
+- `config`: The bot's configuration.
+- `ball`: The type of the ball.
+
+## Returns:
+- The price of the ball, or `None` if the ball isn't sold.
+*/
+pub fn get_ball_price(config: &BotConfig, ball: BallType) -> Option<u64> {
+    let price = match ball {
+        BallType::Poke => config.poke_ball_price,
+        BallType::Great => config.great_ball_price,
+        BallType::Ultra => config.ultra_ball_price,
+        BallType::Master => config.master_ball_price,
+    };
+
+    match price {
+        0 => None,
+        price => Some(price),
+    }
+}
+
+/**
+A method to get the coins needed to buy an amount of a ball.
+
+## Parameters:
+- `price`: The price of the ball.
+- `quantity`: The amount of balls.
+
+## Returns:
+- The cost of the purchase, or `None` if it's too high to be recorded in the ledger.
+*/
+pub fn get_purchase_cost(price: u64, quantity: u64) -> Option<u64> {
+    price
+        .checked_mul(quantity)
+        .filter(|cost| i64::try_from(*cost).is_ok())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn get_purchase_cost_rejects_the_overflowing_costs() {
+        assert_eq!(get_purchase_cost(15, 100), Some(1500));
+        assert_eq!(get_purchase_cost(i64::MAX as u64, 1), Some(i64::MAX as u64));
+        assert_eq!(get_purchase_cost(i64::MAX as u64 + 1, 1), None);
+        assert_eq!(get_purchase_cost(u64::MAX / 2, 3), None);
+        assert_eq!(get_purchase_cost(u64::MAX, 100), None);
+    }
+}
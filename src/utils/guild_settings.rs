@@ -0,0 +1,377 @@
+use once_cell::sync::Lazy;
+use serenity::{
+    all::{ChannelId, EmojiIdentifier},
+    utils::parse_emoji,
+};
+use std::{collections::HashMap, sync::Mutex};
+use tracing::info;
+
+use crate::{
+    errors::DatabaseResult,
+    models::DBGuildSettings,
+    utils::{config::BotConfig, spawn_model::SpawnModel},
+};
+
+// Data
+/**
+The effective settings of the guilds, shared by every handler.
+*/
+pub static GUILD_SETTINGS_CACHE: Lazy<GuildSettingsCache> = Lazy::new(GuildSettingsCache::default);
+
+// Spawn Channel Mode
+/**
+An enum to represent which channels of a guild can spawn pokémons.
+*/
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum SpawnChannelMode {
+    /// Every channel can spawn pokémons.
+    All,
+    /// Only the spawn channels can spawn pokémons.
+    Allow,
+    /// Every channel but the spawn channels can spawn pokémons.
+    Deny,
+}
+
+impl SpawnChannelMode {
+    /**
+    A method to parse the spawn channel mode stored in the database.
+
+    ## Parameters:
+    - `mode`: The stored mode. `None` or an unknown mode means every channel.
+    */
+    pub fn from_db(mode: Option<&str>) -> Self {
+        match mode {
+            Some("allow") => Self::Allow,
+            Some("deny") => Self::Deny,
+            _ => Self::All,
+        }
+    }
+
+    /**
+    A method to get the mode to be stored in the database.
+    */
+    pub fn to_db(self) -> Option<String> {
+        match self {
+            Self::All => None,
+            Self::Allow => Some(String::from("allow")),
+            Self::Deny => Some(String::from("deny")),
+        }
+    }
+}
+
+// Guild Settings
+/**
+A struct to represent the effective settings of a guild.
+
+Each setting is the guild's override, or the `bot` configuration if it wasn't overridden.
+*/
+#[derive(Clone, Debug)]
+pub struct GuildSettings {
+    pub poke_spawn_rate: u64,
+    pub poke_spawn_model: SpawnModel,
+    pub poke_spawns_per_hour: u64,
+    pub poke_shiny_rate: u64,
+    pub poke_flee_time_secs: u64,
+    pub pokeball_emoji: EmojiIdentifier,
+    pub poke_follow_up_msg: bool,
+    pub poke_spawn_cooldown_secs: u64,
+    pub poke_max_spawns_per_channel: u64,
+    pub poke_max_spawns_per_guild: u64,
+    pub msg_min_length: u64,
+    pub msg_duplicate_window_secs: u64,
+    pub msg_user_cooldown_secs: u64,
+    pub msg_min_distinct_authors: u64,
+    pub msg_distinct_authors_window_secs: u64,
+    pub spawn_channel_mode: SpawnChannelMode,
+    pub spawn_channel_ids: Vec<ChannelId>,
+    pub wild_area_channel_id: Option<ChannelId>,
+    pub spawn_generations: Vec<u8>,
+}
+
+impl GuildSettings {
+    /**
+    A method to create the effective settings of a guild.
+
+    ## Parameters:
+    - `config`: The bot's configuration.
+    - `overrides`: The guild's settings in the database, if any.
+    */
+    pub fn new(config: &BotConfig, overrides: Option<&DBGuildSettings>) -> Self {
+        let mut settings = Self {
+            poke_spawn_rate: config.poke_spawn_rate,
+            poke_spawn_model: config.poke_spawn_model,
+            poke_spawns_per_hour: config.poke_spawns_per_hour,
+            poke_shiny_rate: config.poke_shiny_rate,
+            poke_flee_time_secs: config.poke_flee_time_secs,
+            pokeball_emoji: config.pokeball_emoji.clone(),
+            poke_follow_up_msg: config.poke_follow_up_msg,
+            poke_spawn_cooldown_secs: config.poke_spawn_cooldown_secs,
+            poke_max_spawns_per_channel: config.poke_max_spawns_per_channel,
+            poke_max_spawns_per_guild: config.poke_max_spawns_per_guild,
+            msg_min_length: config.msg_min_length,
+            msg_duplicate_window_secs: config.msg_duplicate_window_secs,
+            msg_user_cooldown_secs: config.msg_user_cooldown_secs,
+            msg_min_distinct_authors: config.msg_min_distinct_authors,
+            msg_distinct_authors_window_secs: config.msg_distinct_authors_window_secs,
+            spawn_channel_mode: SpawnChannelMode::All,
+            spawn_channel_ids: Vec::new(),
+            wild_area_channel_id: None,
+            spawn_generations: Vec::new(),
+        };
+
+        let Some(overrides) = overrides else {
+            return settings;
+        };
+
+        info!("Applying the guild's settings overrides...");
+        if let Some(poke_spawn_rate) = overrides.poke_spawn_rate {
+            settings.poke_spawn_rate = poke_spawn_rate;
+        }
+        if let Some(poke_spawn_model) = overrides.poke_spawn_model.as_deref() {
+            settings.poke_spawn_model =
+                SpawnModel::parse(poke_spawn_model).unwrap_or(settings.poke_spawn_model);
+        }
+        if let Some(poke_spawns_per_hour) = overrides.poke_spawns_per_hour {
+            settings.poke_spawns_per_hour = poke_spawns_per_hour;
+        }
+        if let Some(poke_shiny_rate) = overrides.poke_shiny_rate {
+            settings.poke_shiny_rate = poke_shiny_rate;
+        }
+        if let Some(poke_flee_time_secs) = overrides.poke_flee_time_secs {
+            settings.poke_flee_time_secs = poke_flee_time_secs;
+        }
+        if let Some(pokeball_emoji) = overrides.pokeball_emoji.as_deref().and_then(parse_emoji) {
+            settings.pokeball_emoji = pokeball_emoji;
+        }
+        if let Some(poke_follow_up_msg) = overrides.poke_follow_up_msg {
+            settings.poke_follow_up_msg = poke_follow_up_msg;
+        }
+        if let Some(poke_spawn_cooldown_secs) = overrides.poke_spawn_cooldown_secs {
+            settings.poke_spawn_cooldown_secs = poke_spawn_cooldown_secs;
+        }
+        if let Some(poke_max_spawns_per_channel) = overrides.poke_max_spawns_per_channel {
+            settings.poke_max_spawns_per_channel = poke_max_spawns_per_channel;
+        }
+        if let Some(poke_max_spawns_per_guild) = overrides.poke_max_spawns_per_guild {
+            settings.poke_max_spawns_per_guild = poke_max_spawns_per_guild;
+        }
+        if let Some(msg_min_length) = overrides.msg_min_length {
+            settings.msg_min_length = msg_min_length;
+        }
+        if let Some(msg_duplicate_window_secs) = overrides.msg_duplicate_window_secs {
+            settings.msg_duplicate_window_secs = msg_duplicate_window_secs;
+        }
+        if let Some(msg_user_cooldown_secs) = overrides.msg_user_cooldown_secs {
+            settings.msg_user_cooldown_secs = msg_user_cooldown_secs;
+        }
+        if let Some(msg_min_distinct_authors) = overrides.msg_min_distinct_authors {
+            settings.msg_min_distinct_authors = msg_min_distinct_authors;
+        }
+        if let Some(msg_distinct_authors_window_secs) = overrides.msg_distinct_authors_window_secs {
+            settings.msg_distinct_authors_window_secs = msg_distinct_authors_window_secs;
+        }
+        settings.spawn_channel_mode =
+            SpawnChannelMode::from_db(overrides.spawn_channel_mode.as_deref());
+        settings.spawn_channel_ids = overrides
+            .spawn_channel_ids
+            .iter()
+            .filter_map(|channel_id| channel_id.parse().ok())
+            .collect();
+        settings.wild_area_channel_id = overrides
+            .wild_area_channel_id
+            .as_deref()
+            .and_then(|channel_id| channel_id.parse().ok());
+        settings.spawn_generations = overrides.spawn_generations.clone();
+
+        settings
+    }
+
+    /**
+    A method to get the channel where a pokémon triggered in a channel should spawn.
+
+    ## Parameters:
+    - `channel_id`: The channel where the spawn was triggered.
+
+    ## Returns:
+    - The wild area channel if defined, or the channel itself. `None` if the channel can't spawn pokémons.
+    */
+    pub fn get_spawn_channel(&self, channel_id: ChannelId) -> Option<ChannelId> {
+        let is_listed = self.spawn_channel_ids.contains(&channel_id);
+        let can_spawn = match self.spawn_channel_mode {
+            SpawnChannelMode::All => true,
+            SpawnChannelMode::Allow => is_listed,
+            SpawnChannelMode::Deny => !is_listed,
+        };
+
+        match can_spawn {
+            true => Some(self.wild_area_channel_id.unwrap_or(channel_id)),
+            false => None,
+        }
+    }
+
+    /**
+    A method to get the effective value of a setting.
+
+    ## Parameters:
+    - `key`: The setting's key. It must be one of `GUILD_SETTING_KEYS`.
+    */
+    pub fn get_value(&self, key: &str) -> Option<String> {
+        match key {
+            "poke_spawn_rate" => Some(self.poke_spawn_rate.to_string()),
+            "poke_spawn_model" => Some(self.poke_spawn_model.as_str().to_string()),
+            "poke_spawns_per_hour" => Some(self.poke_spawns_per_hour.to_string()),
+            "poke_shiny_rate" => Some(self.poke_shiny_rate.to_string()),
+            "poke_flee_time_secs" => Some(self.poke_flee_time_secs.to_string()),
+            "pokeball_emoji" => Some(self.pokeball_emoji.to_string()),
+            "poke_follow_up_msg" => Some(self.poke_follow_up_msg.to_string()),
+            "poke_spawn_cooldown_secs" => Some(self.poke_spawn_cooldown_secs.to_string()),
+            "poke_max_spawns_per_channel" => Some(self.poke_max_spawns_per_channel.to_string()),
+            "poke_max_spawns_per_guild" => Some(self.poke_max_spawns_per_guild.to_string()),
+            "msg_min_length" => Some(self.msg_min_length.to_string()),
+            "msg_duplicate_window_secs" => Some(self.msg_duplicate_window_secs.to_string()),
+            "msg_user_cooldown_secs" => Some(self.msg_user_cooldown_secs.to_string()),
+            "msg_min_distinct_authors" => Some(self.msg_min_distinct_authors.to_string()),
+            "msg_distinct_authors_window_secs" => {
+                Some(self.msg_distinct_authors_window_secs.to_string())
+            }
+            _ => None,
+        }
+    }
+}
+
+// Guild Settings Cache
+/**
+A struct to cache the effective settings of each guild, so the messages don't read them from the database.
+
+The commands that change the settings must invalidate the guild's entry. The generation is bumped
+by every invalidation, so settings read before an invalidation are never cached after it.
+*/
+#[derive(Default)]
+pub struct GuildSettingsCache {
+    state: Mutex<GuildSettingsCacheState>,
+}
+
+#[derive(Default)]
+struct GuildSettingsCacheState {
+    generation: u64,
+    settings: HashMap<String, GuildSettings>,
+}
+
+impl GuildSettingsCache {
+    /**
+    A method to get the cached settings of a guild.
+
+    ## Parameters:
+    - `discord_guild_id`: The Discord ID of the guild.
+
+    ## Returns:
+    - The settings, if cached, and the generation to pass to `insert` if they aren't.
+    */
+    pub fn get(&self, discord_guild_id: &str) -> (Option<GuildSettings>, u64) {
+        let state = self.state.lock().unwrap_or_else(|e| e.into_inner());
+        let settings = state.settings.get(discord_guild_id).cloned();
+        (settings, state.generation)
+    }
+
+    /**
+    A method to cache the settings of a guild, unless they were invalidated since they were read.
+
+    ## Parameters:
+    - `discord_guild_id`: The Discord ID of the guild.
+    - `generation`: The generation returned by `get` before the settings were read.
+    - `settings`: The effective settings of the guild.
+    */
+    pub fn insert(&self, discord_guild_id: &str, generation: u64, settings: GuildSettings) {
+        let mut state = self.state.lock().unwrap_or_else(|e| e.into_inner());
+        if state.generation == generation {
+            state
+                .settings
+                .insert(discord_guild_id.to_string(), settings);
+        }
+    }
+
+    /**
+    A method to remove the cached settings of a guild, so they're read again from the database.
+
+    ## Parameters:
+    - `discord_guild_id`: The Discord ID of the guild.
+    */
+    pub fn invalidate(&self, discord_guild_id: &str) {
+        let mut state = self.state.lock().unwrap_or_else(|e| e.into_inner());
+        state.generation += 1;
+        state.settings.remove(discord_guild_id);
+    }
+}
+
+// Functions
+/**
+A method to get the effective settings of a guild.
+
+## Parameters:
+- `config`: The bot's configuration.
+- `discord_guild_id`: The Discord ID of the guild.
+*/
+pub async fn get_guild_settings(
+    config: &BotConfig,
+    discord_guild_id: &str,
+) -> DatabaseResult<GuildSettings> {
+    let (cached_settings, generation) = GUILD_SETTINGS_CACHE.get(discord_guild_id);
+    if let Some(settings) = cached_settings {
+        return Ok(settings);
+    }
+
+    let overrides = DBGuildSettings::get_by_guild_id(discord_guild_id).await?;
+    let settings = GuildSettings::new(config, overrides.as_ref());
+    GUILD_SETTINGS_CACHE.insert(discord_guild_id, generation, settings.clone());
+    Ok(settings)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        models::DBModel,
+        testing::{run_test, unique_id},
+        utils::config::Config,
+    };
+
+    #[test]
+    fn insert_skips_the_settings_read_before_an_invalidation() {
+        run_test(async {
+            let config = &Config::global().unwrap().bot;
+            let cache = GuildSettingsCache::default();
+            let guild_id = unique_id();
+            let (cached_settings, generation) = cache.get(&guild_id);
+            assert!(cached_settings.is_none());
+
+            cache.invalidate(&unique_id());
+            cache.insert(&guild_id, generation, GuildSettings::new(config, None));
+            assert!(cache.get(&guild_id).0.is_none());
+
+            let (_, generation) = cache.get(&guild_id);
+            cache.insert(&guild_id, generation, GuildSettings::new(config, None));
+            assert!(cache.get(&guild_id).0.is_some());
+        })
+    }
+
+    #[test]
+    fn get_guild_settings_reads_the_database_after_an_invalidation() {
+        run_test(async {
+            let guild_id = unique_id();
+            let config = &Config::global().unwrap().bot;
+            let default_rate = config.poke_spawn_rate;
+            let settings = get_guild_settings(config, &guild_id).await.unwrap();
+            assert_eq!(settings.poke_spawn_rate, default_rate);
+
+            let mut overrides = DBGuildSettings::new(&guild_id);
+            overrides.poke_spawn_rate = Some(default_rate + 1);
+            overrides.create().await.unwrap();
+            let settings = get_guild_settings(config, &guild_id).await.unwrap();
+            assert_eq!(settings.poke_spawn_rate, default_rate);
+
+            GUILD_SETTINGS_CACHE.invalidate(&guild_id);
+            let settings = get_guild_settings(config, &guild_id).await.unwrap();
+            assert_eq!(settings.poke_spawn_rate, default_rate + 1);
+        })
+    }
+}
@@ -0,0 +1,104 @@
+use serenity::all::{
+    ChannelId, CommandInteraction, Permissions, ResolvedOption, ResolvedValue, User,
+};
+
+// Functions
+/**
+A method to get a string option from a list of resolved options.
+
+## Parameters:
+- `options`: The resolved options of the command.
+- `name`: The name of the option.
+*/
+pub fn get_string_option<'a>(options: &[ResolvedOption<'a>], name: &str) -> Option<&'a str> {
+    options.iter().find_map(|option| match option.value {
+        ResolvedValue::String(value) if option.name == name => Some(value),
+        _ => None,
+    })
+}
+
+/**
+A method to get a channel option from a list of resolved options.
+
+## Parameters:
+- `options`: The resolved options of the command.
+- `name`: The name of the option.
+*/
+pub fn get_channel_option(options: &[ResolvedOption], name: &str) -> Option<ChannelId> {
+    options.iter().find_map(|option| match option.value {
+        ResolvedValue::Channel(channel) if option.name == name => Some(channel.id),
+        _ => None,
+    })
+}
+
+/**
+A method to get a user option from a list of resolved options.
+
+## Parameters:
+- `options`: The resolved options of the command.
+- `name`: The name of the option.
+*/
+pub fn get_user_option<'a>(options: &[ResolvedOption<'a>], name: &str) -> Option<&'a User> {
+    options.iter().find_map(|option| match option.value {
+        ResolvedValue::User(user, _) if option.name == name => Some(user),
+        _ => None,
+    })
+}
+
+/**
+A method to get the subcommand of a command and its options.
+
+## Parameters:
+- `options`: The resolved options of the command.
+*/
+pub fn get_subcommand<'a>(
+    options: &'a [ResolvedOption<'a>],
+) -> Option<(&'a str, &'a [ResolvedOption<'a>])> {
+    match options.first() {
+        Some(ResolvedOption {
+            name,
+            value: ResolvedValue::SubCommand(options),
+            ..
+        }) => Some((name, options)),
+        _ => None,
+    }
+}
+
+/**
+A method to get an integer option from a list of resolved options.
+
+## Parameters:
+- `options`: The resolved options of the command.
+- `name`: The name of the option.
+*/
+pub fn get_integer_option(options: &[ResolvedOption], name: &str) -> Option<i64> {
+    options.iter().find_map(|option| match option.value {
+        ResolvedValue::Integer(value) if option.name == name => Some(value),
+        _ => None,
+    })
+}
+
+/**
+A method to check if the user who called a command has all the given permissions.
+
+## Parameters:
+- `command`: The command interaction.
+- `required`: The required permissions.
+*/
+pub fn has_permissions(command: &CommandInteraction, required: Permissions) -> bool {
+    command
+        .member
+        .as_ref()
+        .and_then(|member| member.permissions)
+        .is_some_and(|permissions| permissions.contains(required))
+}
+
+/**
+A method to check if the user who called a command has the Manage Server permission.
+
+## Parameters:
+- `command`: The command interaction.
+*/
+pub fn can_manage_guild(command: &CommandInteraction) -> bool {
+    has_permissions(command, Permissions::MANAGE_GUILD)
+}
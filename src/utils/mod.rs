@@ -1,5 +1,11 @@
 // Libs
-pub use env_mgr::EnvManager;
-
-mod env_mgr;
+pub mod config;
+pub mod economy;
+pub mod guild_settings;
+pub mod interaction;
+pub mod spawn_eligibility;
+pub mod spawn_limiter;
+pub mod spawn_model;
+pub mod spawn_table;
+pub mod trade;
 pub mod trainer;
@@ -0,0 +1,409 @@
+// Libs
+use once_cell::sync::Lazy;
+use serenity::all::{ChannelId, Message, UserId};
+use std::{
+    collections::{HashMap, HashSet, VecDeque},
+    sync::Mutex,
+    time::{Duration, Instant},
+};
+
+use super::guild_settings::GuildSettings;
+
+// Constants
+/**
+The maximum amount of recent messages kept per channel. The oldest ones are forgotten first, even
+inside the filters' window, so a busy channel can't grow its history without limit.
+*/
+const MAX_HISTORY_LEN: usize = 1000;
+
+// Data
+/**
+The recent messages of each channel, shared by every message handler.
+*/
+pub static MESSAGE_HISTORY: Lazy<MessageHistory> = Lazy::new(MessageHistory::default);
+
+// Recent Message
+/**
+A struct to represent a message sent in a spawn channel.
+*/
+#[derive(Clone, Debug)]
+pub struct RecentMessage {
+    pub author_id: UserId,
+    pub content: String,
+    pub sent_at: Instant,
+    pub is_eligible: bool,
+}
+
+impl RecentMessage {
+    /**
+    A method to create a new RecentMessage from a Discord message.
+    The content is normalized, so the duplicates are detected regardless of the case and spacing.
+
+    ## Parameters:
+    - `msg`: The Discord message.
+    */
+    pub fn new(msg: &Message) -> Self {
+        Self {
+            author_id: msg.author.id,
+            content: msg
+                .content
+                .split_whitespace()
+                .collect::<Vec<&str>>()
+                .join(" ")
+                .to_lowercase(),
+            sent_at: Instant::now(),
+            is_eligible: false,
+        }
+    }
+}
+
+// Eligibility Filter
+/**
+A trait to represent a rule a message must follow to count toward a spawn roll.
+*/
+pub trait EligibilityFilter: Send + Sync {
+    /**
+    A method to get the name of the filter, used in the logs.
+    */
+    fn name(&self) -> &'static str;
+
+    /**
+    A method to get how long the filter needs the channel's messages.
+    */
+    fn window(&self) -> Duration {
+        Duration::ZERO
+    }
+
+    /**
+    A method to check if a message follows the filter's rule.
+
+    ## Parameters:
+    - `msg`: The new message.
+    - `history`: The previous messages of the channel, from the oldest to the newest.
+    */
+    fn is_eligible(&self, msg: &RecentMessage, history: &VecDeque<RecentMessage>) -> bool;
+}
+
+/**
+A filter to ignore the messages shorter than a minimum length.
+*/
+pub struct MinLengthFilter {
+    pub min_length: usize,
+}
+
+impl EligibilityFilter for MinLengthFilter {
+    fn name(&self) -> &'static str {
+        "min_length"
+    }
+
+    fn is_eligible(&self, msg: &RecentMessage, _history: &VecDeque<RecentMessage>) -> bool {
+        msg.content.chars().count() >= self.min_length
+    }
+}
+
+/**
+A filter to ignore the messages repeated by the same user in a window.
+*/
+pub struct DuplicateFilter {
+    pub window: Duration,
+}
+
+impl EligibilityFilter for DuplicateFilter {
+    fn name(&self) -> &'static str {
+        "duplicate"
+    }
+
+    fn window(&self) -> Duration {
+        self.window
+    }
+
+    fn is_eligible(&self, msg: &RecentMessage, history: &VecDeque<RecentMessage>) -> bool {
+        !history.iter().any(|previous| {
+            previous.author_id == msg.author_id
+                && previous.content == msg.content
+                && msg.sent_at.duration_since(previous.sent_at) < self.window
+        })
+    }
+}
+
+/**
+A filter to only count one message per user in a window.
+*/
+pub struct UserRateLimitFilter {
+    pub cooldown: Duration,
+}
+
+impl EligibilityFilter for UserRateLimitFilter {
+    fn name(&self) -> &'static str {
+        "user_rate_limit"
+    }
+
+    fn window(&self) -> Duration {
+        self.cooldown
+    }
+
+    fn is_eligible(&self, msg: &RecentMessage, history: &VecDeque<RecentMessage>) -> bool {
+        !history.iter().any(|previous| {
+            previous.author_id == msg.author_id
+                && previous.is_eligible
+                && msg.sent_at.duration_since(previous.sent_at) < self.cooldown
+        })
+    }
+}
+
+/**
+A filter to require a minimum amount of distinct authors talking in the channel in a window.
+*/
+pub struct DistinctAuthorsFilter {
+    pub min_authors: usize,
+    pub window: Duration,
+}
+
+impl EligibilityFilter for DistinctAuthorsFilter {
+    fn name(&self) -> &'static str {
+        "distinct_authors"
+    }
+
+    fn window(&self) -> Duration {
+        self.window
+    }
+
+    fn is_eligible(&self, msg: &RecentMessage, history: &VecDeque<RecentMessage>) -> bool {
+        let authors: HashSet<UserId> = history
+            .iter()
+            .filter(|previous| msg.sent_at.duration_since(previous.sent_at) < self.window)
+            .map(|previous| previous.author_id)
+            .chain([msg.author_id])
+            .collect();
+        authors.len() >= self.min_authors
+    }
+}
+
+// Functions
+/**
+A method to get the eligibility filters enabled in a guild.
+
+## Parameters:
+- `settings`: The effective settings of the guild.
+*/
+pub fn get_eligibility_filters(settings: &GuildSettings) -> Vec<Box<dyn EligibilityFilter>> {
+    let mut filters: Vec<Box<dyn EligibilityFilter>> = Vec::new();
+    if settings.msg_min_length > 0 {
+        filters.push(Box::new(MinLengthFilter {
+            min_length: settings.msg_min_length as usize,
+        }));
+    }
+    if settings.msg_duplicate_window_secs > 0 {
+        filters.push(Box::new(DuplicateFilter {
+            window: Duration::from_secs(settings.msg_duplicate_window_secs),
+        }));
+    }
+    if settings.msg_user_cooldown_secs > 0 {
+        filters.push(Box::new(UserRateLimitFilter {
+            cooldown: Duration::from_secs(settings.msg_user_cooldown_secs),
+        }));
+    }
+    if settings.msg_min_distinct_authors > 1 && settings.msg_distinct_authors_window_secs > 0 {
+        filters.push(Box::new(DistinctAuthorsFilter {
+            min_authors: settings.msg_min_distinct_authors as usize,
+            window: Duration::from_secs(settings.msg_distinct_authors_window_secs),
+        }));
+    }
+
+    filters
+}
+
+// Message History
+/**
+A struct to keep the recent messages of each channel.
+
+The messages are checked and recorded under a single lock, so concurrent message handlers see each
+other's messages.
+*/
+#[derive(Default)]
+pub struct MessageHistory {
+    channels: Mutex<HashMap<ChannelId, VecDeque<RecentMessage>>>,
+}
+
+impl MessageHistory {
+    /**
+    A method to check if a message is eligible for a spawn roll and record it in the channel's history.
+
+    ## Parameters:
+    - `channel_id`: The channel of the message.
+    - `msg`: The new message.
+    - `filters`: The eligibility filters of the guild.
+
+    ## Returns:
+    - The name of the first filter the message doesn't follow, or `None` if it's eligible.
+    */
+    pub fn check(
+        &self,
+        channel_id: ChannelId,
+        mut msg: RecentMessage,
+        filters: &[Box<dyn EligibilityFilter>],
+    ) -> Option<&'static str> {
+        let mut channels = self.channels.lock().unwrap_or_else(|e| e.into_inner());
+        let history = channels.entry(channel_id).or_default();
+
+        // Forget the messages no filter needs anymore.
+        let window = filters
+            .iter()
+            .map(|filter| filter.window())
+            .max()
+            .unwrap_or_default();
+        while history
+            .front()
+            .is_some_and(|previous| msg.sent_at.duration_since(previous.sent_at) >= window)
+        {
+            history.pop_front();
+        }
+
+        let rejected_by = filters
+            .iter()
+            .find(|filter| !filter.is_eligible(&msg, history))
+            .map(|filter| filter.name());
+        msg.is_eligible = rejected_by.is_none();
+        if !window.is_zero() {
+            history.push_back(msg);
+            if history.len() > MAX_HISTORY_LEN {
+                history.pop_front();
+            }
+        }
+
+        rejected_by
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /**
+    A method to get a message sent some seconds after the start of the test.
+    */
+    fn get_message(start: Instant, secs: u64, author_id: u64, content: &str) -> RecentMessage {
+        RecentMessage {
+            author_id: UserId::new(author_id),
+            content: content.to_string(),
+            sent_at: start + Duration::from_secs(secs),
+            is_eligible: true,
+        }
+    }
+
+    #[test]
+    fn min_length_filter_counts_the_characters() {
+        let start = Instant::now();
+        let filter = MinLengthFilter { min_length: 3 };
+        let history = VecDeque::new();
+        assert!(filter.is_eligible(&get_message(start, 0, 1, "pik"), &history));
+        assert!(filter.is_eligible(&get_message(start, 0, 1, "✨✨✨"), &history));
+        assert!(!filter.is_eligible(&get_message(start, 0, 1, "pi"), &history));
+    }
+
+    #[test]
+    fn duplicate_filter_ignores_the_repeats_of_the_same_user_in_the_window() {
+        let start = Instant::now();
+        let filter = DuplicateFilter {
+            window: Duration::from_secs(60),
+        };
+        let history = VecDeque::from([get_message(start, 0, 1, "hello")]);
+        assert!(!filter.is_eligible(&get_message(start, 59, 1, "hello"), &history));
+        assert!(filter.is_eligible(&get_message(start, 60, 1, "hello"), &history));
+        assert!(filter.is_eligible(&get_message(start, 1, 2, "hello"), &history));
+        assert!(filter.is_eligible(&get_message(start, 1, 1, "bye"), &history));
+    }
+
+    #[test]
+    fn user_rate_limit_filter_only_counts_the_eligible_messages() {
+        let start = Instant::now();
+        let filter = UserRateLimitFilter {
+            cooldown: Duration::from_secs(30),
+        };
+        let mut history = VecDeque::from([get_message(start, 0, 1, "hello")]);
+        assert!(!filter.is_eligible(&get_message(start, 29, 1, "again"), &history));
+        assert!(filter.is_eligible(&get_message(start, 30, 1, "again"), &history));
+        assert!(filter.is_eligible(&get_message(start, 1, 2, "hello"), &history));
+
+        history[0].is_eligible = false;
+        assert!(filter.is_eligible(&get_message(start, 1, 1, "again"), &history));
+    }
+
+    #[test]
+    fn distinct_authors_filter_counts_the_authors_in_the_window() {
+        let start = Instant::now();
+        let filter = DistinctAuthorsFilter {
+            min_authors: 3,
+            window: Duration::from_secs(60),
+        };
+        let history = VecDeque::from([
+            get_message(start, 0, 1, "hello"),
+            get_message(start, 10, 2, "hello"),
+            get_message(start, 20, 2, "hello"),
+        ]);
+        assert!(filter.is_eligible(&get_message(start, 30, 3, "hello"), &history));
+        assert!(!filter.is_eligible(&get_message(start, 30, 2, "hello"), &history));
+        assert!(!filter.is_eligible(&get_message(start, 60, 3, "hello"), &history));
+    }
+
+    #[test]
+    fn check_keeps_every_message_inside_the_window() {
+        let start = Instant::now();
+        let history = MessageHistory::default();
+        let channel_id = ChannelId::new(1);
+        let filters: Vec<Box<dyn EligibilityFilter>> = vec![Box::new(DuplicateFilter {
+            window: Duration::from_secs(3600),
+        })];
+        assert_eq!(
+            history.check(channel_id, get_message(start, 0, 1, "hello"), &filters),
+            None
+        );
+        for i in 1..500 {
+            let msg = get_message(start, i, 2, &format!("message {}", i));
+            assert_eq!(history.check(channel_id, msg, &filters), None);
+        }
+
+        let msg = get_message(start, 3599, 1, "hello");
+        assert_eq!(history.check(channel_id, msg, &filters), Some("duplicate"));
+    }
+
+    #[test]
+    fn check_forgets_the_messages_outside_the_window() {
+        let start = Instant::now();
+        let history = MessageHistory::default();
+        let channel_id = ChannelId::new(1);
+        let filters: Vec<Box<dyn EligibilityFilter>> = vec![Box::new(DuplicateFilter {
+            window: Duration::from_secs(60),
+        })];
+        for i in 0..10 {
+            let msg = get_message(start, i, 1, &format!("message {}", i));
+            assert_eq!(history.check(channel_id, msg, &filters), None);
+        }
+        let msg = get_message(start, 68, 1, "message 9");
+        assert_eq!(history.check(channel_id, msg, &filters), Some("duplicate"));
+
+        let msg = get_message(start, 130, 1, "hello");
+        assert_eq!(history.check(channel_id, msg, &filters), None);
+        let channels = history.channels.lock().unwrap();
+        assert_eq!(channels[&channel_id].len(), 1);
+    }
+
+    #[test]
+    fn check_caps_the_history_of_a_channel() {
+        let start = Instant::now();
+        let history = MessageHistory::default();
+        let channel_id = ChannelId::new(1);
+        let filters: Vec<Box<dyn EligibilityFilter>> = vec![Box::new(DuplicateFilter {
+            window: Duration::from_secs(3600),
+        })];
+        for i in 0..MAX_HISTORY_LEN as u64 + 10 {
+            let msg = get_message(start, i, 1, &format!("message {}", i));
+            assert_eq!(history.check(channel_id, msg, &filters), None);
+        }
+
+        let channels = history.channels.lock().unwrap();
+        let channel_history = &channels[&channel_id];
+        assert_eq!(channel_history.len(), MAX_HISTORY_LEN);
+        assert_eq!(channel_history[0].content, "message 10");
+    }
+}
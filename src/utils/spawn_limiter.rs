@@ -0,0 +1,305 @@
+// Libs
+use once_cell::sync::Lazy;
+use serenity::all::{ChannelId, GuildId};
+use std::{
+    collections::HashMap,
+    sync::Mutex,
+    time::{Duration, Instant},
+};
+
+// Data
+/**
+The spawn limiter shared by every message handler.
+*/
+pub static SPAWN_LIMITER: Lazy<SpawnLimiter> = Lazy::new(SpawnLimiter::default);
+
+// Spawn Limits
+/**
+A struct to represent the spawn limits of a guild.
+
+A limit of `0` disables it.
+*/
+#[derive(Clone, Copy, Debug)]
+pub struct SpawnLimits {
+    pub cooldown: Duration,
+    pub max_per_channel: u64,
+    pub max_per_guild: u64,
+}
+
+// Spawn Limiter
+/**
+A struct to keep track of the active spawns and of the last spawn of each channel.
+
+The state is behind a single lock, so checking the limits and reserving a spawn is atomic across
+concurrent message handlers. The last spawns are forgotten once they're older than the largest
+cooldown, so they can't put any channel in cooldown anymore.
+*/
+#[derive(Default)]
+pub struct SpawnLimiter {
+    state: Mutex<SpawnLimiterState>,
+}
+
+#[derive(Default)]
+struct SpawnLimiterState {
+    last_spawns: HashMap<ChannelId, Instant>,
+    max_cooldown: Duration,
+    channel_spawns: HashMap<ChannelId, u64>,
+    guild_spawns: HashMap<GuildId, u64>,
+}
+
+impl SpawnLimiter {
+    /**
+    A method to reserve a spawn in a channel if the limits allow it.
+
+    ## Parameters:
+    - `guild_id`: The guild of the spawn.
+    - `channel_id`: The channel of the spawn.
+    - `limits`: The spawn limits of the guild.
+
+    ## Returns:
+    - `None` if the channel is in cooldown or has too many active spawns.
+    */
+    pub fn try_reserve(
+        &'static self,
+        guild_id: GuildId,
+        channel_id: ChannelId,
+        limits: SpawnLimits,
+    ) -> Option<SpawnReservation> {
+        let mut state = self.state.lock().unwrap_or_else(|e| e.into_inner());
+        state.prune_last_spawns(limits.cooldown);
+        let in_cooldown = state
+            .last_spawns
+            .get(&channel_id)
+            .is_some_and(|last_spawn| last_spawn.elapsed() < limits.cooldown);
+        let channel_spawns = state.channel_spawns.get(&channel_id).copied();
+        let guild_spawns = state.guild_spawns.get(&guild_id).copied();
+        let is_full = |spawns: Option<u64>, max: u64| max > 0 && spawns.unwrap_or(0) >= max;
+        if in_cooldown
+            || is_full(channel_spawns, limits.max_per_channel)
+            || is_full(guild_spawns, limits.max_per_guild)
+        {
+            return None;
+        }
+
+        Some(state.reserve(self, guild_id, channel_id))
+    }
+
+    /**
+    A method to reserve a spawn in a channel regardless of the limits.
+    It's used by the spawns that were already sent, e.g. the recovered ones.
+
+    ## Parameters:
+    - `guild_id`: The guild of the spawn.
+    - `channel_id`: The channel of the spawn.
+    */
+    pub fn reserve(&'static self, guild_id: GuildId, channel_id: ChannelId) -> SpawnReservation {
+        let mut state = self.state.lock().unwrap_or_else(|e| e.into_inner());
+        state.reserve(self, guild_id, channel_id)
+    }
+
+    /**
+    A method to release a spawn reservation.
+
+    ## Parameters:
+    - `guild_id`: The guild of the spawn.
+    - `channel_id`: The channel of the spawn.
+    */
+    fn release(&self, guild_id: GuildId, channel_id: ChannelId) {
+        let mut state = self.state.lock().unwrap_or_else(|e| e.into_inner());
+        decrement(&mut state.channel_spawns, channel_id);
+        decrement(&mut state.guild_spawns, guild_id);
+    }
+}
+
+impl SpawnLimiterState {
+    /**
+    A method to forget the last spawns that are older than the largest cooldown.
+
+    ## Parameters:
+    - `cooldown`: The cooldown of the guild that is spawning.
+    */
+    fn prune_last_spawns(&mut self, cooldown: Duration) {
+        self.max_cooldown = self.max_cooldown.max(cooldown);
+        let max_cooldown = self.max_cooldown;
+        self.last_spawns
+            .retain(|_, last_spawn| last_spawn.elapsed() < max_cooldown);
+    }
+
+    /**
+    A method to count a new active spawn and restart the channel's cooldown.
+
+    ## Parameters:
+    - `limiter`: The limiter that owns the state.
+    - `guild_id`: The guild of the spawn.
+    - `channel_id`: The channel of the spawn.
+    */
+    fn reserve(
+        &mut self,
+        limiter: &'static SpawnLimiter,
+        guild_id: GuildId,
+        channel_id: ChannelId,
+    ) -> SpawnReservation {
+        self.last_spawns.insert(channel_id, Instant::now());
+        *self.channel_spawns.entry(channel_id).or_default() += 1;
+        *self.guild_spawns.entry(guild_id).or_default() += 1;
+        SpawnReservation {
+            limiter,
+            guild_id,
+            channel_id,
+        }
+    }
+}
+
+// Spawn Reservation
+/**
+A struct to represent an active spawn. The spawn stops being counted when it's dropped.
+*/
+pub struct SpawnReservation {
+    limiter: &'static SpawnLimiter,
+    guild_id: GuildId,
+    channel_id: ChannelId,
+}
+
+impl Drop for SpawnReservation {
+    fn drop(&mut self) {
+        self.limiter.release(self.guild_id, self.channel_id);
+    }
+}
+
+// Functions
+/**
+A method to decrement a counter, removing it when it reaches zero.
+
+## Parameters:
+- `counters`: The counters.
+- `key`: The key of the counter.
+*/
+fn decrement<K>(counters: &mut HashMap<K, u64>, key: K)
+where
+    K: Eq + std::hash::Hash,
+{
+    if let Some(count) = counters.get_mut(&key) {
+        *count = count.saturating_sub(1);
+        if *count == 0 {
+            counters.remove(&key);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /**
+    A method to get a new spawn limiter that lives as long as the reservations need.
+    */
+    fn get_limiter() -> &'static SpawnLimiter {
+        Box::leak(Box::default())
+    }
+
+    /**
+    A method to get the spawn limits.
+
+    ## Parameters:
+    - `cooldown_secs`: The cooldown of a channel after a spawn.
+    - `max_per_channel`: The maximum amount of active spawns per channel.
+    - `max_per_guild`: The maximum amount of active spawns per guild.
+    */
+    fn get_limits(cooldown_secs: u64, max_per_channel: u64, max_per_guild: u64) -> SpawnLimits {
+        SpawnLimits {
+            cooldown: Duration::from_secs(cooldown_secs),
+            max_per_channel,
+            max_per_guild,
+        }
+    }
+
+    #[test]
+    fn try_reserve_blocks_the_channel_during_the_cooldown() {
+        let limiter = get_limiter();
+        let (guild_id, channel_id) = (GuildId::new(1), ChannelId::new(1));
+        let limits = get_limits(60, 0, 0);
+
+        drop(limiter.try_reserve(guild_id, channel_id, limits).unwrap());
+        assert!(limiter.try_reserve(guild_id, channel_id, limits).is_none());
+        assert!(limiter
+            .try_reserve(guild_id, ChannelId::new(2), limits)
+            .is_some());
+    }
+
+    #[test]
+    fn try_reserve_caps_the_active_spawns_per_channel() {
+        let limiter = get_limiter();
+        let (guild_id, channel_id) = (GuildId::new(1), ChannelId::new(1));
+        let limits = get_limits(0, 2, 0);
+
+        let first = limiter.try_reserve(guild_id, channel_id, limits).unwrap();
+        let _second = limiter.try_reserve(guild_id, channel_id, limits).unwrap();
+        assert!(limiter.try_reserve(guild_id, channel_id, limits).is_none());
+
+        drop(first);
+        assert!(limiter.try_reserve(guild_id, channel_id, limits).is_some());
+    }
+
+    #[test]
+    fn try_reserve_caps_the_active_spawns_per_guild() {
+        let limiter = get_limiter();
+        let guild_id = GuildId::new(1);
+        let limits = get_limits(0, 0, 2);
+
+        let first = limiter
+            .try_reserve(guild_id, ChannelId::new(1), limits)
+            .unwrap();
+        let _second = limiter
+            .try_reserve(guild_id, ChannelId::new(2), limits)
+            .unwrap();
+        assert!(limiter
+            .try_reserve(guild_id, ChannelId::new(3), limits)
+            .is_none());
+        assert!(limiter
+            .try_reserve(GuildId::new(2), ChannelId::new(4), limits)
+            .is_some());
+
+        drop(first);
+        assert!(limiter
+            .try_reserve(guild_id, ChannelId::new(3), limits)
+            .is_some());
+    }
+
+    #[test]
+    fn try_reserve_counts_the_reservations_regardless_of_the_limits() {
+        let limiter = get_limiter();
+        let (guild_id, channel_id) = (GuildId::new(1), ChannelId::new(1));
+        let limits = get_limits(0, 1, 0);
+
+        let reservation = limiter.reserve(guild_id, channel_id);
+        assert!(limiter.try_reserve(guild_id, channel_id, limits).is_none());
+
+        drop(reservation);
+        assert!(limiter.try_reserve(guild_id, channel_id, limits).is_some());
+    }
+
+    #[test]
+    fn try_reserve_forgets_the_spawns_older_than_the_largest_cooldown() {
+        let limiter = get_limiter();
+        let guild_id = GuildId::new(1);
+        let get_last_spawns = || {
+            let state = limiter.state.lock().unwrap();
+            let mut channel_ids: Vec<ChannelId> = state.last_spawns.keys().copied().collect();
+            channel_ids.sort();
+            channel_ids
+        };
+
+        // Without any cooldown, the previous spawns are forgotten.
+        limiter.try_reserve(guild_id, ChannelId::new(1), get_limits(0, 0, 0));
+        limiter.try_reserve(guild_id, ChannelId::new(2), get_limits(0, 0, 0));
+        assert_eq!(get_last_spawns(), vec![ChannelId::new(2)]);
+
+        // Once a guild uses a cooldown, the spawns are kept for as long as it lasts.
+        limiter.try_reserve(guild_id, ChannelId::new(3), get_limits(60, 0, 0));
+        limiter.try_reserve(guild_id, ChannelId::new(4), get_limits(0, 0, 0));
+        assert_eq!(
+            get_last_spawns(),
+            vec![ChannelId::new(2), ChannelId::new(3), ChannelId::new(4)]
+        );
+    }
+}
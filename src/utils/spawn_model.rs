@@ -0,0 +1,156 @@
+// Libs
+use once_cell::sync::Lazy;
+use serenity::all::ChannelId;
+use std::{
+    collections::HashMap,
+    sync::Mutex,
+    time::{Duration, Instant},
+};
+
+use super::config::parse_choice;
+
+// Data
+/**
+The recent activity of each spawn channel, shared by every message handler.
+*/
+pub static CHANNEL_ACTIVITY: Lazy<ChannelActivity> = Lazy::new(ChannelActivity::default);
+
+// Spawn Model
+/**
+An enum to represent how the chance of a spawn is calculated.
+*/
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum SpawnModel {
+    /// Every message has a fixed 1 in `poke_spawn_rate` chance to spawn a pokémon.
+    Message,
+    /// The chance of each message is scaled by the time since the channel's previous message to reach `poke_spawns_per_hour`.
+    Activity,
+}
+
+impl SpawnModel {
+    /**
+    The names of the spawn models.
+    */
+    pub const CHOICES: &'static [&'static str] = &["message", "activity"];
+
+    /**
+    A method to parse a spawn model from its name.
+
+    ## Parameters:
+    - `value`: The name of the spawn model.
+    */
+    pub fn parse(value: &str) -> Result<Self, String> {
+        match parse_choice(value, Self::CHOICES)?.as_str() {
+            "activity" => Ok(Self::Activity),
+            _ => Ok(Self::Message),
+        }
+    }
+
+    /**
+    A method to get the name of the spawn model.
+    */
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Message => "message",
+            Self::Activity => "activity",
+        }
+    }
+}
+
+// Channel Activity
+/**
+A struct to keep the time of the last eligible message of each spawn channel.
+*/
+#[derive(Default)]
+pub struct ChannelActivity {
+    channels: Mutex<HashMap<ChannelId, Instant>>,
+}
+
+impl ChannelActivity {
+    /**
+    A method to record a message in a channel and measure the time since the channel's previous message.
+
+    ## Parameters:
+    - `channel_id`: The channel of the message.
+    - `window`: The maximum time a message can count, so a message after a silence doesn't always spawn.
+
+    ## Returns:
+    - The time since the previous message, or the window if it's longer or there's no previous message.
+    */
+    pub fn record(&self, channel_id: ChannelId, window: Duration) -> Duration {
+        let mut channels = self.channels.lock().unwrap_or_else(|e| e.into_inner());
+        let now = Instant::now();
+        match channels.insert(channel_id, now) {
+            Some(previous) => now.duration_since(previous).min(window),
+            None => window,
+        }
+    }
+}
+
+// Functions
+/**
+A method to get the chance of a message to spawn a pokémon with the activity spawn model.
+
+Each message has the chance of a spawn happening in the time since the channel's previous message
+at a rate of `spawns_per_hour`. A channel gets about the same amount of spawns regardless of how
+busy it is, and a burst of messages only has the chance of the time it took.
+
+## Parameters:
+- `spawns_per_hour`: The target of spawns per hour in a channel.
+- `elapsed`: The time since the channel's previous message, limited by the activity window.
+*/
+pub fn get_activity_spawn_chance(spawns_per_hour: u64, elapsed: Duration) -> f64 {
+    let expected_spawns = spawns_per_hour as f64 * elapsed.as_secs_f64() / 3600.0;
+    (1.0 - (-expected_spawns).exp()).clamp(0.0, 1.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /**
+    A method to get the expected spawns in an hour of messages sent at a steady rate.
+    */
+    fn get_expected_spawns(spawns_per_hour: u64, msgs_per_hour: u64, window: Duration) -> f64 {
+        let elapsed = Duration::from_secs_f64(3600.0 / msgs_per_hour as f64).min(window);
+        get_activity_spawn_chance(spawns_per_hour, elapsed) * msgs_per_hour as f64
+    }
+
+    #[test]
+    fn activity_spawn_chance_reaches_the_target_regardless_of_the_activity() {
+        let window = Duration::from_secs(3600);
+        for msgs_per_hour in [60, 600, 6000, 60000] {
+            let spawns = get_expected_spawns(4, msgs_per_hour, window);
+            assert!((3.8..=4.0).contains(&spawns), "{} spawns", spawns);
+        }
+    }
+
+    #[test]
+    fn activity_spawn_chance_is_limited_by_the_window() {
+        let window = Duration::from_secs(15 * 60);
+        let chance = get_activity_spawn_chance(4, window);
+        assert!((chance - (1.0 - (-1.0f64).exp())).abs() < 1e-9);
+        assert!(chance < 1.0);
+        assert!(get_expected_spawns(4, 1, window) < 1.0);
+    }
+
+    #[test]
+    fn activity_spawn_chance_of_a_burst_is_the_chance_of_its_duration() {
+        let burst: f64 = (0..100)
+            .map(|_| get_activity_spawn_chance(4, Duration::from_millis(100)))
+            .sum();
+        let duration = get_activity_spawn_chance(4, Duration::from_secs(10));
+        assert!((burst - duration).abs() < 1e-4);
+        assert_eq!(get_activity_spawn_chance(4, Duration::ZERO), 0.0);
+    }
+
+    #[test]
+    fn record_limits_the_elapsed_time_by_the_window() {
+        let activity = ChannelActivity::default();
+        let channel_id = ChannelId::new(1);
+        let window = Duration::from_secs(60);
+        assert_eq!(activity.record(channel_id, window), window);
+        assert!(activity.record(channel_id, window) < window);
+        assert_eq!(activity.record(ChannelId::new(2), window), window);
+    }
+}
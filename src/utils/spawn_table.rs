@@ -0,0 +1,468 @@
+// Libs
+use rand::Rng;
+use std::collections::HashSet;
+
+use super::config::BotConfig;
+use crate::{
+    errors::PokeFinderResult,
+    serializations::cache::{CachedGeneration, CachedSpawnEntry},
+    services::POKEFINDER_SERVICE,
+};
+
+// Spawn Tier
+/**
+An enum to represent the rarity tier of a species.
+*/
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum SpawnTier {
+    Common,
+    Baby,
+    Legendary,
+    Mythical,
+}
+
+impl SpawnTier {
+    /**
+    All the rarity tiers, from the most common to the rarest.
+    */
+    pub const ALL: [Self; 4] = [Self::Common, Self::Baby, Self::Legendary, Self::Mythical];
+
+    /**
+    A method to get the rarity tier of a species.
+
+    ## Parameters:
+    - `entry`: The species in the spawn table.
+    */
+    pub fn from_entry(entry: &CachedSpawnEntry) -> Self {
+        if entry.is_mythical {
+            Self::Mythical
+        } else if entry.is_legendary {
+            Self::Legendary
+        } else if entry.is_baby {
+            Self::Baby
+        } else {
+            Self::Common
+        }
+    }
+
+    /**
+    A method to get the name of the rarity tier.
+    */
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Common => "Common",
+            Self::Baby => "Baby",
+            Self::Legendary => "Legendary",
+            Self::Mythical => "Mythical",
+        }
+    }
+}
+
+// Spawn Weights
+/**
+A struct to represent the weight of each rarity tier in the spawn table.
+*/
+#[derive(Clone, Copy, Debug)]
+pub struct SpawnWeights {
+    pub common: u64,
+    pub baby: u64,
+    pub legendary: u64,
+    pub mythical: u64,
+    pub by_capture_rate: bool,
+}
+
+impl SpawnWeights {
+    /**
+    A method to get the spawn weights from the `bot` configuration.
+
+    ## Parameters:
+    - `config`: The `bot` configuration.
+    */
+    pub fn from_config(config: &BotConfig) -> Self {
+        Self {
+            common: config.spawn_weight_common,
+            baby: config.spawn_weight_baby,
+            legendary: config.spawn_weight_legendary,
+            mythical: config.spawn_weight_mythical,
+            by_capture_rate: config.spawn_weight_by_capture_rate,
+        }
+    }
+
+    /**
+    A method to get the weight of a species.
+
+    The tier's weight is scaled by the species' capture rate, from 1 to 255, if enabled.
+
+    ## Parameters:
+    - `entry`: The species in the spawn table.
+    */
+    pub fn get_weight(&self, entry: &CachedSpawnEntry) -> f64 {
+        let tier_weight = match SpawnTier::from_entry(entry) {
+            SpawnTier::Common => self.common,
+            SpawnTier::Baby => self.baby,
+            SpawnTier::Legendary => self.legendary,
+            SpawnTier::Mythical => self.mythical,
+        } as f64;
+
+        match self.by_capture_rate {
+            true => tier_weight * f64::from(entry.capture_rate.max(1)) / 255.0,
+            false => tier_weight,
+        }
+    }
+}
+
+// Spawn Tier Stats
+/**
+A struct to represent the effective distribution of a rarity tier.
+*/
+#[derive(Clone, Debug)]
+pub struct SpawnTierStats {
+    pub tier: SpawnTier,
+    pub species: usize,
+    pub chance: f64,
+}
+
+// Spawn Table
+/**
+A struct to pick the species of a spawn, weighted by their rarity.
+*/
+pub struct SpawnTable {
+    entries: Vec<CachedSpawnEntry>,
+    weights: Vec<f64>,
+    total_weight: f64,
+}
+
+impl SpawnTable {
+    /**
+    A method to create a new SpawnTable.
+
+    ## Parameters:
+    - `entries`: The species that can spawn.
+    - `weights`: The weight of each rarity tier.
+    */
+    pub fn new(entries: Vec<CachedSpawnEntry>, weights: &SpawnWeights) -> Self {
+        let weights: Vec<f64> = entries
+            .iter()
+            .map(|entry| weights.get_weight(entry))
+            .collect();
+        let total_weight = weights.iter().sum();
+        Self {
+            entries,
+            weights,
+            total_weight,
+        }
+    }
+
+    /**
+    A method to pick a random species.
+
+    ## Returns:
+    - The ID of the species, or `None` if no species can spawn.
+    */
+    pub fn choose(&self) -> Option<u16> {
+        self.choose_with(&mut rand::thread_rng())
+    }
+
+    /**
+    A method to pick a random species with a given random number generator.
+
+    ## Parameters:
+    - `rng`: The random number generator.
+
+    ## Returns:
+    - The ID of the species, or `None` if no species can spawn.
+    */
+    fn choose_with(&self, rng: &mut impl Rng) -> Option<u16> {
+        if self.total_weight <= 0.0 {
+            return None;
+        }
+
+        let mut target = rng.gen_range(0.0..self.total_weight);
+        for (entry, weight) in self.entries.iter().zip(&self.weights) {
+            if target < *weight {
+                return Some(entry.id);
+            }
+            target -= weight;
+        }
+
+        // Rounding errors can skip the last species with a weight.
+        self.entries
+            .iter()
+            .zip(&self.weights)
+            .rev()
+            .find(|(_, weight)| **weight > 0.0)
+            .map(|(entry, _)| entry.id)
+    }
+
+    /**
+    A method to get a species and its chance to be picked.
+
+    ## Parameters:
+    - `identifier`: The ID or the name of the species.
+    */
+    pub fn get_entry(&self, identifier: &str) -> Option<(&CachedSpawnEntry, f64)> {
+        let identifier = identifier.trim().to_lowercase();
+        self.entries
+            .iter()
+            .zip(&self.weights)
+            .find(|(entry, _)| entry.id.to_string() == identifier || entry.name == identifier)
+            .map(|(entry, weight)| (entry, self.get_chance(*weight)))
+    }
+
+    /**
+    A method to get the effective distribution of each rarity tier.
+    */
+    pub fn get_tier_stats(&self) -> Vec<SpawnTierStats> {
+        SpawnTier::ALL
+            .iter()
+            .map(|tier| {
+                let tier_weights = self
+                    .entries
+                    .iter()
+                    .zip(&self.weights)
+                    .filter(|(entry, _)| SpawnTier::from_entry(entry) == *tier)
+                    .map(|(_, weight)| *weight);
+                SpawnTierStats {
+                    tier: *tier,
+                    species: tier_weights.clone().count(),
+                    chance: self.get_chance(tier_weights.sum()),
+                }
+            })
+            .collect()
+    }
+
+    /**
+    A method to get the chance of a weight to be picked, from 0 to 1.
+
+    ## Parameters:
+    - `weight`: The weight.
+    */
+    fn get_chance(&self, weight: f64) -> f64 {
+        match self.total_weight > 0.0 {
+            true => weight / self.total_weight,
+            false => 0.0,
+        }
+    }
+}
+
+// Functions
+/**
+A method to get the spawn table of a guild, weighted by the `bot` configuration.
+
+## Parameters:
+- `config`: The bot's configuration.
+- `spawn_generations`: The generations that can spawn in the guild. Empty means every generation.
+*/
+pub async fn get_guild_spawn_table(
+    config: &BotConfig,
+    spawn_generations: &[u8],
+) -> PokeFinderResult<SpawnTable> {
+    let poke_svc = POKEFINDER_SERVICE.clone();
+    let mut entries = poke_svc.get_spawn_table().await?;
+    if !spawn_generations.is_empty() {
+        let generations = poke_svc.get_generations().await?;
+        retain_generations(&mut entries, &generations, spawn_generations);
+    }
+
+    let weights = SpawnWeights::from_config(config);
+    Ok(SpawnTable::new(entries, &weights))
+}
+
+/**
+A method to keep only the species introduced in some generations.
+
+## Parameters:
+- `entries`: The species of the spawn table.
+- `generations`: All the generations.
+- `spawn_generations`: The IDs of the generations to keep.
+*/
+fn retain_generations(
+    entries: &mut Vec<CachedSpawnEntry>,
+    generations: &[CachedGeneration],
+    spawn_generations: &[u8],
+) {
+    let species_ids: HashSet<u16> = generations
+        .iter()
+        .filter(|generation| spawn_generations.contains(&generation.id))
+        .flat_map(|generation| generation.species_ids.iter().copied())
+        .collect();
+    entries.retain(|entry| species_ids.contains(&entry.id));
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::{rngs::StdRng, SeedableRng};
+
+    use super::*;
+
+    const WEIGHTS: SpawnWeights = SpawnWeights {
+        common: 100,
+        baby: 10,
+        legendary: 2,
+        mythical: 1,
+        by_capture_rate: false,
+    };
+
+    /**
+    A method to get a species of the spawn table.
+
+    ## Parameters:
+    - `id`: The ID of the species.
+    - `tier`: The rarity tier of the species.
+    - `capture_rate`: The capture rate of the species.
+    */
+    fn get_entry(id: u16, tier: SpawnTier, capture_rate: u8) -> CachedSpawnEntry {
+        CachedSpawnEntry {
+            id,
+            name: format!("species-{}", id),
+            capture_rate,
+            is_baby: tier == SpawnTier::Baby,
+            is_legendary: tier == SpawnTier::Legendary,
+            is_mythical: tier == SpawnTier::Mythical,
+        }
+    }
+
+    /**
+    A method to get a spawn table with a species of each tier, whose ID is its tier's position.
+
+    ## Parameters:
+    - `weights`: The weight of each rarity tier.
+    */
+    fn get_spawn_table(weights: &SpawnWeights) -> SpawnTable {
+        let entries = SpawnTier::ALL
+            .iter()
+            .zip(1..)
+            .map(|(tier, id)| get_entry(id, *tier, 255))
+            .collect();
+        SpawnTable::new(entries, weights)
+    }
+
+    #[test]
+    fn get_weight_uses_the_tier_weight() {
+        for (tier, weight) in SpawnTier::ALL.into_iter().zip([100.0, 10.0, 2.0, 1.0]) {
+            assert_eq!(WEIGHTS.get_weight(&get_entry(1, tier, 3)), weight);
+        }
+    }
+
+    #[test]
+    fn get_weight_scales_the_tier_weight_by_the_capture_rate() {
+        let weights = SpawnWeights {
+            by_capture_rate: true,
+            ..WEIGHTS
+        };
+
+        assert_eq!(
+            weights.get_weight(&get_entry(1, SpawnTier::Common, 255)),
+            100.0
+        );
+        assert_eq!(
+            weights.get_weight(&get_entry(1, SpawnTier::Common, 51)),
+            20.0
+        );
+        assert_eq!(
+            weights.get_weight(&get_entry(1, SpawnTier::Common, 0)),
+            weights.get_weight(&get_entry(1, SpawnTier::Common, 1))
+        );
+    }
+
+    #[test]
+    fn choose_never_picks_a_species_without_weight() {
+        let weights = SpawnWeights {
+            common: 0,
+            baby: 0,
+            legendary: 1,
+            mythical: 0,
+            ..WEIGHTS
+        };
+        let spawn_table = get_spawn_table(&weights);
+        let mut rng = StdRng::seed_from_u64(0);
+
+        for _ in 0..1000 {
+            assert_eq!(spawn_table.choose_with(&mut rng), Some(3));
+        }
+    }
+
+    #[test]
+    fn choose_picks_nothing_without_weight() {
+        let weights = SpawnWeights {
+            common: 0,
+            baby: 0,
+            legendary: 0,
+            mythical: 0,
+            ..WEIGHTS
+        };
+
+        assert_eq!(get_spawn_table(&weights).choose(), None);
+        assert_eq!(SpawnTable::new(Vec::new(), &WEIGHTS).choose(), None);
+    }
+
+    #[test]
+    fn choose_follows_the_weights() {
+        let spawn_table = get_spawn_table(&WEIGHTS);
+        let mut rng = StdRng::seed_from_u64(0);
+        let mut picks = [0; 4];
+        for _ in 0..100_000 {
+            let id = spawn_table.choose_with(&mut rng).unwrap();
+            picks[usize::from(id) - 1] += 1;
+        }
+
+        for (picks, weight) in picks.into_iter().zip([100.0, 10.0, 2.0, 1.0]) {
+            let chance = f64::from(picks) / 100_000.0;
+            assert!((chance - weight / 113.0).abs() < 0.005, "{}", chance);
+        }
+    }
+
+    #[test]
+    fn get_tier_stats_sums_the_species_of_each_tier() {
+        let mut entries: Vec<CachedSpawnEntry> = (1..=3)
+            .map(|id| get_entry(id, SpawnTier::Common, 255))
+            .collect();
+        entries.push(get_entry(4, SpawnTier::Legendary, 255));
+        let spawn_table = SpawnTable::new(entries, &WEIGHTS);
+
+        let tier_stats = spawn_table.get_tier_stats();
+        let species: Vec<usize> = tier_stats.iter().map(|stats| stats.species).collect();
+        assert_eq!(species, vec![3, 0, 1, 0]);
+        let chances: Vec<f64> = tier_stats.iter().map(|stats| stats.chance).collect();
+        assert_eq!(chances, vec![300.0 / 302.0, 0.0, 2.0 / 302.0, 0.0]);
+    }
+
+    #[test]
+    fn get_entry_finds_the_species_by_id_or_name() {
+        let spawn_table = get_spawn_table(&WEIGHTS);
+
+        let (entry, chance) = spawn_table.get_entry("2").unwrap();
+        assert_eq!(entry.id, 2);
+        assert_eq!(chance, 10.0 / 113.0);
+        assert_eq!(spawn_table.get_entry(" Species-4 ").unwrap().0.id, 4);
+        assert!(spawn_table.get_entry("5").is_none());
+    }
+
+    #[test]
+    fn retain_generations_never_picks_the_restricted_generations() {
+        let generations: Vec<CachedGeneration> = (1..=3)
+            .map(|id| CachedGeneration {
+                id,
+                species_ids: (1..=3)
+                    .map(|species| u16::from(id) * 10 + species)
+                    .collect(),
+                ..Default::default()
+            })
+            .collect();
+        let mut entries: Vec<CachedSpawnEntry> = generations
+            .iter()
+            .flat_map(|generation| generation.species_ids.clone())
+            .map(|id| get_entry(id, SpawnTier::Common, 255))
+            .collect();
+        retain_generations(&mut entries, &generations, &[1, 3]);
+
+        let spawn_table = SpawnTable::new(entries, &WEIGHTS);
+        let mut rng = StdRng::seed_from_u64(0);
+        for _ in 0..1000 {
+            let id = spawn_table.choose_with(&mut rng).unwrap();
+            assert!(!(21..=23).contains(&id), "{}", id);
+        }
+        let ids: Vec<u16> = spawn_table.entries.iter().map(|entry| entry.id).collect();
+        assert_eq!(ids, vec![11, 12, 13, 31, 32, 33]);
+    }
+}
@@ -0,0 +1,320 @@
+// Libs
+use serenity::all::User;
+use surrealdb::sql::Thing;
+
+use crate::{
+    errors::PokeFinderResult,
+    models::{DBPoke, DBTrainer},
+    services::POKEFINDER_SERVICE,
+};
+
+// Constants
+/**
+The maximum amount of pokémons a trainer can offer in a single trade.
+*/
+pub const MAX_TRADE_POKES: usize = 5;
+
+/**
+The maximum amount of options in a Discord select menu, so the options are split in pages of this size.
+*/
+pub const MAX_TRADE_OPTIONS: usize = 25;
+
+// Trade Option
+/**
+A struct to represent a pokémon a trainer can offer in a trade.
+
+The pokémons of the same species and shininess are interchangeable, so they share a single option
+that offers one of them.
+*/
+#[derive(Clone, Debug)]
+pub struct TradeOption {
+    pub poke: DBPoke,
+    pub name: String,
+    pub owned: usize,
+}
+
+impl TradeOption {
+    /**
+    A method to get the label of the option, like `#25 PIKACHU ✨ (x2)`.
+    */
+    pub fn get_label(&self) -> String {
+        let shiny = if self.poke.is_shiny { " ✨" } else { "" };
+        let owned = match self.owned > 1 {
+            true => format!(" (x{})", self.owned),
+            false => String::new(),
+        };
+        format!(
+            "#{} {}{}{}",
+            self.poke.poke_id,
+            self.name.to_uppercase(),
+            shiny,
+            owned
+        )
+    }
+
+    /**
+    A method to get the value of the option, which is the ID of the offered pokémon.
+    */
+    pub fn get_value(&self) -> String {
+        self.poke.id.id.to_string()
+    }
+}
+
+// Trade Side
+/**
+A struct to represent one of the trainers of a trade and their offer.
+*/
+#[derive(Clone, Debug)]
+pub struct TradeSide {
+    pub user: User,
+    pub trainer: DBTrainer,
+    pub options: Vec<TradeOption>,
+    pub selected: Vec<String>,
+    pub confirmed: bool,
+    pub page: usize,
+}
+
+impl TradeSide {
+    /**
+    A method to create a new TradeSide without any pokémon offered.
+
+    ## Parameters:
+    - `user`: The Discord user of the trainer.
+    - `trainer`: The trainer.
+    - `options`: The pokémons the trainer can offer.
+    */
+    pub fn new(user: User, trainer: DBTrainer, options: Vec<TradeOption>) -> Self {
+        Self {
+            user,
+            trainer,
+            options,
+            selected: Vec::new(),
+            confirmed: false,
+            page: 0,
+        }
+    }
+
+    /**
+    A method to get the amount of pages of the trainer's options.
+    */
+    pub fn get_page_count(&self) -> usize {
+        self.options.len().div_ceil(MAX_TRADE_OPTIONS).max(1)
+    }
+
+    /**
+    A method to get the options in the current page of the trainer's menu.
+    */
+    pub fn get_page_options(&self) -> &[TradeOption] {
+        self.options
+            .chunks(MAX_TRADE_OPTIONS)
+            .nth(self.page)
+            .unwrap_or_default()
+    }
+
+    /**
+    A method to move the trainer's menu to the next page, going back to the first after the last.
+    */
+    pub fn next_page(&mut self) {
+        self.page = (self.page + 1) % self.get_page_count();
+    }
+
+    /**
+    A method to move the trainer's menu to the previous page, going to the last before the first.
+    */
+    pub fn previous_page(&mut self) {
+        let page_count = self.get_page_count();
+        self.page = (self.page + page_count - 1) % page_count;
+    }
+
+    /**
+    A method to get the amount of options the trainer can pick in the current page of their menu,
+    with the options picked in the other pages.
+    */
+    pub fn get_page_slots(&self) -> usize {
+        let page_values: Vec<String> = self
+            .get_page_options()
+            .iter()
+            .map(TradeOption::get_value)
+            .collect();
+        let other_pages = self
+            .selected
+            .iter()
+            .filter(|value| !page_values.contains(value))
+            .count();
+        MAX_TRADE_POKES.saturating_sub(other_pages)
+    }
+
+    /**
+    A method to change the offer of the trainer in the current page of their menu.
+
+    The options picked in the other pages are kept. Unknown values are ignored.
+
+    ## Parameters:
+    - `values`: The values of the selected options in the current page.
+
+    ## Returns:
+    - If the offer changed. It's unchanged if it'd have more than `MAX_TRADE_POKES` pokémons.
+    */
+    pub fn select(&mut self, values: &[String]) -> bool {
+        let page_values: Vec<String> = self
+            .get_page_options()
+            .iter()
+            .map(TradeOption::get_value)
+            .collect();
+        let page_selected: Vec<String> = page_values
+            .iter()
+            .filter(|value| values.contains(value))
+            .cloned()
+            .collect();
+        if page_selected.len() > self.get_page_slots() {
+            return false;
+        }
+
+        self.selected.retain(|value| !page_values.contains(value));
+        self.selected.extend(page_selected);
+        true
+    }
+
+    /**
+    A method to get the options offered by the trainer.
+    */
+    pub fn get_offer(&self) -> Vec<&TradeOption> {
+        self.options
+            .iter()
+            .filter(|option| self.selected.contains(&option.get_value()))
+            .collect()
+    }
+
+    /**
+    A method to get the IDs of the pokémons offered by the trainer.
+    */
+    pub fn get_offered_poke_ids(&self) -> Vec<Thing> {
+        self.get_offer()
+            .into_iter()
+            .map(|option| option.poke.id.clone())
+            .collect()
+    }
+}
+
+// Functions
+/**
+A method to get the pokémons a trainer can offer in a trade.
+
+Only one pokémon of each species and shininess is listed, so the options fit in fewer pages. It's
+intended: the pokémons don't have any other attribute, so the listed one is as good as the others.
+
+## Parameters:
+- `trainer_pokes`: The pokémons owned by the trainer, sorted by the pokémon ID.
+*/
+pub async fn get_trade_options(trainer_pokes: &[DBPoke]) -> PokeFinderResult<Vec<TradeOption>> {
+    let poke_svc = POKEFINDER_SERVICE.clone();
+    let mut options: Vec<TradeOption> = Vec::new();
+    for poke in trainer_pokes {
+        let same_option = options.iter_mut().find(|option| {
+            option.poke.poke_id == poke.poke_id && option.poke.is_shiny == poke.is_shiny
+        });
+        if let Some(option) = same_option {
+            option.owned += 1;
+        } else {
+            let cached_poke = poke_svc.find_poke(&poke.poke_id.to_string()).await?;
+            options.push(TradeOption {
+                poke: poke.clone(),
+                name: cached_poke.name,
+                owned: 1,
+            });
+        }
+    }
+
+    Ok(options)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /**
+    A method to get a trade side with an option for each pokémon ID.
+    */
+    fn get_trade_side(poke_ids: impl Iterator<Item = u16>) -> TradeSide {
+        let trainer = DBTrainer::new("1", "1");
+        let options = poke_ids
+            .map(|poke_id| TradeOption {
+                poke: DBPoke::new(&trainer.id, &poke_id, false),
+                name: format!("poke-{}", poke_id),
+                owned: 1,
+            })
+            .collect();
+        TradeSide::new(User::default(), trainer, options)
+    }
+
+    /**
+    A method to get the values of the options in the current page.
+    */
+    fn get_page_values(side: &TradeSide) -> Vec<String> {
+        side.get_page_options()
+            .iter()
+            .map(TradeOption::get_value)
+            .collect()
+    }
+
+    #[test]
+    fn pages_every_option() {
+        let mut side = get_trade_side(1..=60);
+        assert_eq!(side.get_page_count(), 3);
+        assert_eq!(side.get_page_options().len(), MAX_TRADE_OPTIONS);
+
+        side.previous_page();
+        assert_eq!(side.page, 2);
+        assert_eq!(side.get_page_options().len(), 10);
+        assert_eq!(side.get_page_options()[9].poke.poke_id, 60);
+        side.next_page();
+        assert_eq!(side.page, 0);
+
+        let mut side = get_trade_side(std::iter::empty());
+        assert_eq!(side.get_page_count(), 1);
+        side.next_page();
+        assert_eq!(side.page, 0);
+        assert!(side.get_page_options().is_empty());
+    }
+
+    #[test]
+    fn select_keeps_the_offer_of_the_other_pages() {
+        let mut side = get_trade_side(1..=60);
+        let first_page = get_page_values(&side);
+        side.select(&first_page[..2]);
+        side.next_page();
+        let second_page = get_page_values(&side);
+        side.select(&[second_page[0].clone(), first_page[3].clone()]);
+        assert_eq!(
+            side.selected,
+            vec![
+                first_page[0].clone(),
+                first_page[1].clone(),
+                second_page[0].clone()
+            ]
+        );
+
+        side.select(&[]);
+        assert_eq!(side.selected, first_page[..2].to_vec());
+    }
+
+    #[test]
+    fn select_rejects_an_offer_with_too_many_pokes() {
+        let mut side = get_trade_side(1..=60);
+        let first_page = get_page_values(&side);
+        assert!(side.select(&first_page[..2]));
+        side.next_page();
+        assert_eq!(side.get_page_slots(), MAX_TRADE_POKES - 2);
+
+        // The rejected offer is unchanged, rather than truncated.
+        let second_page = get_page_values(&side);
+        assert!(!side.select(&second_page[..MAX_TRADE_POKES - 1]));
+        assert_eq!(side.selected, first_page[..2].to_vec());
+
+        assert!(side.select(&second_page[..MAX_TRADE_POKES - 2]));
+        assert_eq!(side.selected.len(), MAX_TRADE_POKES);
+        assert_eq!(side.get_page_slots(), MAX_TRADE_POKES - 2);
+        side.previous_page();
+        assert_eq!(side.get_page_slots(), 2);
+    }
+}
@@ -1,8 +1,11 @@
-use tracing::info;
+use surrealdb::sql::Thing;
+use tracing::{info, warn};
 
+use super::config::BotConfig;
 use crate::{
+    di::{QueryVars, Transaction},
     errors::DatabaseResult,
-    models::{DBPoke, DBTrainer},
+    models::{BallType, DBInventoryItem, DBModel, DBPoke, DBTrainer},
     services::DATABASE_SERVICE,
 };
 
@@ -15,7 +18,7 @@ A method to find a list of all pokémons owned by a trainer.
 
 ## Returns:
 - A tuple containing the list of all pokémons owned by the trainer and the list of all pokémon species owned by the trainer.
-Both lists are sorted by the pokémon ID.
+  Both lists are sorted by the pokémon ID.
 */
 pub async fn get_trainer_owned_pokes(
     trainer: DBTrainer,
@@ -23,12 +26,13 @@ pub async fn get_trainer_owned_pokes(
     // Define the query to fetch all pokemons owned by the trainer.
     info!("Finding all pokemons owned by the {}...", trainer.id);
     let db_svc = DATABASE_SERVICE.clone();
-    let query = format!("SELECT * FROM pokemon WHERE trainer_id = '{}'", trainer.id);
+    let query = "SELECT * FROM pokemon WHERE trainer_id = $trainer_id";
+    let vars = QueryVars::from([(String::from("trainer_id"), trainer.id.clone().into())]);
 
     // Fetch the pokemons and sort them by their ID.
     let (trainer_pokes, trainer_species) = {
-        let mut pokes: Vec<DBPoke> = db_svc.run_query(&query).await?;
-        pokes.sort_by(|a, b| a.poke_id.cmp(&b.poke_id));
+        let mut pokes: Vec<DBPoke> = db_svc.run_query(query, vars).await?;
+        pokes.sort_by_key(|poke| poke.poke_id);
         let mut trainer_species = pokes.clone();
         trainer_species.dedup_by(|a, b| a.poke_id == b.poke_id);
 
@@ -39,3 +43,109 @@ pub async fn get_trainer_owned_pokes(
     info!("{} Pokemons found successfully.", trainer_pokes.len());
     Ok((trainer_pokes, trainer_species))
 }
+
+/**
+A method to check if a trainer has caught a pokémon of a species.
+
+## Parameters:
+- `trainer_id`: The ID of the trainer.
+- `poke_id`: The ID of the pokémon.
+*/
+pub async fn trainer_owns_species(trainer_id: &Thing, poke_id: u16) -> DatabaseResult<bool> {
+    let db_svc = DATABASE_SERVICE.clone();
+    let query =
+        "SELECT VALUE id FROM pokemon WHERE trainer_id = $trainer_id AND poke_id = $poke_id LIMIT 1";
+    let vars = QueryVars::from([
+        (String::from("trainer_id"), trainer_id.clone().into()),
+        (String::from("poke_id"), poke_id.into()),
+    ]);
+    let pokes: Vec<Thing> = db_svc.run_query(query, vars).await?;
+    Ok(!pokes.is_empty())
+}
+
+/**
+A method to get the amount of a ball given to a trainer the first time their inventory is used.
+
+## Parameters:
+- `config`: The bot's configuration.
+- `ball`: The type of the ball.
+*/
+pub fn get_starting_balls(config: &BotConfig, ball: BallType) -> u64 {
+    match ball {
+        BallType::Poke => config.starting_poke_balls,
+        BallType::Great => config.starting_great_balls,
+        BallType::Ultra => config.starting_ultra_balls,
+        BallType::Master => config.starting_master_balls,
+    }
+}
+
+/**
+A method to get a trainer's inventory. The starting balls are given to the trainer if it's the
+first time their inventory is used.
+
+## Parameters:
+- `config`: The bot's configuration.
+- `trainer`: The trainer to get the inventory for.
+
+## Returns:
+- The amount of each ball owned by the trainer, sorted like `BallType::ALL`.
+*/
+pub async fn get_trainer_inventory(
+    config: &BotConfig,
+    trainer: &DBTrainer,
+) -> DatabaseResult<Vec<DBInventoryItem>> {
+    let mut inventory = DBInventoryItem::get_by_trainer_id(&trainer.id).await?;
+    if inventory.is_empty() {
+        info!("Giving the starting balls to the {}...", trainer.id);
+        let mut transaction = Transaction::new();
+        for ball in BallType::ALL {
+            let item = DBInventoryItem::new(&trainer.id, ball, get_starting_balls(config, ball));
+            item.create_in_transaction(&mut transaction)?;
+        }
+
+        // A concurrent interaction may have given them first, which breaks the unique index.
+        let db_svc = DATABASE_SERVICE.clone();
+        if let Err(e) = db_svc.commit_transaction(transaction).await {
+            warn!("Error giving the starting balls. {}", e);
+        }
+        inventory = DBInventoryItem::get_by_trainer_id(&trainer.id).await?;
+    }
+
+    inventory.sort_by_key(|item| BallType::ALL.iter().position(|ball| *ball == item.ball));
+    Ok(inventory)
+}
+
+#[cfg(test)]
+mod tests {
+    use surrealdb::sql::Id;
+
+    use super::*;
+    use crate::testing::{run_test, unique_id, HOSTILE_IDS};
+
+    #[test]
+    fn get_trainer_owned_pokes_binds_hostile_ids() {
+        run_test(async {
+            let victim = DBTrainer::find_by_discord_id(&unique_id(), &unique_id())
+                .await
+                .unwrap();
+            let mut victim_poke = DBPoke::new(&victim.id, &25, false);
+            victim_poke.create().await.unwrap();
+
+            for hostile_id in HOSTILE_IDS {
+                let mut trainer = DBTrainer::new(hostile_id, &victim.discord_guild_id);
+                trainer.id = Thing {
+                    tb: String::from("trainer"),
+                    id: Id::from(hostile_id),
+                };
+                let (pokes, species) = get_trainer_owned_pokes(trainer).await.unwrap();
+                assert!(pokes.is_empty(), "{} found some pokemons", hostile_id);
+                assert!(species.is_empty(), "{} found some species", hostile_id);
+            }
+
+            let (pokes, _) = get_trainer_owned_pokes(victim).await.unwrap();
+            assert_eq!(pokes.len(), 1);
+            assert_eq!(pokes[0].id, victim_poke.id);
+            assert_eq!(pokes[0].updated_at, victim_poke.updated_at);
+        });
+    }
+}